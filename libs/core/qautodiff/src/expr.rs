@@ -229,17 +229,67 @@ impl<K, V> Expr<K, V> {
     }
 
     /// Compress the expression to reduce memory usage, computation time, etc.
+    ///
+    /// The subgraph behind this expression is collapsed into a single `Compressed` node
+    /// which holds the current value and gradients w.r.t. current variables.
+    /// Hence the result no longer depends on intermediate nodes of the subgraph
+    /// and can be reused as a building block of other expressions.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::{Expr, Graph};
+    /// use qmath::num::Exp;
+    ///
+    /// let graph = Graph::new();
+    /// let r: Expr<_, f64> = graph.create_var("r", 0.02).unwrap().into();
+    /// let t = Expr::from(2.0);
+    ///
+    /// let df = (-(&r * &t)).exp();
+    /// let compressed = df.compress();
+    ///
+    /// assert_eq!(compressed.value(), df.value());
+    /// assert_eq!(
+    ///     compressed.grads().unwrap().collect::<Vec<_>>(),
+    ///     df.grads().unwrap().collect::<Vec<_>>(),
+    /// );
+    /// ```
     #[inline]
-    pub fn compress(self) -> Self
+    pub fn compress(&self) -> Self
     where
         V: Real,
     {
-        match self.0 {
-            _Expr::Const(v) => Self::from(v),
+        match &self.0 {
+            _Expr::Const(v) => Self::from(v.clone()),
             _Expr::Node(node) => node._compress(),
         }
     }
 
+    /// Detach the expression from the graph, that is, treat it as a constant.
+    ///
+    /// The result keeps the current value but gradients do not propagate through it.
+    /// Unlike [`Expr::compress`], which keeps gradients of the expression,
+    /// the result does not depend on any variable.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::{Expr, Graph};
+    ///
+    /// let graph = Graph::new();
+    /// let x: Expr<_, f64> = graph.create_var("x", 2.0).unwrap().into();
+    ///
+    /// let y = (&x * &x).detach() + &x;
+    ///
+    /// assert_eq!(y.value(), 6.0);
+    /// assert_eq!(y.grads().unwrap().collect::<Vec<_>>(), vec![("x", 1.0)]);
+    /// ```
+    #[inline]
+    pub fn detach(&self) -> Self
+    where
+        V: Clone,
+    {
+        Self::from(self.value())
+    }
+
     /// Get the expression as a constant if possible
     #[inline]
     pub fn graphviz(&self) -> Option<GraphvizBuilder<K, V, (), ()>>
@@ -254,6 +304,17 @@ impl<K, V> Expr<K, V> {
     }
 }
 
+// impls
+impl<K, V> Expr<K, V> {
+    #[inline]
+    pub(crate) fn _as_node(&self) -> Option<&Node<K, V>> {
+        match &self.0 {
+            _Expr::Node(node) => Some(node),
+            _Expr::Const(_) => None,
+        }
+    }
+}
+
 //
 // numeric
 //
@@ -467,7 +528,8 @@ _define_arithmetic_binary!(Mul, mul, MulAssign, mul_assign);
 _define_arithmetic_binary!(Div, div, DivAssign, div_assign);
 
 macro_rules! _define_elementary_unary {
-    ($tr:ident, $fn:ident) => {
+    ($(#[$meta:meta])* $tr:ident, $fn:ident) => {
+        $(#[$meta])*
         impl<K, V> qmath::num::$tr for Expr<K, V>
         where
             V: Clone + qmath::num::$tr<Output = V>,
@@ -488,7 +550,13 @@ macro_rules! _define_elementary_unary {
 _define_elementary_unary!(Exp, exp);
 _define_elementary_unary!(Log, log);
 _define_elementary_unary!(Erf, erf);
+_define_elementary_unary!(Expm1, expm1);
+_define_elementary_unary!(Log1p, log1p);
 _define_elementary_unary!(Sqrt, sqrt);
+_define_elementary_unary!(
+    /// Derivative is `sign(x)`. Since `abs` is not differentiable at 0, 0 is used there as a subgradient.
+    Abs, abs
+);
 
 impl<K, V> Powi for Expr<K, V>
 where
@@ -505,15 +573,51 @@ where
     }
 }
 
+macro_rules! _define_elementary_binary {
+    ($(#[$meta:meta])* $tr:ident, $fn:ident, $fn_l:ident, $fn_r:ident) => {
+        $(#[$meta])*
+        impl<K, V> qmath::num::$tr for Expr<K, V>
+        where
+            V: Clone + qmath::num::$tr<Output = V>,
+        {
+            type Output = Expr<K, V>;
+
+            #[inline]
+            fn $fn(self, rhs: &Expr<K, V>) -> Self::Output {
+                match (self.0, &rhs.0) {
+                    (_Expr::Const(lhs), _Expr::Const(rhs)) => qmath::num::$tr::$fn(lhs, rhs).into(),
+                    (_Expr::Const(lhs), _Expr::Node(rhs)) => Node::$fn_r(Scalar(lhs), rhs).into(),
+                    (_Expr::Node(lhs), _Expr::Const(rhs)) => lhs.$fn_l(Scalar(rhs)).into(),
+                    (_Expr::Node(lhs), _Expr::Node(rhs)) => qmath::num::$tr::$fn(lhs, rhs).into(),
+                }
+            }
+        }
+    };
+}
+
+_define_elementary_binary!(Atan2, atan2, _atan2_l, _atan2_r);
+_define_elementary_binary!(Hypot, hypot, _hypot_l, _hypot_r);
+_define_elementary_binary!(
+    /// Since `max` is not differentiable where arguments are equal, a subgradient is used.
+    /// The whole gradient flows to the selected argument and, on ties, to the left-hand side.
+    /// For example, `max(x - k, 0)` has gradient 1 w.r.t. `x` if `x >= k` and 0 otherwise.
+    Max, max, _max_l, _max_r
+);
+_define_elementary_binary!(
+    /// Since `min` is not differentiable where arguments are equal, a subgradient is used.
+    /// The whole gradient flows to the selected argument and, on ties, to the left-hand side.
+    Min, min, _min_l, _min_r
+);
+
 #[cfg(test)]
 mod tests {
     use core::f64;
     use std::collections::HashMap;
 
-    use qmath::num::{Erf, Exp, Log, Sqrt};
+    use qmath::num::{Abs, Atan2, Erf, Exp, Expm1, Hypot, Log, Log1p, Max, Min, Sqrt};
     use rstest::rstest;
 
-    use crate::Graph;
+    use crate::{Error, Graph};
 
     use super::*;
 
@@ -832,7 +936,7 @@ mod tests {
         let y = x.clone().erf();
         let grads: HashMap<_, _> = y.grads().unwrap().collect();
 
-        assert_eq!(y.value(), input.erf());
+        assert_eq!(y.value(), Erf::erf(input));
         assert_eq!(grads.len(), 1);
         assert_eq!(
             grads[&"x"],
@@ -840,6 +944,44 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(1e-5)]
+    #[case(-1e-5)]
+    #[case(0.0)]
+    #[case(0.5)]
+    #[case(-0.5)]
+    fn test_expm1(#[case] input: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", input).unwrap();
+        let x = x.as_ref();
+
+        let y = x.clone().expm1();
+        let grads: HashMap<_, _> = y.grads().unwrap().collect();
+
+        assert_eq!(y.value(), input.exp_m1());
+        assert_eq!(grads.len(), 1);
+        approx::assert_relative_eq!(grads[&"x"], input.exp(), max_relative = 1e-15);
+    }
+
+    #[rstest]
+    #[case(1e-5)]
+    #[case(-1e-5)]
+    #[case(0.0)]
+    #[case(0.5)]
+    #[case(-0.5)]
+    fn test_log1p(#[case] input: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", input).unwrap();
+        let x = x.as_ref();
+
+        let y = x.clone().log1p();
+        let grads: HashMap<_, _> = y.grads().unwrap().collect();
+
+        assert_eq!(y.value(), input.ln_1p());
+        assert_eq!(grads.len(), 1);
+        assert_eq!(grads[&"x"], 1. / (1. + input));
+    }
+
     #[rstest]
     #[case(0.5)]
     #[case(1.0)]
@@ -857,6 +999,44 @@ mod tests {
         assert_eq!(grads[&"x"], 1. / (2. * input.sqrt()));
     }
 
+    #[rstest]
+    #[case(0.5)]
+    #[case(4.0)]
+    #[case(-1e-3)]
+    #[case(-3.5)]
+    fn test_abs(#[case] input: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", input).unwrap();
+        let x = x.as_ref();
+
+        let y = (x.clone() * x).abs() + &x.clone().abs();
+        let grads: HashMap<_, _> = y.grads().unwrap().collect();
+
+        let f = |x: f64| (x * x).abs() + x.abs();
+        let h = 1e-6;
+        let fd = (f(input + h) - f(input - h)) / (2. * h);
+        assert_eq!(y.value(), f(input));
+        assert_eq!(grads.len(), 1);
+        approx::assert_abs_diff_eq!(grads[&"x"], fd, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_abs_at_zero() {
+        let graph = Graph::new();
+        let x = graph.create_var("x", 0.0).unwrap();
+        let x = x.as_ref();
+
+        let y = x.clone().abs();
+        let z = (x.clone() - 1.0).abs() + &y;
+        let ygrads: HashMap<_, _> = y.grads().unwrap().collect();
+        let zgrads: HashMap<_, _> = z.grads().unwrap().collect();
+
+        // subgradient 0 is used at 0
+        assert_eq!(y.value(), 0.0);
+        assert_eq!(ygrads[&"x"], 0.0);
+        assert_eq!(zgrads[&"x"], -1.0);
+    }
+
     #[rstest]
     #[case(0.5, 0)]
     #[case(1.0, 0)]
@@ -883,6 +1063,167 @@ mod tests {
         assert_eq!(grads[&"x"], exp as f64 * input.powi(exp - 1));
     }
 
+    #[rstest]
+    #[case(1.0, 1.0)]
+    #[case(4.0, 1.0)]
+    #[case(-3.5, 1.0)]
+    #[case(0.5, -2.0)]
+    #[case(-3.5, -3.5)]
+    fn test_atan2(#[case] lhs: f64, #[case] rhs: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", lhs).unwrap();
+        let y = graph.create_var("y", rhs).unwrap();
+        let x = x.as_ref();
+        let y = y.as_ref();
+        let c = Expr::from(rhs);
+
+        let z = x.clone().atan2(y);
+        let w = x.clone().atan2(&c);
+        let v = c.clone().atan2(x);
+        let zgrads: HashMap<_, _> = z.grads().unwrap().collect();
+        let wgrads: HashMap<_, _> = w.grads().unwrap().collect();
+        let vgrads: HashMap<_, _> = v.grads().unwrap().collect();
+
+        let h = 1e-6;
+        let fd_x = ((lhs + h).atan2(rhs) - (lhs - h).atan2(rhs)) / (2. * h);
+        let fd_y = (lhs.atan2(rhs + h) - lhs.atan2(rhs - h)) / (2. * h);
+        let fd_v = (rhs.atan2(lhs + h) - rhs.atan2(lhs - h)) / (2. * h);
+        assert_eq!(z.value(), lhs.atan2(rhs));
+        assert_eq!(w.value(), lhs.atan2(rhs));
+        assert_eq!(v.value(), rhs.atan2(lhs));
+        assert_eq!(zgrads.len(), 2);
+        assert_eq!(wgrads[&"y"], 0.0);
+        assert_eq!(vgrads[&"y"], 0.0);
+        approx::assert_abs_diff_eq!(zgrads[&"x"], fd_x, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(zgrads[&"y"], fd_y, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(wgrads[&"x"], fd_x, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(vgrads[&"x"], fd_v, epsilon = 1e-8);
+    }
+
+    #[rstest]
+    #[case(1.0, 1.0)]
+    #[case(4.0, 1.0)]
+    #[case(-3.5, 1.0)]
+    #[case(0.5, -2.0)]
+    #[case(-3.5, -3.5)]
+    fn test_hypot(#[case] lhs: f64, #[case] rhs: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", lhs).unwrap();
+        let y = graph.create_var("y", rhs).unwrap();
+        let x = x.as_ref();
+        let y = y.as_ref();
+        let c = Expr::from(rhs);
+
+        let z = x.clone().hypot(y);
+        let w = x.clone().hypot(&c);
+        let v = c.clone().hypot(x);
+        let zgrads: HashMap<_, _> = z.grads().unwrap().collect();
+        let wgrads: HashMap<_, _> = w.grads().unwrap().collect();
+        let vgrads: HashMap<_, _> = v.grads().unwrap().collect();
+
+        let h = 1e-6;
+        let fd_x = ((lhs + h).hypot(rhs) - (lhs - h).hypot(rhs)) / (2. * h);
+        let fd_y = (lhs.hypot(rhs + h) - lhs.hypot(rhs - h)) / (2. * h);
+        assert_eq!(z.value(), lhs.hypot(rhs));
+        assert_eq!(w.value(), lhs.hypot(rhs));
+        assert_eq!(v.value(), rhs.hypot(lhs));
+        assert_eq!(zgrads.len(), 2);
+        assert_eq!(wgrads[&"y"], 0.0);
+        assert_eq!(vgrads[&"y"], 0.0);
+        approx::assert_abs_diff_eq!(zgrads[&"x"], fd_x, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(zgrads[&"y"], fd_y, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(wgrads[&"x"], fd_x, epsilon = 1e-8);
+        approx::assert_abs_diff_eq!(vgrads[&"x"], fd_x, epsilon = 1e-8);
+    }
+
+    #[rstest]
+    #[case(1.0, 2.0)]
+    #[case(4.0, 1.0)]
+    #[case(-3.5, 1.0)]
+    #[case(2.0, 2.0)]
+    fn test_max(#[case] lhs: f64, #[case] rhs: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", lhs).unwrap();
+        let y = graph.create_var("y", rhs).unwrap();
+        let x = x.as_ref();
+        let y = y.as_ref();
+        let c = Expr::from(rhs);
+
+        let z = x.clone().max(y);
+        let w = x.clone().max(&c);
+        let v = c.clone().max(x);
+        let zgrads: HashMap<_, _> = z.grads().unwrap().collect();
+        let wgrads: HashMap<_, _> = w.grads().unwrap().collect();
+        let vgrads: HashMap<_, _> = v.grads().unwrap().collect();
+
+        // ties go to the left-hand side
+        let lhs_selected = if rhs <= lhs { 1.0 } else { 0.0 };
+        let rhs_selected = if lhs <= rhs { 1.0 } else { 0.0 };
+        assert_eq!(z.value(), lhs.max(rhs));
+        assert_eq!(w.value(), lhs.max(rhs));
+        assert_eq!(v.value(), lhs.max(rhs));
+        assert_eq!(zgrads[&"x"], lhs_selected);
+        assert_eq!(zgrads[&"y"], 1.0 - lhs_selected);
+        assert_eq!(wgrads[&"x"], lhs_selected);
+        assert_eq!(wgrads[&"y"], 0.0);
+        assert_eq!(vgrads[&"x"], 1.0 - rhs_selected);
+        assert_eq!(vgrads[&"y"], 0.0);
+    }
+
+    #[rstest]
+    #[case(1.0, 2.0)]
+    #[case(4.0, 1.0)]
+    #[case(-3.5, 1.0)]
+    #[case(2.0, 2.0)]
+    fn test_min(#[case] lhs: f64, #[case] rhs: f64) {
+        let graph = Graph::new();
+        let x = graph.create_var("x", lhs).unwrap();
+        let y = graph.create_var("y", rhs).unwrap();
+        let x = x.as_ref();
+        let y = y.as_ref();
+        let c = Expr::from(rhs);
+
+        let z = x.clone().min(y);
+        let w = x.clone().min(&c);
+        let v = c.clone().min(x);
+        let zgrads: HashMap<_, _> = z.grads().unwrap().collect();
+        let wgrads: HashMap<_, _> = w.grads().unwrap().collect();
+        let vgrads: HashMap<_, _> = v.grads().unwrap().collect();
+
+        // ties go to the left-hand side
+        let lhs_selected = if lhs <= rhs { 1.0 } else { 0.0 };
+        let rhs_selected = if rhs <= lhs { 1.0 } else { 0.0 };
+        assert_eq!(z.value(), lhs.min(rhs));
+        assert_eq!(w.value(), lhs.min(rhs));
+        assert_eq!(v.value(), lhs.min(rhs));
+        assert_eq!(zgrads[&"x"], lhs_selected);
+        assert_eq!(zgrads[&"y"], 1.0 - lhs_selected);
+        assert_eq!(wgrads[&"x"], lhs_selected);
+        assert_eq!(wgrads[&"y"], 0.0);
+        assert_eq!(vgrads[&"x"], 1.0 - rhs_selected);
+        assert_eq!(vgrads[&"y"], 0.0);
+    }
+
+    #[rstest]
+    #[case(90.0, 0.0, 0.0)]
+    #[case(99.0, 0.0, 0.0)]
+    #[case(101.0, 1.0, 1.0)]
+    #[case(110.0, 10.0, 1.0)]
+    // at the strike, the gradient goes to `spot - strike` since it is the left-hand side
+    #[case(100.0, 0.0, 1.0)]
+    fn test_call_payoff_delta(#[case] spot: f64, #[case] payoff: f64, #[case] delta: f64) {
+        let graph = Graph::new();
+        let s = graph.create_var("spot", spot).unwrap();
+        let k = graph.create_var("strike", 100.0).unwrap();
+
+        let tested = (s.as_ref().clone() - k.as_ref()).max(&Expr::from(0.0));
+        let grads: HashMap<_, _> = tested.grads().unwrap().collect();
+
+        assert_eq!(tested.value(), payoff);
+        assert_eq!(grads[&"spot"], delta);
+        assert_eq!(grads[&"strike"], -delta);
+    }
+
     #[rstest]
     #[case(0.0, 0.0)]
     #[case(1.0, 0.0)]
@@ -1315,6 +1656,28 @@ mod tests {
         assert_eq!(cgrads, grads);
     }
 
+    #[test]
+    fn test_compressed_reused() {
+        let graph = Graph::new();
+        let r = graph.create_var("r", 0.02).unwrap();
+        let s = graph.create_var("s", 0.01).unwrap();
+        let t = Expr::from(2.0);
+        let df = (-(r.as_ref() + s.as_ref()) * &t).exp();
+        let pv = &df * Expr::from(100.) + r.as_ref() * s.as_ref();
+        let val = pv.value();
+        let grads: HashMap<_, _> = pv.grads().unwrap().collect();
+
+        let cdf = df.compress();
+        drop(df);
+        let cpv = &cdf * Expr::from(100.) + r.as_ref() * s.as_ref();
+        let cgrads: HashMap<_, _> = cpv.grads().unwrap().collect();
+
+        assert_eq!(cpv.value(), val);
+        assert_eq!(cgrads.len(), 2);
+        approx::assert_relative_eq!(cgrads[&"r"], grads[&"r"], max_relative = 1e-15);
+        approx::assert_relative_eq!(cgrads[&"s"], grads[&"s"], max_relative = 1e-15);
+    }
+
     #[test]
     fn test_graphviz() {
         let graph = Graph::new();
@@ -1378,4 +1741,100 @@ mod tests {
 "##
         )
     }
+
+    #[test]
+    fn test_graphviz_root_labels() {
+        let graph = Graph::new();
+        let x = graph.create_var("x", 1.0).unwrap();
+        let y = graph.create_var("y", 2.0).unwrap();
+        let w = x.as_ref() * y.as_ref();
+        let v = w.clone() + x.as_ref();
+
+        let res = graph
+            .graphviz(&[("pv", &w), ("total", &v), ("const", &Expr::from(1.0))])
+            .unwrap()
+            .with_key_formatter(std::string::ToString::to_string)
+            .with_value_formatter(|n| format!("{:.3}", n))
+            .gen_dot();
+
+        // gradients are those of the sum of roots, i.e. d(w + v)/dw = 2
+        assert!(res.contains(
+            r#"[label="*|{value=2.000|grad=2.000}", shape=record, xlabel="pv", color="red", penwidth=2];"#
+        ));
+        assert!(res.contains(
+            r#"[label="+|{value=3.000|grad=1.000}", shape=record, xlabel="total", color="red", penwidth=2];"#
+        ));
+        assert!(res.contains(r#"[label="x|{value=1.000|grad=5.000}""#));
+        assert!(res.contains(r#"[label="y|{value=2.000|grad=2.000}""#));
+        assert_eq!(res.matches("xlabel=").count(), 2);
+    }
+
+    #[test]
+    fn test_graphviz_root_labels_escaped() {
+        let graph = Graph::new();
+        let x = graph.create_var("x", 1.0).unwrap();
+        let w = x.as_ref() * x.as_ref();
+
+        let res = graph
+            .graphviz(&[(r#"pv "usd" \ jpy"#, &w)])
+            .unwrap()
+            .with_key_formatter(std::string::ToString::to_string)
+            .with_value_formatter(|n| format!("{:.3}", n))
+            .gen_dot();
+
+        assert!(res.contains(r#"xlabel="pv \"usd\" \\ jpy""#));
+    }
+
+    #[test]
+    fn test_graphviz_root_labels_different_graphs() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let x = graph1.create_var("x", 1.0).unwrap();
+        let y = graph2.create_var("y", 2.0).unwrap();
+
+        let res = graph1.graphviz(&[("x", x.as_ref()), ("y", y.as_ref())]);
+
+        assert_eq!(res.err(), Some(Error::DifferentGraphs("graphviz")));
+    }
+
+    #[test]
+    fn test_const_folding() {
+        let graph = Graph::new();
+        let x: Expr<&str, f64> = graph.create_var("x", 1.5).unwrap().into();
+
+        let consts = (Expr::from(2.0) * Expr::from(3.0)).exp() + Expr::from(1.0);
+        let cells_consts = graph.num_live_cells();
+        let folded = &x * &consts;
+        let cells_folded = graph.num_live_cells();
+        let unfolded = &x * 6f64.exp() + &x;
+        let cells_unfolded = graph.num_live_cells();
+
+        assert!(consts.grads().is_none());
+        assert_eq!(consts.value(), 6f64.exp() + 1.0);
+        assert_eq!(cells_consts, 1);
+        assert_eq!(cells_folded - cells_consts, 1);
+        assert!(cells_folded - cells_consts < cells_unfolded - cells_folded);
+        assert_eq!(folded.value(), unfolded.value());
+        let gfolded = folded.grads().unwrap().collect::<HashMap<_, _>>();
+        let gunfolded = unfolded.grads().unwrap().collect::<HashMap<_, _>>();
+        assert_eq!(gfolded[&"x"], 6f64.exp() + 1.0);
+        assert_eq!(gfolded[&"x"], gunfolded[&"x"]);
+    }
+
+    #[test]
+    fn test_detach() {
+        let graph = Graph::new();
+        let x: Expr<&str, f64> = graph.create_var("x", 1.5).unwrap().into();
+        let y: Expr<&str, f64> = graph.create_var("y", 2.5).unwrap().into();
+
+        let detached = (&x * &y).detach();
+        let expr = &detached * &x;
+
+        assert_eq!(detached.value(), 1.5 * 2.5);
+        assert!(detached.grads().is_none());
+        let grads = expr.grads().unwrap().collect::<HashMap<_, _>>();
+        assert_eq!(expr.value(), 1.5 * 2.5 * 1.5);
+        assert_eq!(grads[&"x"], 1.5 * 2.5);
+        assert_eq!(grads[&"y"], 0.0);
+    }
 }
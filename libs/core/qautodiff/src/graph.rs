@@ -1,9 +1,11 @@
 mod grads;
 mod graph_impl;
+mod sync_graph;
 mod tape;
 
 pub(crate) use tape::{Node, Scalar};
 
 pub use grads::{Grads, GradsAccum};
 pub use graph_impl::Graph;
+pub use sync_graph::SyncGraph;
 pub use tape::GraphvizBuilder;
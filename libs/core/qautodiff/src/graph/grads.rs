@@ -1,11 +1,11 @@
-use std::convert::Infallible;
+use std::{collections::HashMap, convert::Infallible, fmt::Display, hash::Hash, io::Write};
 
 use qmath::num::Real;
 
 use crate::{Error, Var};
 
 use super::{
-    tape::{_BackProp, _BackPropWorkSpace, _Tape},
+    tape::{_BackProp, _BackPropWorkSpace, _Tape, _VarIdx},
     Graph,
 };
 
@@ -102,7 +102,7 @@ impl<V> _GradPool<V> {
         let mut process = _GradCollect {
             grads: &mut self.grads[index].grads,
         };
-        ws._back_prop(tape, node, &mut process).unwrap();
+        ws._back_prop(tape, &[node], &mut process).unwrap();
         Grads { graph, index }
     }
 }
@@ -187,6 +187,79 @@ impl<K, V> Grads<K, V> {
     {
         self.collect_mapped(|k, v| (k.clone(), v))
     }
+
+    /// Collect gradients as a dense vector aligned to [`Graph::var_order`].
+    ///
+    /// The `i`-th element is the gradient of the `i`-th variable created on the graph.
+    /// Variables which the expression does not depend on are filled with zero.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::Graph;
+    ///
+    /// let graph = Graph::new();
+    /// let x = graph.create_var("x", 2.0).unwrap();
+    /// let y = graph.create_var("y", 3.0).unwrap();
+    /// let z = graph.create_var("z", 4.0).unwrap();
+    ///
+    /// let expr = x.as_ref() * z.as_ref();
+    /// let grads = expr.grads().unwrap();
+    ///
+    /// assert_eq!(graph.var_order(), vec!["x", "y", "z"]);
+    /// assert_eq!(grads.to_dense(), vec![4.0, 0.0, 2.0]);
+    /// ```
+    #[inline]
+    pub fn to_dense(&self) -> Vec<V>
+    where
+        V: Real,
+    {
+        self.collect_mapped(|_, v| v)
+    }
+
+    /// Write gradients as CSV rows of `key,grad` sorted by the formatted key.
+    ///
+    /// A header row `key,grad` is written first.
+    /// Formatted keys containing commas, double quotes or line breaks are quoted
+    /// and double quotes in them are doubled as RFC 4180.
+    /// Non-finite gradients are written as they are formatted, e.g. `NaN`, `inf` and `-inf`,
+    /// so that they can be parsed back with [`str::parse`].
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::Graph;
+    ///
+    /// let graph = Graph::new();
+    /// let x = graph.create_var("x", 2.0).unwrap();
+    /// let y = graph.create_var("y", 3.0).unwrap();
+    /// let grads = (x.as_ref() * y.as_ref()).grads().unwrap();
+    ///
+    /// let mut buf = Vec::new();
+    /// grads.write_csv(&mut buf, |k| k.to_string()).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "key,grad\nx,3\ny,2\n");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    pub fn write_csv<W, F>(&self, mut w: W, key_fmt: F) -> std::io::Result<()>
+    where
+        V: Real + Display,
+        W: Write,
+        F: Fn(&K) -> String,
+    {
+        let mut rows: Vec<_> = self.collect_mapped(|k, v| (key_fmt(k), v));
+        rows.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+
+        writeln!(w, "key,grad")?;
+        for (key, grad) in rows {
+            if key.contains([',', '"', '\n', '\r']) {
+                writeln!(w, "\"{}\",{grad}", key.replace('"', "\"\""))?;
+            } else {
+                writeln!(w, "{key},{grad}")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -196,10 +269,16 @@ impl<K, V> Grads<K, V> {
 ///
 /// [Grads] is immutable object.
 /// Hence, we can not accumulate
+///
+/// Gradients can also be merged by variable key from accumulators of other graphs,
+/// e.g. per-thread graphs of parallel Monte Carlo simulation.
+/// Gradients of keys which are not variables of this graph are kept separately
+/// and they are available via [`GradsAccum::collect`] and [`GradsAccum::into_map`].
 #[derive(Debug, Clone)]
 pub struct GradsAccum<K, V> {
     graph: Graph<K, V>,
     grads: Vec<V>,
+    foreign: Vec<(K, V)>,
 }
 
 //
@@ -212,6 +291,7 @@ impl<K, V> GradsAccum<K, V> {
         Self {
             graph,
             grads: Vec::new(),
+            foreign: Vec::new(),
         }
     }
 }
@@ -222,7 +302,8 @@ impl<K, V> GradsAccum<K, V> {
 impl<K, V> GradsAccum<K, V> {
     /// Returns the gradient of the variable.
     ///
-    /// If the variable belongs to different graph, this method returns zero.
+    /// If the variable belongs to different graph, this method returns zero
+    /// even if gradients of the same key are merged from other graphs.
     #[inline]
     pub fn wrt(&self, var: &Var<K, V>) -> V
     where
@@ -238,23 +319,43 @@ impl<K, V> GradsAccum<K, V> {
     #[inline]
     pub fn collect<R>(&self) -> R
     where
-        K: Clone,
+        K: Clone + Eq + Hash,
         V: Real,
         R: FromIterator<(K, V)>,
     {
         let internal = self.graph.0.borrow();
         let vars = internal.tape._vars();
+        let index = _var_index(vars);
+        let mut grads: Vec<_> = (0..vars.len())
+            .map(|i| self.grads.get(i).cloned().unwrap_or_else(V::zero))
+            .collect();
+        let mut foreign = Vec::new();
+        for (key, grad) in &self.foreign {
+            match index.get(key) {
+                Some(&i) => grads[i] += grad,
+                None => foreign.push((key.clone(), grad.clone())),
+            }
+        }
         vars.iter()
-            .enumerate()
-            .map(|(i, idx)| {
-                (
-                    idx.key.clone(),
-                    self.grads.get(i).cloned().unwrap_or_else(V::zero),
-                )
-            })
+            .map(|v| v.key.clone())
+            .zip(grads)
+            .chain(foreign)
             .collect()
     }
 
+    /// Convert accumulated gradients into a map from variable keys.
+    ///
+    /// Unlike this instance, the result does not refer to the graph,
+    /// so it can be sent to other threads if `K` and `V` are [`Send`].
+    #[inline]
+    pub fn into_map(self) -> HashMap<K, V>
+    where
+        K: Clone + Eq + Hash,
+        V: Real,
+    {
+        self.collect()
+    }
+
     /// Accumulate gradients to this instance.
     ///
     /// This method accumulates gradients passed by the argument with the closure `f`.
@@ -281,12 +382,120 @@ impl<K, V> GradsAccum<K, V> {
         }
         Ok(())
     }
+
+    /// Merge another accumulator into this instance by summing gradients by variable key.
+    ///
+    /// This is intended to combine partial results, e.g. accumulators of Monte Carlo batches.
+    /// `other` may belong to a different graph.
+    /// Gradients of variables which only one of the accumulators has are carried over.
+    #[inline]
+    pub fn merge(&mut self, other: GradsAccum<K, V>)
+    where
+        K: Clone + Eq + Hash,
+        V: Real,
+    {
+        if !Graph::ptr_eq(&self.graph, &other.graph) {
+            let other: Vec<_> = other.collect();
+            self.merge_by_key(other);
+            return;
+        }
+        if self.grads.len() < other.grads.len() {
+            self.grads.resize(other.grads.len(), V::zero());
+        }
+        for (i, grad) in other.grads.iter().enumerate() {
+            self.grads[i] += grad;
+        }
+        self.merge_by_key(other.foreign);
+    }
+
+    /// Merge gradients keyed by variable into this instance by summing them.
+    ///
+    /// This is the counterpart of [`GradsAccum::into_map`] to combine results of other threads,
+    /// since accumulators themselves can not be sent across threads.
+    /// Gradients of keys which are not variables of this graph are carried over.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::Graph;
+    ///
+    /// let partial = std::thread::spawn(|| {
+    ///     let graph = Graph::new();
+    ///     let y = graph.create_var("y", 3.0).unwrap();
+    ///     let mut accum = graph.gen_grads_accum();
+    ///     let expr = y.as_ref() * y.as_ref();
+    ///     accum.accum(&expr.grads().unwrap(), |acc, g| *acc += g).unwrap();
+    ///     accum.into_map()
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// let graph = Graph::new();
+    /// let x = graph.create_var("x", 2.0).unwrap();
+    /// let mut accum = graph.gen_grads_accum();
+    /// let expr = x.as_ref() * x.as_ref();
+    /// accum.accum(&expr.grads().unwrap(), |acc, g| *acc += g).unwrap();
+    ///
+    /// accum.merge_by_key(partial);
+    ///
+    /// let merged = accum.into_map();
+    /// assert_eq!(merged.len(), 2);
+    /// assert_eq!(merged["x"], 4.0);
+    /// assert_eq!(merged["y"], 6.0);
+    /// ```
+    pub fn merge_by_key<I>(&mut self, grads: I)
+    where
+        K: Eq + Hash,
+        V: Real,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let internal = self.graph.0.borrow();
+        let vars = internal.tape._vars();
+        let index = _var_index(vars);
+
+        // keys merged before the variable is created are folded here as well
+        let foreign = std::mem::take(&mut self.foreign);
+        for (key, grad) in foreign.into_iter().chain(grads) {
+            if let Some(&i) = index.get(&key) {
+                if self.grads.len() <= i {
+                    self.grads.resize(i + 1, V::zero());
+                }
+                self.grads[i] += &grad;
+            } else if let Some((_, acc)) = self.foreign.iter_mut().find(|(k, _)| k == &key) {
+                *acc += &grad;
+            } else {
+                self.foreign.push((key, grad));
+            }
+        }
+    }
+}
+
+#[inline]
+fn _var_index<K: Eq + Hash>(vars: &[_VarIdx<K>]) -> HashMap<&K, usize> {
+    vars.iter().enumerate().map(|(i, v)| (&v.key, i)).collect()
+}
+
+/// Sum up accumulators with [`GradsAccum::merge`].
+///
+/// The result is `None` if the iterator is empty.
+impl<K, V> std::iter::Sum<GradsAccum<K, V>> for Option<GradsAccum<K, V>>
+where
+    K: Clone + Eq + Hash,
+    V: Real,
+{
+    fn sum<I: Iterator<Item = GradsAccum<K, V>>>(iter: I) -> Self {
+        iter.reduce(|mut acc, other| {
+            acc.merge(other);
+            acc
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
+    use qmath::num::Sqrt;
+
     use super::*;
 
     #[test]
@@ -327,6 +536,76 @@ mod tests {
         assert_eq!(dvdz, 0.);
     }
 
+    #[test]
+    fn test_grads_to_dense() {
+        let graph = Graph::new();
+        let varz = graph.create_var("z", 2.3f64).unwrap();
+        let varx = graph.create_var("x", 4.2f64).unwrap();
+        let vary = graph.create_var("y", 3.1f64).unwrap();
+        let x = varx.as_ref();
+        let z = varz.as_ref();
+        let expr = x * x + z;
+        let grads = expr.grads().unwrap();
+
+        let dense = grads.to_dense();
+
+        assert_eq!(graph.var_order(), vec!["z", "x", "y"]);
+        assert_eq!(dense, vec![1., 2. * 4.2, 0.]);
+        assert_eq!(dense[0], grads.wrt(&varz));
+        assert_eq!(dense[1], grads.wrt(&varx));
+        assert_eq!(dense[2], grads.wrt(&vary));
+    }
+
+    #[test]
+    fn test_grads_write_csv() {
+        let graph = Graph::new();
+        let varz = graph.create_var("z", -1f64).unwrap();
+        let varx = graph.create_var("x", 4f64).unwrap();
+        let vary = graph.create_var("y", 0f64).unwrap();
+        let expr = varx.as_ref().clone().sqrt()
+            + vary.as_ref().clone().sqrt()
+            + varz.as_ref().clone().sqrt();
+        let grads = expr.grads().unwrap();
+
+        let mut buf = Vec::new();
+        grads.write_csv(&mut buf, |k| k.to_string()).unwrap();
+        let csv = String::from_utf8(buf).unwrap();
+        let parsed = csv
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let (k, v) = line.split_once(',').unwrap();
+                (k.to_string(), v.parse::<f64>().unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(csv, "key,grad\nx,0.25\ny,inf\nz,NaN\n");
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0], ("x".to_string(), grads.wrt(&varx)));
+        assert_eq!(parsed[1], ("y".to_string(), grads.wrt(&vary)));
+        assert_eq!(parsed[2].0, "z");
+        assert!(parsed[2].1.is_nan() && grads.wrt(&varz).is_nan());
+    }
+
+    #[test]
+    fn test_grads_write_csv_quoted() {
+        let graph = Graph::new();
+        let varx = graph.create_var("usd,jpy", 2f64).unwrap();
+        let vary = graph.create_var("leg \"fixed\"", 3f64).unwrap();
+        let varz = graph.create_var("line\nbreak", 4f64).unwrap();
+        let expr = varx.as_ref() * vary.as_ref() + varz.as_ref();
+        let grads = expr.grads().unwrap();
+
+        let mut buf = Vec::new();
+        grads.write_csv(&mut buf, |k| k.to_string()).unwrap();
+        let csv = String::from_utf8(buf).unwrap();
+
+        assert_eq!(
+            csv,
+            "key,grad\n\"leg \"\"fixed\"\"\",2\n\"line\nbreak\",1\n\"usd,jpy\",3\n"
+        );
+    }
+
     #[test]
     fn test_grads_accum_wrt() {
         let graph = Graph::new();
@@ -391,4 +670,161 @@ mod tests {
         assert_eq!(collected[&"y"], 2.);
         assert_eq!(collected[&"z"], 8.);
     }
+
+    fn _partial_accum(
+        graph: &Graph<&'static str, f64>,
+        vars: &[&Var<&'static str, f64>],
+    ) -> GradsAccum<&'static str, f64> {
+        let mut accum = graph.gen_grads_accum();
+        for var in vars {
+            let x = var.as_ref();
+            let expr = x * x;
+            accum.accum(&expr.grads().unwrap(), |x, y| *x += y).unwrap();
+        }
+        accum
+    }
+
+    #[test]
+    fn test_grads_accum_merge() {
+        let graph = Graph::new();
+        let varx = graph.create_var("x", 1.).unwrap();
+        let vary = graph.create_var("y", 2.).unwrap();
+        let varz = graph.create_var("z", 3.).unwrap();
+        let mut accum1 = _partial_accum(&graph, &[&varx, &vary]);
+        let accum2 = _partial_accum(&graph, &[&vary, &varz]);
+
+        accum1.merge(accum2);
+
+        assert_eq!(accum1.wrt(&varx), 2.);
+        assert_eq!(accum1.wrt(&vary), 8.);
+        assert_eq!(accum1.wrt(&varz), 6.);
+    }
+
+    #[test]
+    fn test_grads_accum_merge_different_graphs() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let varx = graph1.create_var("x", 1.).unwrap();
+        let vary1 = graph1.create_var("y", 2.).unwrap();
+        let vary2 = graph2.create_var("y", 2.).unwrap();
+        let varz = graph2.create_var("z", 3.).unwrap();
+        let mut accum1 = _partial_accum(&graph1, &[&varx, &vary1]);
+        let accum2 = _partial_accum(&graph2, &[&vary2, &varz]);
+
+        accum1.merge(accum2);
+        let merged = accum1.collect::<HashMap<_, _>>();
+
+        assert_eq!(accum1.wrt(&varx), 2.);
+        assert_eq!(accum1.wrt(&vary1), 8.);
+        assert_eq!(accum1.wrt(&varz), 0.);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[&"x"], 2.);
+        assert_eq!(merged[&"y"], 8.);
+        assert_eq!(merged[&"z"], 6.);
+    }
+
+    #[test]
+    fn test_grads_accum_merge_before_var_creation() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let varx = graph1.create_var("x", 1.).unwrap();
+        let varz2 = graph2.create_var("z", 3.).unwrap();
+        let mut accum = _partial_accum(&graph1, &[&varx]);
+        accum.merge(_partial_accum(&graph2, &[&varz2]));
+
+        let varz1 = graph1.create_var("z", 4.).unwrap();
+        let expr = varz1.as_ref() * varz1.as_ref();
+        accum.accum(&expr.grads().unwrap(), |x, y| *x += y).unwrap();
+        let collected = accum.collect::<Vec<_>>();
+        let before_fold = accum.wrt(&varz1);
+        accum.merge_by_key(Vec::new());
+        let after_fold = accum.wrt(&varz1);
+        let merged = accum.into_map();
+
+        assert_eq!(collected.len(), 2);
+        assert_eq!(before_fold, 8.);
+        assert_eq!(after_fold, 14.);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[&"x"], 2.);
+        assert_eq!(merged[&"z"], 14.);
+    }
+
+    #[test]
+    fn test_grads_accum_merge_across_threads() {
+        let partials = std::thread::scope(|s| {
+            let handles: Vec<_> = [vec![("x", 1.), ("y", 2.)], vec![("y", 2.), ("z", 3.)]]
+                .into_iter()
+                .map(|vars| {
+                    s.spawn(move || {
+                        let graph = Graph::new();
+                        let vars: Vec<_> = vars
+                            .into_iter()
+                            .map(|(k, v)| graph.create_var(k, v).unwrap())
+                            .collect();
+                        let vars: Vec<_> = vars.iter().collect();
+                        _partial_accum(&graph, &vars).into_map()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        let graph = Graph::new();
+        let varx = graph.create_var("x", 1.).unwrap();
+        let mut accum = graph.gen_grads_accum();
+
+        for partial in partials {
+            accum.merge_by_key(partial);
+        }
+        let merged = accum.clone().into_map();
+
+        assert_eq!(accum.wrt(&varx), 2.);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[&"x"], 2.);
+        assert_eq!(merged[&"y"], 8.);
+        assert_eq!(merged[&"z"], 6.);
+    }
+
+    #[test]
+    fn test_grads_accum_sum() {
+        let graph = Graph::new();
+        let varx = graph.create_var("x", 1.).unwrap();
+        let vary = graph.create_var("y", 2.).unwrap();
+        let varz = graph.create_var("z", 3.).unwrap();
+        let accums = vec![
+            _partial_accum(&graph, &[&varx]),
+            _partial_accum(&graph, &[&varx, &vary]),
+            _partial_accum(&graph, &[&varz]),
+        ];
+
+        let summed: Option<GradsAccum<_, _>> = accums.into_iter().sum();
+        let empty: Option<GradsAccum<&str, f64>> = std::iter::empty().sum();
+
+        let summed = summed.unwrap();
+        assert_eq!(summed.wrt(&varx), 4.);
+        assert_eq!(summed.wrt(&vary), 4.);
+        assert_eq!(summed.wrt(&varz), 6.);
+        assert!(empty.is_none());
+    }
+
+    #[test]
+    fn test_grads_accum_sum_different_graphs() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let varx = graph1.create_var("x", 1.).unwrap();
+        let vary = graph2.create_var("y", 2.).unwrap();
+        let accums = vec![
+            _partial_accum(&graph1, &[&varx]),
+            _partial_accum(&graph2, &[&vary]),
+        ];
+
+        let summed: Option<GradsAccum<_, _>> = accums.into_iter().sum();
+
+        let summed = summed.unwrap().into_map();
+        assert_eq!(summed.len(), 2);
+        assert_eq!(summed[&"x"], 2.);
+        assert_eq!(summed[&"y"], 4.);
+    }
 }
@@ -1,11 +1,17 @@
-use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use std::{
+    cell::{RefCell, RefMut},
+    fmt::Debug,
+    rc::Rc,
+};
+
+use qmath::num::Real;
 
-use crate::{Error, Var};
+use crate::{Error, Expr, Var};
 
 use super::{
-    grads::{GradsAccum, _GradPool},
+    grads::{_GradPool, GradsAccum},
     tape::{_BackPropWorkSpace, _Tape},
-    Node,
+    GraphvizBuilder, Node,
 };
 
 // -----------------------------------------------------------------------------
@@ -19,6 +25,54 @@ pub(super) struct _Graph<K, V> {
     pub(super) grad_pool: _GradPool<V>,
 }
 
+/// Computational graph which records operations on variables for automatic differentiation.
+///
+/// # Invariant
+/// Each [Var] and [crate::Expr] created on a graph holds a strong reference to it,
+/// so the graph outlives all expressions which refer to it.
+/// Operations on expressions mutably borrow the internal tape,
+/// and they must not be performed while the tape is borrowed elsewhere.
+/// [`Graph::is_alive`] reports whether this invariant allows operations now.
+///
+/// # Threads
+/// Graph is neither [`Send`] nor [`Sync`] because every clone and drop of an expression
+/// updates reference counts on the tape. Sharing a tape with a lock would serialize all threads
+/// on these updates, so parallel computations, e.g. path-wise pricing, should create
+/// an independent graph on each thread and combine values and gradients afterwards.
+/// Gradients are combined by variable key with [`GradsAccum::into_map`],
+/// which can be sent across threads, and [`GradsAccum::merge_by_key`].
+/// [`super::SyncGraph`] packages this pattern with variables shared by threads.
+///
+/// ```
+/// use qautodiff::Graph;
+///
+/// let partials = std::thread::scope(|s| {
+///     let handles: Vec<_> = (1..=4)
+///         .map(|i| {
+///             s.spawn(move || {
+///                 let graph = Graph::new();
+///                 let x = graph.create_var("x", 2.0).unwrap();
+///                 let y = x.as_ref() * x.as_ref() * (i as f64);
+///                 let mut accum = graph.gen_grads_accum();
+///                 accum.accum(&y.grads().unwrap(), |acc, g| *acc += g).unwrap();
+///                 (y.value(), accum.into_map())
+///             })
+///         })
+///         .collect();
+///     handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+/// });
+///
+/// let graph = Graph::new();
+/// let mut total = graph.gen_grads_accum();
+/// let mut value = 0.0;
+/// for (v, grads) in partials {
+///     value += v;
+///     total.merge_by_key(grads);
+/// }
+///
+/// assert_eq!(value, 40.0);
+/// assert_eq!(total.into_map()["x"], 40.0);
+/// ```
 #[derive(Debug)]
 pub struct Graph<K, V>(pub(super) Rc<RefCell<_Graph<K, V>>>);
 
@@ -69,10 +123,128 @@ impl<K, V> Graph<K, V> {
         Rc::ptr_eq(&lhs.0, &rhs.0)
     }
 
+    /// Check that the internal tape of this graph is accessible.
+    ///
+    /// Because expressions keep their graph alive, this returns `false` only when
+    /// the tape is borrowed by another operation, e.g. re-entrance during back propagation.
+    /// Operations on expressions panic with a descriptive message in debug builds in such a case.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.try_borrow_mut().is_ok()
+    }
+
+    /// Keys of variables in the order of their creation.
+    ///
+    /// This order is the layout of dense gradients, e.g. [`crate::Grads::to_dense`].
+    #[inline]
+    pub fn var_order(&self) -> Vec<K>
+    where
+        K: Clone,
+    {
+        let internal = self.0.borrow();
+        internal
+            .tape
+            ._vars()
+            .iter()
+            .map(|v| v.key.clone())
+            .collect()
+    }
+
+    /// Number of variables created on this graph.
+    #[inline]
+    pub fn num_vars(&self) -> usize {
+        self.0.borrow().tape._vars().len()
+    }
+
+    /// Number of tape cells which are referred to by variables or living expressions.
+    ///
+    /// Variables are never cleaned up, so this is at least [`Graph::num_vars`].
+    /// This is useful to check that repeated calculations, e.g. Monte Carlo paths,
+    /// do not leak nodes on the tape.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::Graph;
+    ///
+    /// let graph = Graph::new();
+    /// let x = graph.create_var("x", 2.0).unwrap();
+    /// assert_eq!((graph.num_vars(), graph.num_live_cells(), graph.num_vacant()), (1, 1, 0));
+    ///
+    /// let y = x.as_ref() * x.as_ref() + 1.0;
+    /// assert_eq!((graph.num_vars(), graph.num_live_cells(), graph.num_vacant()), (1, 3, 0));
+    ///
+    /// drop(y);
+    /// assert_eq!((graph.num_vars(), graph.num_live_cells(), graph.num_vacant()), (1, 1, 2));
+    /// ```
+    #[inline]
+    pub fn num_live_cells(&self) -> usize {
+        let internal = self.0.borrow();
+        internal.tape._num_cells() - internal.tape._num_vacant()
+    }
+
+    /// Number of vacated tape cells which are reused for new expressions.
+    #[inline]
+    pub fn num_vacant(&self) -> usize {
+        self.0.borrow().tape._num_vacant()
+    }
+
     #[inline]
     pub fn gen_grads_accum(&self) -> GradsAccum<K, V> {
         GradsAccum::new(self.clone())
     }
+
+    /// Generate a graphviz builder of multiple labeled expressions on this graph.
+    ///
+    /// Unlike [`Expr::graphviz`], this renders several outputs, e.g. price and its components,
+    /// in one graph. Each root is rendered with its label as an external label and
+    /// a highlighted border. Labels are escaped, so they may contain any characters.
+    /// Gradients shown on nodes are those of the sum of the roots.
+    /// Constant expressions are skipped since they have no node on the graph.
+    ///
+    /// # Errors
+    /// Returns [`Error::DifferentGraphs`] if some expression belongs to another graph.
+    ///
+    /// # Example
+    /// ```
+    /// use qautodiff::Graph;
+    ///
+    /// let graph = Graph::new();
+    /// let x = graph.create_var("x", 1.0).unwrap();
+    /// let y = graph.create_var("y", 2.0).unwrap();
+    /// let pv = x.as_ref() * y.as_ref();
+    /// let fee = x.as_ref() + y.as_ref();
+    ///
+    /// let dot = graph
+    ///     .graphviz(&[("pv", &pv), ("fee", &fee)])
+    ///     .unwrap()
+    ///     .with_key_formatter(|k| k.to_string())
+    ///     .with_value_formatter(|v| v.to_string())
+    ///     .gen_dot();
+    ///
+    /// assert!(dot.contains(r#"xlabel="pv""#));
+    /// assert!(dot.contains(r#"xlabel="fee""#));
+    /// ```
+    pub fn graphviz<L>(
+        &self,
+        roots: &[(L, &Expr<K, V>)],
+    ) -> Result<GraphvizBuilder<K, V, (), ()>, Error<K>>
+    where
+        K: Clone,
+        V: Real,
+        L: AsRef<str>,
+    {
+        let mut nodes = Vec::with_capacity(roots.len());
+        for (label, expr) in roots {
+            let Some(node) = expr._as_node() else {
+                continue;
+            };
+            if !Graph::ptr_eq(self, node._graph()) {
+                return Err(Error::DifferentGraphs("graphviz"));
+            }
+            nodes.push((Some(label.as_ref()), node));
+        }
+        Ok(Node::_dotize_roots(self, &nodes))
+    }
 }
 
 // impls
@@ -81,6 +253,16 @@ impl<K, V> Graph<K, V> {
     pub(super) fn _debug_ptr(&self) -> impl std::fmt::Debug {
         self.0.as_ptr()
     }
+
+    #[inline]
+    pub(super) fn _borrow_mut(&self) -> RefMut<'_, _Graph<K, V>> {
+        debug_assert!(
+            self.is_alive(),
+            "The tape of graph({:?}) is not accessible. It is borrowed by another operation.",
+            self._debug_ptr()
+        );
+        self.0.borrow_mut()
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +298,36 @@ mod tests {
         assert!(Graph::ptr_eq(&graph1, &graph2));
     }
 
+    #[test]
+    fn test_is_alive() {
+        let graph = Graph::<&'static str, f64>::new();
+        let x = graph.create_var("x", 1.0).unwrap();
+
+        let borrowed = graph.0.borrow();
+        let alive_while_borrowed = graph.is_alive();
+        drop(borrowed);
+
+        assert!(graph.is_alive());
+        assert!(!alive_while_borrowed);
+        assert!(x.value() == 1.0);
+    }
+
+    #[test]
+    fn test_ops_on_same_live_graph() {
+        let graph = Graph::<&'static str, f64>::new();
+        let x = graph.create_var("x", 2.0).unwrap();
+        let y = graph.create_var("y", 3.0).unwrap();
+        let x_ptr = format!("{:?}", x._node()._graph()._debug_ptr());
+        let y_ptr = format!("{:?}", y._node()._graph()._debug_ptr());
+
+        let z = x.as_ref() * y.as_ref();
+
+        assert!(graph.is_alive());
+        assert_eq!(x_ptr, y_ptr);
+        assert_eq!(x_ptr, format!("{:?}", graph._debug_ptr()));
+        assert_eq!(z.value(), 6.0);
+    }
+
     #[test]
     fn test_ptr_neq() {
         let graph1 = Graph::<&'static str, f64>::new();
@@ -123,4 +335,51 @@ mod tests {
 
         assert!(!Graph::ptr_eq(&graph1, &graph2));
     }
+
+    #[test]
+    fn test_not_send_sync() {
+        static_assertions::assert_not_impl_any!(Graph<&'static str, f64>: Send, Sync);
+    }
+
+    #[test]
+    fn test_independent_graphs_on_threads() {
+        let n = 8;
+
+        let tested = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..n)
+                .map(|i| {
+                    s.spawn(move || {
+                        let graph = Graph::new();
+                        let x = graph.create_var("x", i as f64).unwrap();
+                        let y = graph.create_var("y", 2.0).unwrap();
+                        let z = x.as_ref() * y.as_ref() + x.as_ref();
+                        let grads = z.grads().unwrap();
+                        let mut accum = graph.gen_grads_accum();
+                        accum.accum(&grads, |acc, g| *acc += g).unwrap();
+                        (z.value(), grads.wrt(&x), grads.wrt(&y), accum.into_map())
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        let graph = Graph::new();
+        let y = graph.create_var("y", 2.0).unwrap();
+        let mut total = graph.gen_grads_accum();
+
+        for (i, (value, dx, dy, grads)) in tested.into_iter().enumerate() {
+            assert_eq!(value, 3. * i as f64);
+            assert_eq!(dx, 3.);
+            assert_eq!(dy, i as f64);
+            total.merge_by_key(grads);
+        }
+        let combined = total.collect::<std::collections::HashMap<_, _>>();
+
+        assert_eq!(total.wrt(&y), (0..n).sum::<usize>() as f64);
+        assert_eq!(combined.len(), 2);
+        assert_eq!(combined[&"x"], 3. * n as f64);
+        assert_eq!(combined[&"y"], (0..n).sum::<usize>() as f64);
+    }
 }
@@ -0,0 +1,288 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use qmath::num::Real;
+
+use crate::{Error, Var};
+
+use super::{grads::GradsAccum, Graph};
+
+// -----------------------------------------------------------------------------
+// _SyncGraph
+// SyncGraph
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+struct _SyncGraph<K, V> {
+    vars: Vec<(K, V)>,
+    grads: HashMap<K, V>,
+}
+
+type _Local<K, V> = (Graph<K, V>, Vec<Var<K, V>>);
+
+/// Thread-safe counterpart of [`Graph`] for parallel computations, e.g. path-wise pricing.
+///
+/// This is a pool of thread-local tapes sharing variables.
+/// Variables are registered on this instance and each thread records operations
+/// on its own [`Graph`] generated by [`SyncGraph::local`],
+/// which has all registered variables with the same keys and values.
+/// So expressions on each thread are usual [`crate::Expr`] and all operations on them are available.
+/// Gradients of each thread are merged into this instance by variable key with [`SyncGraph::merge`].
+///
+/// # Performance
+/// Variables and merged gradients are guarded by [`RwLock`], but operations on expressions
+/// are not since they are performed on thread-local tapes.
+/// So the lock is taken only when variables are registered, a local graph is generated
+/// and gradients are merged, and threads do not contend with each other during calculation.
+/// Sharing one tape with a lock, instead, would serialize all threads
+/// because every operation, and even every clone and drop of an expression, updates the tape.
+///
+/// On the other hand, generating a local graph copies all variables.
+/// Hence, it is recommended to generate one local graph per thread or per batch of paths,
+/// reuse it for paths and merge accumulated gradients once at the end.
+/// Expressions of each path are cleaned up from the tape when they are dropped.
+///
+/// # Example
+/// ```
+/// use qautodiff::SyncGraph;
+///
+/// let graph = SyncGraph::new();
+/// graph.create_var("x", 2.0).unwrap();
+///
+/// std::thread::scope(|s| {
+///     for i in 1..=4 {
+///         let graph = &graph;
+///         s.spawn(move || {
+///             let (local, vars) = graph.local().unwrap();
+///             let mut accum = local.gen_grads_accum();
+///             let y = vars[0].as_ref() * vars[0].as_ref() * (i as f64);
+///             accum.accum(&y.grads().unwrap(), |acc, g| *acc += g).unwrap();
+///             graph.merge(accum);
+///         });
+///     }
+/// });
+///
+/// assert_eq!(graph.grads()["x"], 40.0);
+/// ```
+#[derive(Debug)]
+pub struct SyncGraph<K, V>(Arc<RwLock<_SyncGraph<K, V>>>);
+
+impl<K, V> Clone for SyncGraph<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+//
+// ctor
+//
+impl<K, V> Default for SyncGraph<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> SyncGraph<K, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self(Arc::new(RwLock::new(_SyncGraph {
+            vars: Vec::new(),
+            grads: HashMap::new(),
+        })))
+    }
+}
+
+//
+// methods
+//
+// Registered variables and merged gradients are always left consistent,
+// so poisoned locks are recovered.
+impl<K, V> SyncGraph<K, V> {
+    /// Register a new variable which is shared by all local graphs generated after this call.
+    pub fn create_var(&self, key: K, value: V) -> Result<(), Error<K>>
+    where
+        K: Eq,
+    {
+        let mut internal = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        if internal.vars.iter().any(|(k, _)| k == &key) {
+            return Err(Error::VarAlreadyExists(key));
+        }
+        internal.vars.push((key, value));
+        Ok(())
+    }
+
+    /// Keys of registered variables in the order of their creation.
+    #[inline]
+    pub fn var_order(&self) -> Vec<K>
+    where
+        K: Clone,
+    {
+        let internal = self.0.read().unwrap_or_else(PoisonError::into_inner);
+        internal.vars.iter().map(|(k, _)| k.clone()).collect()
+    }
+
+    /// Generate a graph for the current thread with all registered variables.
+    ///
+    /// Variables are returned in the order of [`SyncGraph::var_order`].
+    /// Variables created on the returned graph afterwards are local to it,
+    /// but their gradients are merged by key as well.
+    pub fn local(&self) -> Result<_Local<K, V>, Error<K>>
+    where
+        K: Clone + Debug + Eq,
+        V: Clone,
+    {
+        let internal = self.0.read().unwrap_or_else(PoisonError::into_inner);
+        let graph = Graph::new();
+        let vars = internal
+            .vars
+            .iter()
+            .map(|(k, v)| graph.create_var(k.clone(), v.clone()))
+            .collect::<Result<_, _>>()?;
+        Ok((graph, vars))
+    }
+
+    /// Merge gradients accumulated on a local graph by summing them by variable key.
+    pub fn merge(&self, accum: GradsAccum<K, V>)
+    where
+        K: Clone + Eq + Hash,
+        V: Real,
+    {
+        let grads = accum.into_map();
+        let mut internal = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        for (key, grad) in grads {
+            *internal.grads.entry(key).or_insert_with(V::zero) += &grad;
+        }
+    }
+
+    /// Gradients merged so far by variable key.
+    #[inline]
+    pub fn grads(&self) -> HashMap<K, V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let internal = self.0.read().unwrap_or_else(PoisonError::into_inner);
+        internal.grads.clone()
+    }
+
+    /// Clear merged gradients, e.g. to start a new calculation with the same variables.
+    #[inline]
+    pub fn clear_grads(&self) {
+        let mut internal = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        internal.grads.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_sync() {
+        static_assertions::assert_impl_all!(SyncGraph<&'static str, f64>: Send, Sync);
+    }
+
+    #[test]
+    fn test_create_var() {
+        let graph = SyncGraph::new();
+
+        graph.create_var("x", 1.0).unwrap();
+        graph.create_var("y", 2.0).unwrap();
+        let (local, vars) = graph.local().unwrap();
+
+        assert_eq!(graph.var_order(), vec!["x", "y"]);
+        assert_eq!(local.var_order(), vec!["x", "y"]);
+        assert_eq!(vars[0].value(), 1.0);
+        assert_eq!(vars[1].value(), 2.0);
+    }
+
+    #[test]
+    fn test_create_var_err_already_exists() {
+        let graph = SyncGraph::new();
+
+        graph.create_var("x", 1.0).unwrap();
+        let err = graph.create_var("x", 2.0);
+
+        assert_eq!(err, Err(Error::VarAlreadyExists("x")));
+        assert_eq!(graph.var_order(), vec!["x"]);
+    }
+
+    #[test]
+    fn test_local_graphs_are_independent() {
+        let graph = SyncGraph::new();
+        graph.create_var("x", 1.0).unwrap();
+
+        let (local1, _) = graph.local().unwrap();
+        let (local2, _) = graph.local().unwrap();
+        local1.create_var("y", 2.0).unwrap();
+
+        assert!(!Graph::ptr_eq(&local1, &local2));
+        assert_eq!(local1.num_vars(), 2);
+        assert_eq!(local2.num_vars(), 1);
+        assert_eq!(graph.var_order(), vec!["x"]);
+    }
+
+    #[test]
+    fn test_backprop_on_threads() {
+        let n = 8;
+        let paths = 100;
+        let graph = SyncGraph::new();
+        graph.create_var("x".to_string(), 2.0).unwrap();
+        graph.create_var("y".to_string(), 3.0).unwrap();
+
+        std::thread::scope(|s| {
+            for i in 0..n {
+                let graph = graph.clone();
+                s.spawn(move || {
+                    let (local, vars) = graph.local().unwrap();
+                    let z = local.create_var(format!("z{i}"), i as f64).unwrap();
+                    let mut accum = local.gen_grads_accum();
+                    for _ in 0..paths {
+                        let (x, y) = (vars[0].as_ref(), vars[1].as_ref());
+                        let w = x * y + x * z.as_ref();
+                        accum
+                            .accum(&w.grads().unwrap(), |acc, g| *acc += g)
+                            .unwrap();
+                    }
+                    assert_eq!(local.num_live_cells(), 3);
+                    graph.merge(accum);
+                });
+            }
+        });
+        let tested = graph.grads();
+
+        let sum_z = (0..n).sum::<usize>() as f64;
+        let paths = paths as f64;
+        assert_eq!(tested.len(), 2 + n);
+        assert_eq!(tested["x"], paths * (3.0 * n as f64 + sum_z));
+        assert_eq!(tested["y"], paths * 2.0 * n as f64);
+        for i in 0..n {
+            assert_eq!(tested[&format!("z{i}")], paths * 2.0);
+        }
+    }
+
+    #[test]
+    fn test_clear_grads() {
+        let graph = SyncGraph::new();
+        graph.create_var("x", 2.0).unwrap();
+        let (local, vars) = graph.local().unwrap();
+        let mut accum = local.gen_grads_accum();
+        let y = vars[0].as_ref() * vars[0].as_ref();
+        accum
+            .accum(&y.grads().unwrap(), |acc, g| *acc += g)
+            .unwrap();
+        graph.merge(accum);
+
+        let before = graph.grads();
+        graph.clear_grads();
+
+        assert_eq!(before["x"], 4.0);
+        assert!(graph.grads().is_empty());
+        assert_eq!(graph.var_order(), vec!["x"]);
+    }
+}
@@ -28,14 +28,29 @@ enum _Node<V> {
     Exp { value: V, index: usize },
     Log { value: V, index: usize },
     Erf { value: V, index: usize },
+    Expm1 { value: V, index: usize },
+    Log1p { value: V, index: usize },
     Sqrt { value: V, index: usize },
+    Abs { value: V, index: usize },
     Powi { value: V, index: usize, exp: i32 },
+    Atan2L { value: V, lhs: usize, rhs: V },
+    Atan2R { value: V, lhs: V, rhs: usize },
+    HypotL { value: V, lhs: usize, rhs: V },
+    HypotR { value: V, lhs: V, rhs: usize },
+    MaxL { value: V, lhs: usize, rhs: V },
+    MaxR { value: V, lhs: V, rhs: usize },
+    MinL { value: V, lhs: usize, rhs: V },
+    MinR { value: V, lhs: V, rhs: usize },
 
     // binary
     Add { value: V, lhs: usize, rhs: usize },
     Sub { value: V, lhs: usize, rhs: usize },
     Mul { value: V, lhs: usize, rhs: usize },
     Div { value: V, lhs: usize, rhs: usize },
+    Atan2 { value: V, lhs: usize, rhs: usize },
+    Hypot { value: V, lhs: usize, rhs: usize },
+    Max { value: V, lhs: usize, rhs: usize },
+    Min { value: V, lhs: usize, rhs: usize },
 
     // multi-ary
     Compressed { value: V, grads: Vec<V> },
@@ -58,12 +73,27 @@ impl<V> _Node<V> {
             | _Node::Exp { value, .. }
             | _Node::Log { value, .. }
             | _Node::Erf { value, .. }
+            | _Node::Expm1 { value, .. }
+            | _Node::Log1p { value, .. }
             | _Node::Sqrt { value, .. }
+            | _Node::Abs { value, .. }
             | _Node::Powi { value, .. }
+            | _Node::Atan2L { value, .. }
+            | _Node::Atan2R { value, .. }
+            | _Node::HypotL { value, .. }
+            | _Node::HypotR { value, .. }
+            | _Node::MaxL { value, .. }
+            | _Node::MaxR { value, .. }
+            | _Node::MinL { value, .. }
+            | _Node::MinR { value, .. }
             | _Node::Add { value, .. }
             | _Node::Sub { value, .. }
             | _Node::Mul { value, .. }
             | _Node::Div { value, .. }
+            | _Node::Atan2 { value, .. }
+            | _Node::Hypot { value, .. }
+            | _Node::Max { value, .. }
+            | _Node::Min { value, .. }
             | _Node::Compressed { value, .. } => value,
         }
     }
@@ -81,7 +111,7 @@ pub(crate) struct Node<K, V> {
 impl<K, V> Clone for Node<K, V> {
     #[inline]
     fn clone(&self) -> Self {
-        self.graph.0.borrow_mut().tape._incl_refcnt(self.index);
+        self.graph._borrow_mut().tape._incl_refcnt(self.index);
         Self {
             index: self.index,
             graph: self.graph.clone(),
@@ -92,7 +122,7 @@ impl<K, V> Clone for Node<K, V> {
 impl<K, V> Drop for Node<K, V> {
     #[inline]
     fn drop(&mut self) {
-        self.graph.0.borrow_mut().tape._decl_refcnt(self.index);
+        self.graph._borrow_mut().tape._decl_refcnt(self.index);
     }
 }
 
@@ -123,7 +153,7 @@ impl<K, V> Node<K, V> {
     where
         V: Real,
     {
-        let mut internal = self.graph.0.borrow_mut();
+        let mut internal = self.graph._borrow_mut();
         let internal = &mut *internal;
         internal.grad_pool._calc_grad(
             &mut internal.workspace,
@@ -152,7 +182,7 @@ impl<K, V> Node<K, V> {
             grads: self._grads().collect_mapped(|_, v| v),
         };
         Node {
-            index: self.graph.0.borrow_mut().tape._reg_node(node),
+            index: self.graph._borrow_mut().tape._reg_node(node),
             graph: self.graph.clone(),
         }
         .into()
@@ -164,15 +194,44 @@ impl<K, V> Node<K, V> {
         K: Clone,
         V: Real,
     {
+        Self::_dotize_roots(&self.graph, &[(None, self)])
+    }
+
+    /// Dotize the subgraph of the given roots which must belong to `graph`.
+    pub(crate) fn _dotize_roots(
+        graph: &Graph<K, V>,
+        roots: &[(Option<&str>, &Self)],
+    ) -> GraphvizBuilder<K, V, (), ()>
+    where
+        K: Clone,
+        V: Real,
+    {
+        debug_assert!(roots
+            .iter()
+            .all(|(_, node)| Graph::ptr_eq(graph, &node.graph)));
+        let cells: Vec<_> = roots.iter().map(|(_, node)| node.index).collect();
         let mut collector = _GraphvizGraph::default();
         {
-            let mut internal = self.graph.0.borrow_mut();
+            let mut internal = graph._borrow_mut();
             let internal = &mut *internal;
             internal
                 .workspace
-                ._back_prop(&internal.tape, self.index, &mut collector)
+                ._back_prop(&internal.tape, &cells, &mut collector)
                 .unwrap();
         }
+        let mut root_labels = BTreeMap::<usize, String>::new();
+        for (label, node) in roots {
+            let Some(label) = label else {
+                continue;
+            };
+            root_labels
+                .entry(collector.cell2node[&node.index])
+                .and_modify(|l| {
+                    l.push_str(", ");
+                    l.push_str(label);
+                })
+                .or_insert_with(|| label.to_string());
+        }
 
         let resolve_node = |node: &_GraphvizNodeIdx| match node {
             _GraphvizNodeIdx::Cell(idx) => collector.cell2node[idx],
@@ -190,6 +249,7 @@ impl<K, V> Node<K, V> {
             edges,
             graph_global_settings: Default::default(),
             node_global_settings: Default::default(),
+            root_labels,
             key_fmt: Default::default(),
             value_fmt: Default::default(),
         }
@@ -202,7 +262,7 @@ impl<K, V> Node<K, V> {
     where
         K: Eq,
     {
-        let index = graph.0.borrow_mut().tape._reg_var(key, value)?;
+        let index = graph._borrow_mut().tape._reg_var(key, value)?;
         Ok(Self {
             index,
             graph: graph.clone(),
@@ -370,6 +430,28 @@ pub(super) trait _BackProp<K, V> {
         Ok(())
     }
 
+    #[inline]
+    fn _on_expm1(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_log1p(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     #[inline]
     fn _on_sqrt(
         &mut self,
@@ -381,6 +463,17 @@ pub(super) trait _BackProp<K, V> {
         Ok(())
     }
 
+    #[inline]
+    fn _on_abs(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     #[inline]
     fn _on_powi(
         &mut self,
@@ -393,6 +486,102 @@ pub(super) trait _BackProp<K, V> {
         Ok(())
     }
 
+    #[inline]
+    fn _on_atan2l(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_atan2r(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypotl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypotr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_maxl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_maxr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_minl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_minr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     #[inline]
     fn _on_add(
         &mut self,
@@ -441,6 +630,54 @@ pub(super) trait _BackProp<K, V> {
         Ok(())
     }
 
+    #[inline]
+    fn _on_atan2(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypot(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_max(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_min(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     #[inline]
     fn _on_compressed(
         &mut self,
@@ -477,7 +714,7 @@ impl<V> _BackPropWorkSpace<V> {
     /// Count the number of references to each node in the computation graph.
     /// Note that this method is a preparation for gradient calculation
     /// and please does not call for other purposes.
-    fn _count_ref<K>(&mut self, tape: &_Tape<K, V>, root: usize)
+    fn _count_ref<K>(&mut self, tape: &_Tape<K, V>, roots: &[usize])
     where
         V: Real,
     {
@@ -491,7 +728,7 @@ impl<V> _BackPropWorkSpace<V> {
 
         let stack = &mut self.next_nodes;
         stack.clear();
-        stack.push(root);
+        stack.extend_from_slice(roots);
 
         while let Some(idx) = stack.pop() {
             // skip if already visited
@@ -529,13 +766,28 @@ impl<V> _BackPropWorkSpace<V> {
                 | _Node::Exp { index, .. }
                 | _Node::Log { index, .. }
                 | _Node::Erf { index, .. }
+                | _Node::Expm1 { index, .. }
+                | _Node::Log1p { index, .. }
                 | _Node::Sqrt { index, .. }
-                | _Node::Powi { index, .. } => stack.push(*index),
+                | _Node::Abs { index, .. }
+                | _Node::Powi { index, .. }
+                | _Node::Atan2L { lhs: index, .. }
+                | _Node::Atan2R { rhs: index, .. }
+                | _Node::HypotL { lhs: index, .. }
+                | _Node::HypotR { rhs: index, .. }
+                | _Node::MaxL { lhs: index, .. }
+                | _Node::MaxR { rhs: index, .. }
+                | _Node::MinL { lhs: index, .. }
+                | _Node::MinR { rhs: index, .. } => stack.push(*index),
                 // binary
                 _Node::Add { lhs, rhs, .. }
                 | _Node::Sub { lhs, rhs, .. }
                 | _Node::Mul { lhs, rhs, .. }
-                | _Node::Div { lhs, rhs, .. } => {
+                | _Node::Div { lhs, rhs, .. }
+                | _Node::Atan2 { lhs, rhs, .. }
+                | _Node::Hypot { lhs, rhs, .. }
+                | _Node::Max { lhs, rhs, .. }
+                | _Node::Min { lhs, rhs, .. } => {
                     stack.push(*lhs);
                     stack.push(*rhs);
                 }
@@ -552,7 +804,7 @@ impl<V> _BackPropWorkSpace<V> {
     pub(super) fn _back_prop<K, Proccesor>(
         &mut self,
         tape: &_Tape<K, V>,
-        root: usize,
+        roots: &[usize],
         proc: &mut Proccesor,
     ) -> Result<(), Proccesor::Error>
     where
@@ -589,19 +841,21 @@ impl<V> _BackPropWorkSpace<V> {
         // and update the reference counts to x0=1, x1=1, y=0, z=0, w=0.
         // Finally, we propagate the gradient of `y` to `x0` and `x1` because the reference
         // counts of 'y' becomes zero.
+        //
+        // When multiple roots are given, the gradient of their sum is calculated.
+        // Each root is counted as referred once more, so a root which another root depends on
+        // waits until the gradient is propagated from the other root.
 
         // preparation phase
-        self._count_ref(tape, root);
+        self._count_ref(tape, roots);
         let refcount = &mut self.refcount;
 
         let stack = &mut self.next_nodes;
         stack.clear();
-        stack.push(root);
 
         let grads_memo = &mut self.grads_memo;
         grads_memo.clear();
         grads_memo.resize(tape.cells.len(), V::zero());
-        grads_memo[root] = V::one();
 
         // calculation phase
         let _decl_refcnt = |idx: usize, rc: &mut Vec<usize>, next: &mut Vec<usize>| {
@@ -610,6 +864,10 @@ impl<V> _BackPropWorkSpace<V> {
                 next.push(idx);
             }
         };
+        for &root in roots {
+            grads_memo[root] += &V::one();
+            _decl_refcnt(root, refcount, stack);
+        }
 
         while let Some(tgt) = stack.pop() {
             let node = tape._cell(tgt);
@@ -686,12 +944,35 @@ impl<V> _BackPropWorkSpace<V> {
                     grads_memo[*index] += &(seed * coeff * (-arg.clone() * arg).exp());
                     _decl_refcnt(*index, refcount, stack);
                 }
+                _Node::Expm1 { value, index } => {
+                    proc._on_expm1(tgt, *index, value, &seed)?;
+                    // d/dx (exp(x) - 1) = exp(x) = value + 1
+                    grads_memo[*index] += &(seed.clone() * value + &seed);
+                    _decl_refcnt(*index, refcount, stack);
+                }
+                _Node::Log1p { value, index } => {
+                    proc._on_log1p(tgt, *index, value, &seed)?;
+                    let val = tape._cell(*index).value();
+                    grads_memo[*index] += &(seed / &(V::one() + val));
+                    _decl_refcnt(*index, refcount, stack);
+                }
                 _Node::Sqrt { value, index } => {
                     proc._on_sqrt(tgt, *index, value, &seed)?;
                     let coeff = V::nearest_value_of_f64(0.5);
                     grads_memo[*index] += &(seed * &coeff / value);
                     _decl_refcnt(*index, refcount, stack);
                 }
+                _Node::Abs { value, index } => {
+                    proc._on_abs(tgt, *index, value, &seed)?;
+                    // d/dx |x| = sign(x), where 0 is used as a subgradient at x = 0
+                    let val = tape._cell(*index).value();
+                    if &V::zero() < val {
+                        grads_memo[*index] += &seed;
+                    } else if val < &V::zero() {
+                        grads_memo[*index] -= &seed;
+                    }
+                    _decl_refcnt(*index, refcount, stack);
+                }
                 _Node::Powi { value, index, exp } => {
                     proc._on_powi(tgt, *index, *exp, value, &seed)?;
                     let coeff = V::nearest_value_of_f64(*exp as f64);
@@ -699,6 +980,61 @@ impl<V> _BackPropWorkSpace<V> {
                     grads_memo[*index] += &(seed * &val.clone().powi(*exp - 1) * &coeff);
                     _decl_refcnt(*index, refcount, stack);
                 }
+                _Node::Atan2L { value, lhs, rhs } => {
+                    proc._on_atan2l(tgt, *lhs, rhs, value, &seed)?;
+                    let lhs_val = tape._cell(*lhs).value();
+                    let sqnorm = lhs_val.clone() * lhs_val + &(rhs.clone() * rhs);
+                    grads_memo[*lhs] += &(seed * rhs / &sqnorm);
+                    _decl_refcnt(*lhs, refcount, stack);
+                }
+                _Node::Atan2R { value, lhs, rhs } => {
+                    proc._on_atan2r(tgt, lhs, *rhs, value, &seed)?;
+                    let rhs_val = tape._cell(*rhs).value();
+                    let sqnorm = lhs.clone() * lhs + &(rhs_val.clone() * rhs_val);
+                    grads_memo[*rhs] -= &(seed * lhs / &sqnorm);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::HypotL { value, lhs, rhs } => {
+                    proc._on_hypotl(tgt, *lhs, rhs, value, &seed)?;
+                    let lhs_val = tape._cell(*lhs).value();
+                    grads_memo[*lhs] += &(seed * lhs_val / value);
+                    _decl_refcnt(*lhs, refcount, stack);
+                }
+                _Node::HypotR { value, lhs, rhs } => {
+                    proc._on_hypotr(tgt, lhs, *rhs, value, &seed)?;
+                    let rhs_val = tape._cell(*rhs).value();
+                    grads_memo[*rhs] += &(seed * rhs_val / value);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::MaxL { value, lhs, rhs } => {
+                    proc._on_maxl(tgt, *lhs, rhs, value, &seed)?;
+                    // subgradient: the gradient flows to the selected argument, lhs on ties
+                    if value == tape._cell(*lhs).value() {
+                        grads_memo[*lhs] += &seed;
+                    }
+                    _decl_refcnt(*lhs, refcount, stack);
+                }
+                _Node::MaxR { value, lhs, rhs } => {
+                    proc._on_maxr(tgt, lhs, *rhs, value, &seed)?;
+                    if value != lhs {
+                        grads_memo[*rhs] += &seed;
+                    }
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::MinL { value, lhs, rhs } => {
+                    proc._on_minl(tgt, *lhs, rhs, value, &seed)?;
+                    if value == tape._cell(*lhs).value() {
+                        grads_memo[*lhs] += &seed;
+                    }
+                    _decl_refcnt(*lhs, refcount, stack);
+                }
+                _Node::MinR { value, lhs, rhs } => {
+                    proc._on_minr(tgt, lhs, *rhs, value, &seed)?;
+                    if value != lhs {
+                        grads_memo[*rhs] += &seed;
+                    }
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
                 // binary arithmetic
                 _Node::Add { value, lhs, rhs } => {
                     proc._on_add(tgt, *lhs, *rhs, value, &seed)?;
@@ -732,6 +1068,47 @@ impl<V> _BackPropWorkSpace<V> {
                     _decl_refcnt(*lhs, refcount, stack);
                     _decl_refcnt(*rhs, refcount, stack);
                 }
+                // binary elementary functions
+                _Node::Atan2 { value, lhs, rhs } => {
+                    proc._on_atan2(tgt, *lhs, *rhs, value, &seed)?;
+                    let lhs_val = tape._cell(*lhs).value();
+                    let rhs_val = tape._cell(*rhs).value();
+                    let sqnorm = lhs_val.clone() * lhs_val + &(rhs_val.clone() * rhs_val);
+                    grads_memo[*lhs] += &(seed.clone() * rhs_val / &sqnorm);
+                    grads_memo[*rhs] -= &(seed * lhs_val / &sqnorm);
+                    _decl_refcnt(*lhs, refcount, stack);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::Hypot { value, lhs, rhs } => {
+                    proc._on_hypot(tgt, *lhs, *rhs, value, &seed)?;
+                    let lhs_val = tape._cell(*lhs).value();
+                    let rhs_val = tape._cell(*rhs).value();
+                    grads_memo[*lhs] += &(seed.clone() * lhs_val / value);
+                    grads_memo[*rhs] += &(seed * rhs_val / value);
+                    _decl_refcnt(*lhs, refcount, stack);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::Max { value, lhs, rhs } => {
+                    proc._on_max(tgt, *lhs, *rhs, value, &seed)?;
+                    // subgradient: the gradient flows to the selected argument, lhs on ties
+                    if value == tape._cell(*lhs).value() {
+                        grads_memo[*lhs] += &seed;
+                    } else {
+                        grads_memo[*rhs] += &seed;
+                    }
+                    _decl_refcnt(*lhs, refcount, stack);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
+                _Node::Min { value, lhs, rhs } => {
+                    proc._on_min(tgt, *lhs, *rhs, value, &seed)?;
+                    if value == tape._cell(*lhs).value() {
+                        grads_memo[*lhs] += &seed;
+                    } else {
+                        grads_memo[*rhs] += &seed;
+                    }
+                    _decl_refcnt(*lhs, refcount, stack);
+                    _decl_refcnt(*rhs, refcount, stack);
+                }
                 // multi-ary
                 _Node::Compressed { value, grads } => {
                     proc._on_compressed(tgt, grads, value, &seed)?;
@@ -836,6 +1213,16 @@ impl<K, V> _Tape<K, V> {
     pub(super) fn _vars(&self) -> &[_VarIdx<K>] {
         &self.vars
     }
+
+    #[inline]
+    pub(super) fn _num_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub(super) fn _num_vacant(&self) -> usize {
+        self.vacancy.len()
+    }
 }
 
 impl<K, V> _Tape<K, V> {
@@ -933,24 +1320,37 @@ impl<K, V> _Tape<K, V> {
                 | _Node::Exp { index, .. }
                 | _Node::Log { index, .. }
                 | _Node::Erf { index, .. }
+                | _Node::Expm1 { index, .. }
+                | _Node::Log1p { index, .. }
                 | _Node::Sqrt { index, .. }
-                | _Node::Powi { index, .. } => {
+                | _Node::Abs { index, .. }
+                | _Node::Powi { index, .. }
+                | _Node::Atan2L { lhs: index, .. }
+                | _Node::HypotL { lhs: index, .. }
+                | _Node::Atan2R { rhs: index, .. }
+                | _Node::HypotR { rhs: index, .. }
+                | _Node::MaxL { lhs: index, .. }
+                | _Node::MinL { lhs: index, .. }
+                | _Node::MaxR { rhs: index, .. }
+                | _Node::MinR { rhs: index, .. } => {
                     self.vacancy.push(idx);
                     stack.push(*index);
                 }
 
-                // binary arithmetic
+                // binary
                 _Node::Add { lhs, rhs, .. }
                 | _Node::Sub { lhs, rhs, .. }
                 | _Node::Mul { lhs, rhs, .. }
-                | _Node::Div { lhs, rhs, .. } => {
+                | _Node::Div { lhs, rhs, .. }
+                | _Node::Atan2 { lhs, rhs, .. }
+                | _Node::Hypot { lhs, rhs, .. }
+                | _Node::Max { lhs, rhs, .. }
+                | _Node::Min { lhs, rhs, .. } => {
                     self.vacancy.push(idx);
                     stack.push(*lhs);
                     stack.push(*rhs);
                 }
 
-                // unary elementary functions
-
                 // multi-ary
                 _Node::Compressed { .. } => {
                     self.vacancy.push(idx);
@@ -1002,7 +1402,7 @@ where
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let mut internal = self.graph.0.borrow_mut();
+        let mut internal = self.graph._borrow_mut();
         Node {
             index: internal.tape._make_unary(self.index, |val| _Node::Neg {
                 value: std::ops::Neg::neg(val.clone()),
@@ -1031,7 +1431,7 @@ macro_rules! _define_arithmetic_binary {
                         rhs.graph._debug_ptr()
                     );
                 }
-                let mut internal = self.graph.0.borrow_mut();
+                let mut internal = self.graph._borrow_mut();
                 Node {
                     index: internal
                         .tape
@@ -1052,7 +1452,7 @@ macro_rules! _define_arithmetic_binary {
 
             #[inline]
             fn $fn(self, rhs: Scalar<&V>) -> Self::Output {
-                let mut internal = self.graph.0.borrow_mut();
+                let mut internal = self.graph._borrow_mut();
                 Node {
                     index: internal
                         .tape
@@ -1073,7 +1473,7 @@ macro_rules! _define_arithmetic_binary {
 
             #[inline]
             fn $fn(self, rhs: &Node<K, V>) -> Self::Output {
-                let mut internal = rhs.graph.0.borrow_mut();
+                let mut internal = rhs.graph._borrow_mut();
                 Node {
                     index: internal.tape._make_unary(rhs.index, |rval| _Node::$node_r {
                         value: std::ops::$tr::$fn(self.0.clone(), rval),
@@ -1092,7 +1492,7 @@ macro_rules! _define_arithmetic_binary {
 
             #[inline]
             fn $fn(self, rhs: &Node<K, V>) -> Self::Output {
-                let mut internal = rhs.graph.0.borrow_mut();
+                let mut internal = rhs.graph._borrow_mut();
                 Node {
                     index: internal.tape._make_unary(rhs.index, |rval| _Node::$node_r {
                         value: std::ops::$tr::$fn(self.0.clone(), rval),
@@ -1124,7 +1524,7 @@ macro_rules! _define_elementary_unary {
 
             #[inline]
             fn $fn(self) -> Self::Output {
-                let mut internal = self.graph.0.borrow_mut();
+                let mut internal = self.graph._borrow_mut();
                 Node {
                     index: internal.tape._make_unary(self.index, |val| _Node::$node {
                         value: qmath::num::$tr::$fn(val.clone()),
@@ -1140,7 +1540,10 @@ macro_rules! _define_elementary_unary {
 _define_elementary_unary!(Exp, exp, Exp);
 _define_elementary_unary!(Log, log, Log);
 _define_elementary_unary!(Erf, erf, Erf);
+_define_elementary_unary!(Expm1, expm1, Expm1);
+_define_elementary_unary!(Log1p, log1p, Log1p);
 _define_elementary_unary!(Sqrt, sqrt, Sqrt);
+_define_elementary_unary!(Abs, abs, Abs);
 
 impl<K, V> qmath::num::Powi for Node<K, V>
 where
@@ -1150,7 +1553,7 @@ where
 
     #[inline]
     fn powi(self, exp: i32) -> Self::Output {
-        let mut internal = self.graph.0.borrow_mut();
+        let mut internal = self.graph._borrow_mut();
         Node {
             index: internal.tape._make_unary(self.index, |val| _Node::Powi {
                 value: qmath::num::Powi::powi(val.clone(), exp),
@@ -1162,22 +1565,100 @@ where
     }
 }
 
-// -----------------------------------------------------------------------------
-// _GraphvizNodeIdx
-// _GraphvizNode
-// _GraphvizGraph
-// -----------------------------------------------------------------------------
-#[derive(Debug, Clone)]
-enum _GraphvizNodeIdx {
-    Node(usize),
-    Cell(usize),
-}
+//
+// binary elementary functions
+//
+macro_rules! _define_elementary_binary {
+    ($tr:ident, $fn:ident, $node:ident, $node_l:ident, $node_r:ident, $fn_l:ident, $fn_r:ident) => {
+        impl<K, V> qmath::num::$tr for Node<K, V>
+        where
+            V: Clone + qmath::num::$tr<Output = V>,
+        {
+            type Output = Node<K, V>;
 
-#[derive(Debug, Clone)]
-enum _GraphvizNode<K, V> {
-    Const { value: V },
-    Var { key: K, value: V, grad: V },
-    Node { op: String, value: V, grad: V },
+            #[inline]
+            fn $fn(self, rhs: &Node<K, V>) -> Self::Output {
+                if !Graph::ptr_eq(&self.graph, &rhs.graph) {
+                    panic!(
+                        "Cannot {} nodes from different tapes: lhs.tape={:?}, rhs.tape={:?}",
+                        stringify!($tr),
+                        self.graph._debug_ptr(),
+                        rhs.graph._debug_ptr()
+                    );
+                }
+                let mut internal = self.graph._borrow_mut();
+                Node {
+                    index: internal
+                        .tape
+                        ._make_binary(self.index, rhs.index, |lval, rval| _Node::$node {
+                            value: qmath::num::$tr::$fn(lval.clone(), rval),
+                            lhs: self.index,
+                            rhs: rhs.index,
+                        }),
+                    graph: self.graph.clone(),
+                }
+            }
+        }
+
+        impl<K, V> Node<K, V> {
+            #[inline]
+            pub(crate) fn $fn_l(&self, rhs: Scalar<&V>) -> Node<K, V>
+            where
+                V: Clone + qmath::num::$tr<Output = V>,
+            {
+                let mut internal = self.graph._borrow_mut();
+                Node {
+                    index: internal
+                        .tape
+                        ._make_unary(self.index, |lval| _Node::$node_l {
+                            value: qmath::num::$tr::$fn(lval.clone(), rhs.0),
+                            lhs: self.index,
+                            rhs: rhs.0.clone(),
+                        }),
+                    graph: self.graph.clone(),
+                }
+            }
+
+            #[inline]
+            pub(crate) fn $fn_r(lhs: Scalar<V>, rhs: &Node<K, V>) -> Node<K, V>
+            where
+                V: Clone + qmath::num::$tr<Output = V>,
+            {
+                let mut internal = rhs.graph._borrow_mut();
+                Node {
+                    index: internal.tape._make_unary(rhs.index, |rval| _Node::$node_r {
+                        value: qmath::num::$tr::$fn(lhs.0.clone(), rval),
+                        lhs: lhs.0,
+                        rhs: rhs.index,
+                    }),
+                    graph: rhs.graph.clone(),
+                }
+            }
+        }
+    };
+}
+
+_define_elementary_binary!(Atan2, atan2, Atan2, Atan2L, Atan2R, _atan2_l, _atan2_r);
+_define_elementary_binary!(Hypot, hypot, Hypot, HypotL, HypotR, _hypot_l, _hypot_r);
+_define_elementary_binary!(Max, max, Max, MaxL, MaxR, _max_l, _max_r);
+_define_elementary_binary!(Min, min, Min, MinL, MinR, _min_l, _min_r);
+
+// -----------------------------------------------------------------------------
+// _GraphvizNodeIdx
+// _GraphvizNode
+// _GraphvizGraph
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone)]
+enum _GraphvizNodeIdx {
+    Node(usize),
+    Cell(usize),
+}
+
+#[derive(Debug, Clone)]
+enum _GraphvizNode<K, V> {
+    Const { value: V },
+    Var { key: K, value: V, grad: V },
+    Node { op: String, value: V, grad: V },
 }
 
 struct _GraphvizGraph<K, V> {
@@ -1538,6 +2019,30 @@ where
         Ok(())
     }
 
+    #[inline]
+    fn _on_expm1(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._unary("expm1", cell_idx, arg, value, grad, None);
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_log1p(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._unary("log1p", cell_idx, arg, value, grad, None);
+        Ok(())
+    }
+
     #[inline]
     fn _on_sqrt(
         &mut self,
@@ -1550,6 +2055,194 @@ where
         Ok(())
     }
 
+    #[inline]
+    fn _on_abs(
+        &mut self,
+        cell_idx: usize,
+        arg: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._unary("abs", cell_idx, arg, value, grad, None);
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_atan2l(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "atan2",
+            cell_idx,
+            rhs,
+            lhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_atan2r(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "atan2",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "R".to_string().into(),
+            "L".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypotl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "hypot",
+            cell_idx,
+            rhs,
+            lhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypotr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "hypot",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "R".to_string().into(),
+            "L".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_maxl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "max",
+            cell_idx,
+            rhs,
+            lhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_maxr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "max",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "R".to_string().into(),
+            "L".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_minl(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: &V,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "min",
+            cell_idx,
+            rhs,
+            lhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_minr(
+        &mut self,
+        cell_idx: usize,
+        lhs: &V,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary_partial(
+            "min",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "R".to_string().into(),
+            "L".to_string().into(),
+        );
+        Ok(())
+    }
+
     #[inline]
     fn _on_add(
         &mut self,
@@ -1637,10 +2330,98 @@ where
         );
         Ok(())
     }
-}
 
-// -----------------------------------------------------------------------------
-// GraphvizBuilder
+    #[inline]
+    fn _on_atan2(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary(
+            "atan2",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_hypot(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary(
+            "hypot",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_max(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary(
+            "max",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    fn _on_min(
+        &mut self,
+        cell_idx: usize,
+        lhs: usize,
+        rhs: usize,
+        value: &V,
+        grad: &V,
+    ) -> Result<(), Self::Error> {
+        self._binary(
+            "min",
+            cell_idx,
+            lhs,
+            rhs,
+            value,
+            grad,
+            "L".to_string().into(),
+            "R".to_string().into(),
+        );
+        Ok(())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// GraphvizBuilder
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone)]
 pub struct GraphvizBuilder<K, V, KeyFmt, ValFmt> {
@@ -1649,6 +2430,7 @@ pub struct GraphvizBuilder<K, V, KeyFmt, ValFmt> {
     name: String,
     graph_global_settings: BTreeMap<String, String>,
     node_global_settings: BTreeMap<String, String>,
+    root_labels: BTreeMap<usize, String>,
     key_fmt: KeyFmt,
     value_fmt: ValFmt,
 }
@@ -1665,6 +2447,7 @@ impl<K, V, KF> GraphvizBuilder<K, V, KF, ()> {
             name: self.name,
             graph_global_settings: self.graph_global_settings,
             node_global_settings: self.node_global_settings,
+            root_labels: self.root_labels,
             key_fmt: self.key_fmt,
             value_fmt: val_fmt,
         }
@@ -1683,6 +2466,7 @@ impl<K, V, VF> GraphvizBuilder<K, V, (), VF> {
             name: self.name,
             graph_global_settings: self.graph_global_settings,
             node_global_settings: self.node_global_settings,
+            root_labels: self.root_labels,
             key_fmt,
             value_fmt: self.value_fmt,
         }
@@ -1740,32 +2524,29 @@ impl<K, V, KeyFmt, ValFmt> GraphvizBuilder<K, V, KeyFmt, ValFmt> {
         // nodes
         buf += "  // nodes\n";
         for (idx, node) in self.nodes.iter().enumerate() {
-            match node {
-                _GraphvizNode::Const { value } => {
-                    let annotations = format!(
-                        "label=\"{{value={}}}\", shape=record",
-                        (self.value_fmt)(value)
-                    );
-                    buf.push_str(&format!("  {idx} [{annotations}];\n"));
-                }
-                _GraphvizNode::Var { key, value, grad } => {
-                    let annotations = format!("label=\"{key}|{{value={value}|grad={grad}}}\", shape=record, style=\"diagonals\"",
-                        key = (self.key_fmt)(key),
-                        value = (self.value_fmt)(value),
-                        grad = (self.value_fmt)(grad),
-                    );
-                    buf.push_str(&format!("  {idx} [{annotations}];\n"));
-                }
-                _GraphvizNode::Node { op, value, grad } => {
-                    let annotations = format!(
-                        "label=\"{op}|{{value={value}|grad={grad}}}\", shape=record",
-                        op = op,
-                        value = (self.value_fmt)(value),
-                        grad = (self.value_fmt)(grad),
-                    );
-                    buf.push_str(&format!("  {idx} [{annotations}];\n"));
-                }
+            let mut annotations = match node {
+                _GraphvizNode::Const { value } => format!(
+                    "label=\"{{value={}}}\", shape=record",
+                    (self.value_fmt)(value)
+                ),
+                _GraphvizNode::Var { key, value, grad } => format!(
+                    "label=\"{key}|{{value={value}|grad={grad}}}\", shape=record, style=\"diagonals\"",
+                    key = (self.key_fmt)(key),
+                    value = (self.value_fmt)(value),
+                    grad = (self.value_fmt)(grad),
+                ),
+                _GraphvizNode::Node { op, value, grad } => format!(
+                    "label=\"{op}|{{value={value}|grad={grad}}}\", shape=record",
+                    op = op,
+                    value = (self.value_fmt)(value),
+                    grad = (self.value_fmt)(grad),
+                ),
+            };
+            if let Some(label) = self.root_labels.get(&idx) {
+                let label = label.replace('\\', "\\\\").replace('"', "\\\"");
+                annotations.push_str(&format!(", xlabel=\"{label}\", color=\"red\", penwidth=2"));
             }
+            buf.push_str(&format!("  {idx} [{annotations}];\n"));
         }
         buf += "\n";
 
@@ -1786,7 +2567,7 @@ impl<K, V, KeyFmt, ValFmt> GraphvizBuilder<K, V, KeyFmt, ValFmt> {
 
 #[cfg(test)]
 mod tests {
-    use qmath::num::{Erf, Exp, Log, Powi, Sqrt};
+    use qmath::num::{Abs, Atan2, Erf, Exp, Expm1, Hypot, Log, Log1p, Max, Min, Powi, Sqrt};
 
     use super::*;
 
@@ -2106,6 +2887,52 @@ mod tests {
         assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
     }
 
+    #[test]
+    fn test_refcnt_expm1() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().expm1();
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_log1p() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().log1p();
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
     #[test]
     fn test_refcnt_sqrt() {
         let graph = Graph::new();
@@ -2129,6 +2956,29 @@ mod tests {
         assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
     }
 
+    #[test]
+    fn test_refcnt_abs() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().abs();
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
     #[test]
     fn test_refcnt_powi() {
         let graph = Graph::new();
@@ -2152,6 +3002,98 @@ mod tests {
         assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
     }
 
+    #[test]
+    fn test_refcnt_atan2l() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().atan2(&Expr::from(3.0));
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_atan2r() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = Expr::from(3.0).atan2(x1.as_ref());
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_hypotl() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().hypot(&Expr::from(3.0));
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_hypotr() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = Expr::from(3.0).hypot(x1.as_ref());
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
     #[test]
     fn test_refcnt_add() {
         let graph = Graph::new();
@@ -2256,6 +3198,202 @@ mod tests {
         assert_eq!(graph.0.borrow().tape.vacancy[0], 2);
     }
 
+    #[test]
+    fn test_refcnt_atan2() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        let x2 = graph.create_var("43", 4.3f64).unwrap();
+        {
+            let x3 = x1.as_ref().clone().atan2(x2.as_ref());
+            let x4 = x3.clone();
+            let x5 = x3.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x4, x5);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 2);
+    }
+
+    #[test]
+    fn test_refcnt_hypot() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        let x2 = graph.create_var("43", 4.3f64).unwrap();
+        {
+            let x3 = x1.as_ref().clone().hypot(x2.as_ref());
+            let x4 = x3.clone();
+            let x5 = x3.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x4, x5);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 2);
+    }
+
+    #[test]
+    fn test_refcnt_maxl() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().max(&Expr::from(3.0));
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_maxr() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = Expr::from(3.0).max(x1.as_ref());
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_max() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        let x2 = graph.create_var("43", 4.3f64).unwrap();
+        {
+            let x3 = x1.as_ref().clone().max(x2.as_ref());
+            let x4 = x3.clone();
+            let x5 = x3.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x4, x5);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 2);
+    }
+
+    #[test]
+    fn test_refcnt_minl() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = x1.as_ref().clone().min(&Expr::from(3.0));
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_minr() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        {
+            let x2 = Expr::from(3.0).min(x1.as_ref());
+            let x3 = x2.clone();
+            let x4 = x2.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x3, x4);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 2);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 1);
+    }
+
+    #[test]
+    fn test_refcnt_min() {
+        let graph = Graph::new();
+
+        let x1 = graph.create_var("42", 4.2f64).unwrap();
+        let x2 = graph.create_var("43", 4.3f64).unwrap();
+        {
+            let x3 = x1.as_ref().clone().min(x2.as_ref());
+            let x4 = x3.clone();
+            let x5 = x3.clone();
+            assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+            assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 2);
+            assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 3);
+            assert_eq!(graph.0.borrow().tape.vacancy.len(), 0);
+            let _ = (x4, x5);
+        }
+
+        assert_eq!(graph.0.borrow().tape.cells.len(), 3);
+        assert_eq!(graph.0.borrow().tape.cells[0].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[1].refcnt, 1);
+        assert_eq!(graph.0.borrow().tape.cells[2].refcnt, 0);
+        assert_eq!(graph.0.borrow().tape.vacancy.len(), 1);
+        assert_eq!(graph.0.borrow().tape.vacancy[0], 2);
+    }
+
     #[test]
     fn test_refcnt_recursive_decl() {
         let graph = Graph::new();
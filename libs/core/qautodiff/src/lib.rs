@@ -4,4 +4,4 @@ mod graph;
 
 pub use error::Error;
 pub use expr::{Expr, Var};
-pub use graph::{Grads, GradsAccum, Graph, GraphvizBuilder};
+pub use graph::{Grads, GradsAccum, Graph, GraphvizBuilder, SyncGraph};
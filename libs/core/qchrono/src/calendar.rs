@@ -1,9 +1,15 @@
+#[cfg(feature = "builtin-calendars")]
+mod builtin;
 mod calendar_impl;
 mod data_src;
 mod holadj;
+mod holiday_rule;
 mod sym;
 
+#[cfg(feature = "builtin-calendars")]
+pub use builtin::BuiltinCalendarSrc;
 pub use calendar_impl::{Calendar, CalendarBuilder, CalendarError};
 pub use data_src::CalendarSrc;
 pub use holadj::HolidayAdj;
+pub use holiday_rule::{HolidayRule, Observance};
 pub use sym::{CalendarSym, CalendarSymAtom};
@@ -0,0 +1,154 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use anyhow::Context;
+
+use super::{Calendar, CalendarSrc, CalendarSymAtom};
+
+// -----------------------------------------------------------------------------
+// BuiltinCalendarSrc
+// -----------------------------------------------------------------------------
+/// [`CalendarSrc`] backed by holiday data embedded in this crate.
+///
+/// This is available with `builtin-calendars` feature, which is enabled by default.
+/// Following calendars are provided and all of them are valid from 2020-01-01 to 2030-12-31.
+/// Saturdays and Sundays are treated as holidays for all calendars.
+///
+/// - `TKY`: Japanese national holidays and bank holidays (Jan 2, Jan 3 and Dec 31).
+/// - `NYC`: Holidays of the Federal Reserve. Holidays on Saturday are not observed.
+/// - `LON`: Bank holidays in England and Wales.
+///
+/// Embedded data are not maintained against future announcements
+/// such as special holidays. Use your own [`CalendarSrc`] for production usage.
+///
+/// # Example
+/// ```
+/// use qchrono::calendar::{BuiltinCalendarSrc, CalendarSrc};
+///
+/// let ymd = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let src = BuiltinCalendarSrc;
+///
+/// let cal = src.get_calendar(&"TKY|NYC".parse().unwrap()).unwrap();
+///
+/// assert!(cal.is_holiday(ymd(2024, 1, 1)).unwrap()); // New Year's Day
+/// assert!(cal.is_holiday(ymd(2024, 7, 4)).unwrap()); // Independence Day
+/// assert!(cal.is_holiday(ymd(2024, 7, 15)).unwrap()); // Marine Day
+/// assert!(cal.is_bizday(ymd(2024, 7, 16)).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BuiltinCalendarSrc;
+
+//
+// methods
+//
+impl BuiltinCalendarSrc {
+    fn _data() -> &'static HashMap<CalendarSymAtom, Calendar> {
+        static DATA: OnceLock<HashMap<CalendarSymAtom, Calendar>> = OnceLock::new();
+        DATA.get_or_init(|| {
+            serde_json::from_str(include_str!("../../data/builtin_calendars.json"))
+                .expect("Embedded calendar data must be valid")
+        })
+    }
+
+    /// Symbols of the calendars provided by this source.
+    #[inline]
+    pub fn symbols(&self) -> impl Iterator<Item = &'static CalendarSymAtom> {
+        Self::_data().keys()
+    }
+}
+
+impl CalendarSrc for BuiltinCalendarSrc {
+    fn get_calendar_atom(&self, req: &CalendarSymAtom) -> anyhow::Result<Calendar> {
+        Self::_data()
+            .get(req)
+            .cloned()
+            .with_context(|| format!("Calendar '{req}' is not found in builtin calendars"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_symbols() {
+        let mut tested = BuiltinCalendarSrc.symbols().cloned().collect::<Vec<_>>();
+        tested.sort();
+
+        assert_eq!(
+            tested,
+            vec![
+                "LON".parse().unwrap(),
+                "NYC".parse().unwrap(),
+                "TKY".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_valid_period(#[values("TKY", "NYC", "LON")] sym: &str) {
+        let cal = BuiltinCalendarSrc
+            .get_calendar_atom(&sym.parse().unwrap())
+            .unwrap();
+
+        let tested = cal.valid_period();
+
+        assert_eq!(tested.start, ymd(2020, 1, 1));
+        assert_eq!(tested.end, ymd(2031, 1, 1));
+        assert!(cal.is_holiday(ymd(2019, 12, 31)).is_err());
+        assert!(cal.is_holiday(ymd(2031, 1, 1)).is_err());
+    }
+
+    #[rstest]
+    #[case("TKY", ymd(2024, 1, 1), true)]
+    #[case("TKY", ymd(2024, 1, 2), true)]
+    #[case("TKY", ymd(2024, 1, 4), false)]
+    #[case("TKY", ymd(2021, 7, 22), true)] // moved Marine Day
+    #[case("TKY", ymd(2021, 7, 19), false)]
+    #[case("TKY", ymd(2026, 5, 6), true)] // substitute holiday
+    #[case("TKY", ymd(2026, 9, 22), true)] // citizens' holiday
+    #[case("NYC", ymd(2024, 1, 1), true)]
+    #[case("NYC", ymd(2023, 1, 2), true)] // observed New Year's Day
+    #[case("NYC", ymd(2023, 11, 10), false)] // Veterans Day on Saturday is not observed
+    #[case("NYC", ymd(2024, 11, 28), true)] // Thanksgiving Day
+    #[case("NYC", ymd(2024, 11, 29), false)]
+    #[case("LON", ymd(2024, 1, 1), true)]
+    #[case("LON", ymd(2024, 3, 29), true)] // Good Friday
+    #[case("LON", ymd(2024, 4, 1), true)] // Easter Monday
+    #[case("LON", ymd(2022, 6, 3), true)] // Platinum Jubilee
+    #[case("LON", ymd(2021, 12, 28), true)] // substitute Boxing Day
+    #[case("LON", ymd(2024, 7, 4), false)]
+    fn test_is_holiday(#[case] sym: &str, #[case] date: NaiveDate, #[case] expected: bool) {
+        let cal = BuiltinCalendarSrc
+            .get_calendar_atom(&sym.parse().unwrap())
+            .unwrap();
+
+        let tested = cal.is_holiday(date).unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[test]
+    fn test_composite() {
+        let src = BuiltinCalendarSrc;
+        let tky = src.get_calendar_atom(&"TKY".parse().unwrap()).unwrap();
+        let lon = src.get_calendar_atom(&"LON".parse().unwrap()).unwrap();
+
+        let tested = src.get_calendar(&"TKY&LON".parse().unwrap()).unwrap();
+
+        assert_eq!(tested, tky & lon);
+    }
+
+    #[test]
+    fn test_unknown() {
+        let tested = BuiltinCalendarSrc.get_calendar_atom(&"XXX".parse().unwrap());
+
+        assert!(tested.is_err());
+    }
+}
@@ -1,11 +1,16 @@
 use std::{
+    collections::BTreeSet,
+    io::{BufRead, BufReader, Read, Write},
     ops::{BitAnd, BitOr, Bound, Range, RangeBounds},
     sync::Arc,
 };
 
-use anyhow::ensure;
+use anyhow::{bail, ensure, Context};
 use chrono::{Datelike, Days, NaiveDate, Weekday};
 
+use super::{HolidayAdj, HolidayRule};
+use crate::duration::Tenor;
+
 // -----------------------------------------------------------------------------
 // _CalendarData
 // -----------------------------------------------------------------------------
@@ -116,6 +121,8 @@ pub enum CalendarError {
         date: NaiveDate,
         valid_period: Range<NaiveDate>,
     },
+    #[error("The {ordinal}-th business day does not exist in {year}")]
+    OrdinalOutOfYear { year: i32, ordinal: usize },
 }
 
 // -----------------------------------------------------------------------------
@@ -175,6 +182,7 @@ pub enum CalendarError {
 /// the any-closed strategy is equivalent to the union of holidays sets
 /// and the all-closed strategy is equivalent to the intersection of holidays sets.
 /// Hence, these are implemented by the [`BitOr`] and [`BitAnd`] operators respectively.
+/// For overlap analysis, [`Calendar::xor_holidays_of`] provides the symmetric difference of holidays sets.
 ///
 /// ```
 /// use chrono::{NaiveDate, Weekday};
@@ -412,6 +420,248 @@ impl Calendar {
         .expect("AllClosed of valid calendars must be valid")
         .into()
     }
+
+    /// Create a new calendar whose holidays are days which are holidays in exactly one of the given calendars.
+    /// When we focus on the set of holidays, this is the symmetric difference of holiday sets,
+    /// and it is useful to find days where, e.g. only one leg of a basis swap is closed.
+    ///
+    /// Holiday weekdays follow the same rule, that is,
+    /// a weekday is a holiday weekday of the result if it is so in exactly one of the given calendars.
+    /// Hence, when all calendars treat weekends as holidays, weekends are business days of the result
+    /// and only extra holidays and extra business days which are not shared by all calendars remain.
+    /// Conversely, when some calendars treat weekends as business days,
+    /// weekends are holidays of the result if exactly one calendar treats them as holidays.
+    ///
+    /// This function requires an iterator over values, not references.
+    /// Because the [`Calendar`] object is lightweight, please clone objects if necessary.
+    ///
+    /// When given iterator is empty or no ovarlapping valid period, [None] is returned.
+    pub fn xor_holidays_of<It>(cals: It) -> Option<Self>
+    where
+        It: IntoIterator<Item = Self>,
+    {
+        let cals = smallvec::SmallVec::<[Self; 10]>::from_iter(cals);
+        match cals.len() {
+            0 => return None,
+            1 => return Some(cals.into_iter().next().unwrap()),
+            _ => {}
+        }
+
+        let valid_per = {
+            let valid_from = *cals.iter().map(|c| &c.0.valid_from).max()?;
+            let valid_to = *cals.iter().map(|c| &c.0.valid_to).min()?;
+            valid_from..valid_to
+        };
+        if valid_per.is_empty() {
+            return None;
+        }
+
+        let hol_wds = {
+            let hol_wds = cals.iter().flat_map(|c| &c.0.holiday_weekdays);
+            let hol_wds = hol_wds.filter(|w| {
+                cals.iter()
+                    .filter(|c| c.holiday_weekdays().contains(w))
+                    .count()
+                    == 1
+            });
+            let mut hol_wds = hol_wds.copied().collect::<Vec<_>>();
+            hol_wds.sort_by_key(|a| a.number_from_monday());
+            hol_wds.dedup();
+            hol_wds
+        };
+
+        // only extra days of some calendar can deviate from the weekday rule of the result
+        let (extra_holds, extra_bizds) = {
+            let days = cals
+                .iter()
+                .flat_map(|c| c.0.extra_holds.iter().chain(&c.0.extra_bizds))
+                .filter(|d| valid_per.contains(d));
+            let mut days = days.copied().collect::<Vec<_>>();
+            days.sort();
+            days.dedup();
+            days.into_iter().partition::<Vec<_>, _>(|d| {
+                cals.iter()
+                    .filter(|c| c.is_holiday(*d).unwrap_or(false))
+                    .count()
+                    == 1
+            })
+        };
+        let extra_holds = extra_holds
+            .into_iter()
+            .filter(|d| !hol_wds.contains(&d.weekday()))
+            .collect();
+        let extra_bizds = extra_bizds
+            .into_iter()
+            .filter(|d| hol_wds.contains(&d.weekday()))
+            .collect();
+
+        Self::_new(
+            extra_holds,
+            extra_bizds,
+            valid_per.start,
+            valid_per.end,
+            hol_wds,
+        )
+        .expect("XorHolidays of valid calendars must be valid")
+        .into()
+    }
+
+    /// Create a new calendar whose valid period is extended to `new_to` (exclusive).
+    ///
+    /// Days newly covered by the calendar have neither extra holidays nor extra business days,
+    /// that is, only [`Calendar::holiday_weekdays`] are holidays there.
+    /// This is useful to price long-dated trades beyond the range of known holidays
+    /// at the cost of ignoring future holidays.
+    ///
+    /// # Errors
+    /// If `new_to` is earlier than the end of the current valid period.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let extended = cal.with_extended_period(ymd(2061, 1, 1)).unwrap();
+    ///
+    /// assert_eq!(extended.valid_period(), ymd(2021, 1, 1)..ymd(2061, 1, 1));
+    /// assert!(extended.is_holiday(ymd(2021, 1, 1)).unwrap());
+    /// assert!(!extended.is_holiday(ymd(2060, 1, 1)).unwrap()); // Thursday
+    /// assert!(cal.with_extended_period(ymd(2021, 6, 1)).is_err());
+    /// ```
+    pub fn with_extended_period(&self, new_to: NaiveDate) -> anyhow::Result<Self> {
+        ensure!(
+            self.0.valid_to <= new_to,
+            "new end of the valid period must not be earlier than the current one: current={}, new={new_to}",
+            self.0.valid_to,
+        );
+        Self::_new(
+            self.0.extra_holds.clone(),
+            self.0.extra_bizds.clone(),
+            self.0.valid_from,
+            new_to,
+            self.0.holiday_weekdays.clone(),
+        )
+    }
+}
+
+//
+// csv
+//
+impl Calendar {
+    /// Load a calendar from CSV.
+    ///
+    /// Each row consists of an ISO 8601 date and an optional marker, `holiday` or `business`.
+    /// Rows without the marker are treated as holidays. Empty rows are ignored.
+    ///
+    /// ```text
+    /// 2021-01-01
+    /// 2021-01-11,holiday
+    /// 2021-01-16,business
+    /// ```
+    ///
+    /// Saturday and Sunday are holiday weekdays unless `treat_weekend_as_bizday` is `true`.
+    /// Rows which do not change the default, such as a holiday on Sunday, are ignored
+    /// because holiday lists maintained by hand often contain them.
+    /// Dates out of `valid_from..valid_to` are also ignored as [`CalendarBuilder::build`] does.
+    ///
+    /// # Errors
+    /// - When a row is malformed. The error message contains the 1-based line number.
+    /// - When `valid_from` is not less than `valid_to`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let csv = "2021-01-01\n2021-01-11,holiday\n";
+    /// let cal = Calendar::from_csv(csv.as_bytes(), ymd(2021, 1, 1), ymd(2022, 1, 1), false).unwrap();
+    ///
+    /// assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 11)]);
+    /// ```
+    pub fn from_csv<R: Read>(
+        reader: R,
+        valid_from: NaiveDate,
+        valid_to: NaiveDate,
+        treat_weekend_as_bizday: bool,
+    ) -> anyhow::Result<Calendar> {
+        let holiday_weekdays = if treat_weekend_as_bizday {
+            vec![]
+        } else {
+            vec![Weekday::Sat, Weekday::Sun]
+        };
+        let mut extra_holds = Vec::new();
+        let mut extra_bizds = Vec::new();
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let lineno = i + 1;
+            let line = line.with_context(|| format!("Reading calendar csv at line {lineno}"))?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut cols = line.split(',').map(str::trim);
+            let date = cols.next().unwrap_or_default();
+            let date: NaiveDate = date
+                .parse()
+                .with_context(|| format!("Parsing date '{date}' at line {lineno}"))?;
+            let is_holiday = match cols.next() {
+                None | Some("holiday") => true,
+                Some("business") => false,
+                Some(marker) => bail!(
+                    "Unknown marker '{marker}' at line {lineno}. Expected 'holiday' or 'business'"
+                ),
+            };
+            if let Some(extra) = cols.next() {
+                bail!("Unexpected column '{extra}' at line {lineno}");
+            }
+
+            let is_weekend = holiday_weekdays.contains(&date.weekday());
+            match (is_holiday, is_weekend) {
+                (true, false) => extra_holds.push(date),
+                (false, true) => extra_bizds.push(date),
+                _ => {}
+            }
+        }
+        Calendar::_new(
+            extra_holds,
+            extra_bizds,
+            valid_from,
+            valid_to,
+            holiday_weekdays,
+        )
+    }
+
+    /// Write extra holidays and extra business days of the calendar as CSV.
+    ///
+    /// Rows are sorted by date and each row has a marker, `holiday` or `business`.
+    /// The output can be read with [`Calendar::from_csv`].
+    /// Note that valid period and holiday weekdays are not written.
+    ///
+    /// # Errors
+    /// When writing to `writer` fails.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> anyhow::Result<()> {
+        let holds = self.extra_holidays().iter().map(|d| (d, "holiday"));
+        let bizds = self.extra_bizdays().iter().map(|d| (d, "business"));
+        let mut rows: Vec<_> = holds.chain(bizds).collect();
+        rows.sort();
+        for (date, marker) in rows {
+            writeln!(writer, "{date},{marker}")?;
+        }
+        Ok(())
+    }
 }
 
 //
@@ -444,6 +694,63 @@ impl Calendar {
         }
     }
 
+    /// Convert the given range to a half-open interval `[start, end)` of supported dates.
+    ///
+    /// This returns [None] if the range is empty.
+    fn _half_open<R>(
+        &self,
+        range: R,
+        operation: &'static str,
+    ) -> Result<Option<Range<NaiveDate>>, CalendarError>
+    where
+        R: RangeBounds<NaiveDate>,
+    {
+        // treat trivial cases, unbounded or empty range
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => {
+                return Err(CalendarError::Unbounded { operation })
+            }
+            (Bound::Included(&s), Bound::Included(&e)) if s > e => return Ok(None),
+            (Bound::Included(&s), Bound::Excluded(&e)) if s >= e => return Ok(None),
+            (Bound::Excluded(&s), Bound::Included(&e)) if s >= e => return Ok(None),
+            (Bound::Excluded(&s), Bound::Excluded(&e)) if s >= e => return Ok(None),
+            _ => {}
+        };
+
+        let start = match range.start_bound() {
+            Bound::Unbounded => unreachable!(),
+            Bound::Included(&d) => self.is_suppoted(d)?,
+            Bound::Excluded(&d) => {
+                self.is_suppoted(d.checked_add_days(Days::new(1)).ok_or_else(|| {
+                    CalendarError::OutOfValidPeriod {
+                        date: d,
+                        valid_period: self.valid_period(),
+                    }
+                })?)?
+            }
+        };
+        let end = match range.end_bound() {
+            Bound::Unbounded => unreachable!(),
+            Bound::Included(&d) => self
+                .is_suppoted(d)?
+                .checked_add_days(Days::new(1))
+                .ok_or_else(|| CalendarError::OutOfValidPeriod {
+                    date: d,
+                    valid_period: self.valid_period(),
+                })?,
+            Bound::Excluded(&d) => {
+                self.is_suppoted(d.checked_sub_days(Days::new(1)).ok_or_else(|| {
+                    CalendarError::OutOfValidPeriod {
+                        date: d,
+                        valid_period: self.valid_period(),
+                    }
+                })?)?;
+                d
+            }
+        };
+        Ok(Some(start..end))
+    }
+
     /// Get the extra holidays of the calendar.
     #[inline]
     pub fn extra_holidays(&self) -> &[NaiveDate] {
@@ -500,52 +807,8 @@ impl Calendar {
     where
         R: RangeBounds<NaiveDate>,
     {
-        // treat trivial cases, unbounded or empty range
-        match (range.start_bound(), range.end_bound()) {
-            (Bound::Unbounded, _) | (_, Bound::Unbounded) => {
-                return Err(CalendarError::Unbounded {
-                    operation: "counting business days",
-                })
-            }
-            (Bound::Included(&s), Bound::Included(&e)) if s > e => return Ok(0),
-            (Bound::Included(&s), Bound::Excluded(&e)) if s >= e => return Ok(0),
-            (Bound::Excluded(&s), Bound::Included(&e)) if s >= e => return Ok(0),
-            (Bound::Excluded(&s), Bound::Excluded(&e)) if s >= e => return Ok(0),
-            _ => {}
-        };
-
-        // adjust range to half-open interval
-        // to count the days between [start, end).
-        let start = match range.start_bound() {
-            Bound::Unbounded => unreachable!(),
-            Bound::Included(&d) => self.is_suppoted(d)?,
-            Bound::Excluded(&d) => {
-                self.is_suppoted(d.checked_add_days(Days::new(1)).ok_or_else(|| {
-                    CalendarError::OutOfValidPeriod {
-                        date: d,
-                        valid_period: self.valid_period(),
-                    }
-                })?)?
-            }
-        };
-        let end = match range.end_bound() {
-            Bound::Unbounded => unreachable!(),
-            Bound::Included(&d) => self
-                .is_suppoted(d)?
-                .checked_add_days(Days::new(1))
-                .ok_or_else(|| CalendarError::OutOfValidPeriod {
-                    date: d,
-                    valid_period: self.valid_period(),
-                })?,
-            Bound::Excluded(&d) => {
-                self.is_suppoted(d.checked_sub_days(Days::new(1)).ok_or_else(|| {
-                    CalendarError::OutOfValidPeriod {
-                        date: d,
-                        valid_period: self.valid_period(),
-                    }
-                })?)?;
-                d
-            }
+        let Some(Range { start, end }) = self._half_open(range, "counting business days")? else {
+            return Ok(0);
         };
 
         let extra_hols = {
@@ -573,80 +836,99 @@ impl Calendar {
         Ok((naive_count - extra_hols + extra_bds) as usize)
     }
 
-    /// Check if the given date is a holiday.
-    ///
-    /// If the given date is not supported by the calendar, this method returns [`Err`].
-    #[inline]
-    pub fn is_holiday(&self, date: NaiveDate) -> Result<bool, CalendarError> {
-        let date = self.is_suppoted(date)?;
-        if self.holiday_weekdays().contains(&date.weekday()) {
-            Ok(self.0.extra_bizds.binary_search(&date).is_err())
-        } else {
-            Ok(self.0.extra_holds.binary_search(&date).is_ok())
-        }
-    }
-
-    /// Check if the given date is a business day.
+    /// Count the business days from `start` to the date offset by `tenor`.
     ///
-    /// If the given date is not supported by the calendar, this method returns [`Err`].
-    #[inline]
-    pub fn is_bizday(&self, date: NaiveDate) -> Result<bool, CalendarError> {
-        let date = self.is_suppoted(date)?;
-        if self.holiday_weekdays().contains(&date.weekday()) {
-            Ok(self.0.extra_bizds.binary_search(&date).is_ok())
-        } else {
-            Ok(self.0.extra_holds.binary_search(&date).is_err())
-        }
-    }
-
-    /// Iterator over the business days from the given date.
+    /// The end date is resolved with [`Tenor::apply`], so month-end stickiness is applied.
+    /// The range is half-open, that is, `start` is included but the end date is not,
+    /// which is consistent with `self.num_bizdays(start..end)`.
+    /// When the tenor is negative, the range is empty and this method returns `0`.
     ///
-    /// This iterator ends when iterated date is out of the valid period of the calendar.
-    /// The first date of the iterator is the given date if it is a business day.
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the range contains a date which is out of the valid period
     ///
     /// # Example
     /// ```
     /// use chrono::{NaiveDate, Weekday};
-    /// use qchrono::calendar::Calendar;
-    ///
-    /// let ymd = |y: i32, m: u32, d: u32| {
-    ///    NaiveDate::from_ymd_opt(y, m, d).unwrap()
-    /// };
+    /// use qchrono::{calendar::Calendar, duration::Tenor};
     ///
     /// let cal = Calendar::builder()
-    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
-    ///     .with_extra_holidays(vec![ymd(2021, 1, 6)])
+    ///     .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+    ///     .with_extra_holidays(vec![])
     ///     .with_extra_business_days(vec![])
     ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
     ///     .build()
     ///     .unwrap();
     ///
-    /// let mut iter = cal.iter_bizdays(ymd(2021, 1, 1));
+    /// let start = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(); // Mon
     ///
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(cal.num_bizdays_to_tenor(start, Tenor::Weeks(1)), Ok(5));
+    /// assert_eq!(cal.num_bizdays_to_tenor(start, Tenor::Days(-1)), Ok(0));
     /// ```
     #[inline]
-    pub fn iter_bizdays(
+    pub fn num_bizdays_to_tenor(
         &self,
         start: NaiveDate,
-    ) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
-        DateIterator {
-            cur: start,
-            from: self.0.valid_from,
-            to: self.0.valid_to,
-        }
-        .filter(move |d| self.is_bizday(*d).unwrap_or(false))
+        tenor: Tenor,
+    ) -> Result<usize, CalendarError> {
+        self.num_bizdays(start..tenor.apply(start))
     }
 
-    /// Iterator over the holidays from the given date.
+    /// Sum up weights of business days in the given range.
     ///
-    /// This iterator ends when iterated date is out of the valid period of the calendar.
-    /// The first date of the iterator is the given date if it is a holiday.
+    /// `weight` is called for each business day in the range and the results are summed.
+    /// [`Calendar::num_bizdays`] is the special case with `weight = |_| 1.0`,
+    /// although it is preferable for plain counts because it does not iterate over days.
+    /// When the range is empty, this method returns `0.0`.
+    ///
+    /// # Errors
+    /// * [`CalendarError::Unbounded`]: When the range is unbounded
+    /// * [`CalendarError::OutOfValidPeriod`]: When the range contains a date which is out of the valid period
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+    ///     .with_extra_holidays(vec![])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(); // Mon
+    /// let to = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap(); // Sat
+    ///
+    /// // Friday counts double
+    /// let weight = |d: NaiveDate| if d.weekday() == Weekday::Fri { 2.0 } else { 1.0 };
+    /// assert_eq!(cal.accrued_bizdays(from..to, weight), Ok(6.0));
+    /// ```
+    pub fn accrued_bizdays<R, F>(&self, range: R, weight: F) -> Result<f64, CalendarError>
+    where
+        R: RangeBounds<NaiveDate>,
+        F: Fn(NaiveDate) -> f64,
+    {
+        let Some(range) = self._half_open(range, "accruing business days")? else {
+            return Ok(0.);
+        };
+        let bizdays = DateRange {
+            from: range.start,
+            to: range.end,
+        }
+        .filter(|d| self.is_bizday(*d).unwrap_or(false));
+        Ok(bizdays.map(weight).sum())
+    }
+
+    /// Ordinal of the given date among business days of its year.
+    ///
+    /// This counts business days from January 1st through the given date (inclusive),
+    /// so the first business day of the year has ordinal `1`.
+    /// When the given date is a holiday, this returns the ordinal of the last business day before it
+    /// (`0` if there is no business day in the year before the date).
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When January 1st of the year or the given date is out of the valid period
     ///
     /// # Example
     /// ```
@@ -658,421 +940,1397 @@ impl Calendar {
     /// };
     ///
     /// let cal = Calendar::builder()
-    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
     ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
     ///     .with_extra_business_days(vec![])
     ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
     ///     .build()
     ///     .unwrap();
     ///
-    /// let mut iter = cal.iter_holidays(ymd(2021, 1, 1));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
-    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
-    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(cal.bizday_of_year(ymd(2021, 1, 4)), Ok(1));
+    /// assert_eq!(cal.bizday_of_year(ymd(2021, 1, 5)), Ok(2));
     /// ```
     #[inline]
-    pub fn iter_holidays(
-        &self,
-        start: NaiveDate,
-    ) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
-        DateIterator {
-            cur: start,
-            from: self.0.valid_from,
-            to: self.0.valid_to,
-        }
-        .filter(move |d| self.is_holiday(*d).unwrap_or(false))
+    pub fn bizday_of_year(&self, date: NaiveDate) -> Result<usize, CalendarError> {
+        let jan1 = date.with_ordinal(1).expect("January 1st always exists");
+        self.num_bizdays(jan1..=date)
     }
-}
-
-//
-// operators
-//
-impl BitAnd for Calendar {
-    type Output = Self;
 
-    fn bitand(self, rhs: Self) -> Self::Output {
-        Self::all_closed_of([self, rhs]).expect("`Some` for non-empty iterator")
+    /// The `n`-th business day of the given year, where `n` is 1-based.
+    ///
+    /// This is the inverse of [`Calendar::bizday_of_year`] for business days.
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the valid period ends before the `n`-th business day is found
+    ///   or January 1st of the year is out of the valid period
+    /// * [`CalendarError::OrdinalOutOfYear`]: When `n` is zero or the year has less than `n` business days
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cal.nth_bizday_of_year(2021, 1), Ok(ymd(2021, 1, 4)));
+    /// assert!(cal.nth_bizday_of_year(2021, 0).is_err());
+    /// ```
+    pub fn nth_bizday_of_year(&self, year: i32, n: usize) -> Result<NaiveDate, CalendarError> {
+        let not_exist = CalendarError::OrdinalOutOfYear { year, ordinal: n };
+        if n == 0 {
+            return Err(not_exist);
+        }
+        let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).ok_or(not_exist.clone())?;
+        let jan1 = self.is_suppoted(jan1)?;
+        let found = self
+            .iter_bizdays(jan1)
+            .take_while(|d| d.year() == year)
+            .nth(n - 1);
+        match found {
+            Some(d) => Ok(d),
+            None => {
+                let dec31 = NaiveDate::from_ymd_opt(year, 12, 31).ok_or(not_exist.clone())?;
+                self.is_suppoted(dec31)?;
+                Err(not_exist)
+            }
+        }
     }
-}
 
-impl BitOr for Calendar {
-    type Output = Self;
-
-    fn bitor(self, rhs: Self) -> Self::Output {
-        Self::any_closed_of([self, rhs]).expect("`Some` for non-empty iterator")
+    /// The whole given year as a half-open range, checking that the valid period covers it.
+    fn _year_range(&self, year: i32) -> Result<Range<NaiveDate>, CalendarError> {
+        let clamped = if year < 0 {
+            NaiveDate::MIN
+        } else {
+            NaiveDate::MAX
+        };
+        let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(clamped);
+        let dec31 = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(clamped);
+        let jan1 = self.is_suppoted(jan1)?;
+        let dec31 = self.is_suppoted(dec31)?;
+        Ok(jan1..(dec31 + Days::new(1)))
     }
-}
-
-// -----------------------------------------------------------------------------
-// DateIterator
-// -----------------------------------------------------------------------------
-struct DateIterator {
-    cur: NaiveDate,
-    from: NaiveDate,
-    to: NaiveDate,
-}
-
-impl Iterator for DateIterator {
-    type Item = NaiveDate;
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.cur < self.from || self.to <= self.cur {
-            return None;
+    /// All holidays in the given year, including holiday weekdays, in ascending order.
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the valid period does not cover the whole year
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let holidays = cal.holidays_in_year(2021).unwrap();
+    /// assert_eq!(holidays.len(), 105);
+    /// assert_eq!(holidays[..3], [ymd(2021, 1, 1), ymd(2021, 1, 2), ymd(2021, 1, 3)]);
+    /// assert!(cal.holidays_in_year(2022).is_err());
+    /// ```
+    pub fn holidays_in_year(&self, year: i32) -> Result<Vec<NaiveDate>, CalendarError> {
+        let Range { start, end } = self._year_range(year)?;
+        let holidays = DateRange {
+            from: start,
+            to: end,
         }
-        let ret = self.cur;
-        self.cur = self.cur.checked_add_days(chrono::Days::new(1))?;
-        Some(ret)
+        .filter(|d| {
+            self.is_holiday(*d)
+                .expect("Whole year is in the valid period")
+        })
+        .collect();
+        Ok(holidays)
     }
-}
-impl DoubleEndedIterator for DateIterator {
+
+    /// Number of business days in the given year.
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the valid period does not cover the whole year
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cal.business_days_in_year(2021), Ok(260));
+    /// assert!(cal.business_days_in_year(2020).is_err());
+    /// ```
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.cur < self.from || self.to <= self.cur {
-            return None;
-        }
-        let ret = self.cur;
-        self.cur = self.cur.checked_sub_days(chrono::Days::new(1))?;
-        Some(ret)
+    pub fn business_days_in_year(&self, year: i32) -> Result<usize, CalendarError> {
+        let range = self._year_range(year)?;
+        self.num_bizdays(range)
     }
-}
-
-// -----------------------------------------------------------------------------
-// CalendarBuilder
-// -----------------------------------------------------------------------------
-/// Builder of a calendar
-///
-/// The [`Calendar`] consists of the three data, extra holidays, extra business days, and valid period.
-/// (See the documentation of [`Calendar`] for more details)
-///
-/// This builder provides methods to set these data and build a new calendar.
-/// Weekdays treated as holidays can be controlled by the method [`CalendarBuilder::with_holiday_weekdays`].
-/// For typical calendars which treat Saturday and Sunday as holidays,
-/// please set [`Weekday::Sat`] and [`Weekday::Sun`] to this method.
-///
-/// This builder has type parameters for each data.
-/// These are used to control builder methods and prevent multiple calls of the same method.
-///
-/// # Example
-/// ```
-/// use chrono::NaiveDate;
-/// use qchrono::calendar::Calendar;
-///
-/// let ymd = |y: i32, m: u32, d: u32| {
-///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
-/// };
-///
-/// let cal = Calendar::builder()
-///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
-///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
-///     .with_extra_business_days(vec![])
-///     .with_holiday_weekdays(vec![])
-///     .build();
-///
-/// ````
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CalendarBuilder<H = (), B = (), V = (), W = ()> {
-    extra_holds: H,
-    extra_bizds: B,
-    valid_from: V,
-    valid_to: V,
-    holiday_weekdays: W,
-}
 
-//
-// construction
-//
-impl Default for CalendarBuilder {
+    /// Check if the given date is a holiday.
+    ///
+    /// If the given date is not supported by the calendar, this method returns [`Err`].
     #[inline]
-    fn default() -> Self {
-        Self {
-            extra_holds: (),
-            extra_bizds: (),
-            valid_from: (),
-            valid_to: (),
-            holiday_weekdays: (),
+    pub fn is_holiday(&self, date: NaiveDate) -> Result<bool, CalendarError> {
+        let date = self.is_suppoted(date)?;
+        if self.holiday_weekdays().contains(&date.weekday()) {
+            Ok(self.0.extra_bizds.binary_search(&date).is_err())
+        } else {
+            Ok(self.0.extra_holds.binary_search(&date).is_ok())
         }
     }
-}
-
-impl CalendarBuilder {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
 
-impl<B, V, W> CalendarBuilder<(), B, V, W> {
-    /// Set the extra holidays of the calendar.
+    /// Check if the given date is a business day.
     ///
-    /// As `extra_holds`, this function expects that days which are non-business day weekdays.
-    pub fn with_extra_holidays(
-        self,
-        extra_holds: Vec<NaiveDate>,
-    ) -> CalendarBuilder<Vec<NaiveDate>, B, V, W> {
-        CalendarBuilder {
-            extra_holds,
-            extra_bizds: self.extra_bizds,
-            valid_from: self.valid_from,
-            valid_to: self.valid_to,
-            holiday_weekdays: self.holiday_weekdays,
+    /// If the given date is not supported by the calendar, this method returns [`Err`].
+    #[inline]
+    pub fn is_bizday(&self, date: NaiveDate) -> Result<bool, CalendarError> {
+        let date = self.is_suppoted(date)?;
+        if self.holiday_weekdays().contains(&date.weekday()) {
+            Ok(self.0.extra_bizds.binary_search(&date).is_ok())
+        } else {
+            Ok(self.0.extra_holds.binary_search(&date).is_err())
         }
     }
-}
 
-impl<H, V, W> CalendarBuilder<H, (), V, W> {
-    /// Set the extra business days of the calendar.
+    /// Check if two calendars classify every date in the given range in the same way.
     ///
-    /// As `extra_bizds`, this function expects that days which are business day weekends.
-    pub fn with_extra_business_days(
-        self,
-        extra_bizds: Vec<NaiveDate>,
-    ) -> CalendarBuilder<H, Vec<NaiveDate>, V, W> {
-        CalendarBuilder {
-            extra_holds: self.extra_holds,
-            extra_bizds,
-            valid_from: self.valid_from,
-            valid_to: self.valid_to,
-            holiday_weekdays: self.holiday_weekdays,
+    /// Unlike `==`, which compares the whole data including valid periods,
+    /// this compares only whether each date in `over` is a holiday or not.
+    /// Hence calendars with different valid periods or different representations,
+    /// e.g. holiday weekdays vs extra holidays, can be equal over a common range.
+    /// An empty range is always equal.
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the valid period of either calendar
+    ///   does not cover the given range
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal1 = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    /// let cal2 = Calendar::builder()
+    ///     .with_valid_period(ymd(2020, 1, 1), ymd(2021, 7, 1))
+    ///     .with_extra_holidays(vec![ymd(2020, 1, 1), ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_ne!(cal1, cal2);
+    /// assert_eq!(cal1.holidays_eq(&cal2, ymd(2021, 1, 1)..ymd(2021, 7, 1)), Ok(true));
+    /// assert!(cal1.holidays_eq(&cal2, ymd(2020, 1, 1)..ymd(2021, 7, 1)).is_err());
+    /// ```
+    pub fn holidays_eq(
+        &self,
+        other: &Calendar,
+        over: Range<NaiveDate>,
+    ) -> Result<bool, CalendarError> {
+        let Range { start, end } = over;
+        if end <= start {
+            return Ok(true);
+        }
+        let last = end.pred_opt().expect("end is greater than start");
+        for cal in [self, other] {
+            cal.is_suppoted(start)?;
+            cal.is_suppoted(last)?;
         }
+        Ok(DateRange {
+            from: start,
+            to: end,
+        }
+        .all(|d| {
+            let lhs = self.is_holiday(d).expect("Range is in the valid period");
+            let rhs = other.is_holiday(d).expect("Range is in the valid period");
+            lhs == rhs
+        }))
     }
-}
 
-impl<H, B, W> CalendarBuilder<H, B, (), W> {
-    /// Set the valid period of the calendar.
+    /// The first business day strictly after the given date.
     ///
-    /// The valid period is a half-open interval `[valid_from, valid_to)` and
-    /// `valid_from < valid_to` must hold.
-    pub fn with_valid_period(
-        self,
-        from: NaiveDate,
-        to: NaiveDate,
-    ) -> CalendarBuilder<H, B, NaiveDate, W> {
-        CalendarBuilder {
-            extra_holds: self.extra_holds,
-            extra_bizds: self.extra_bizds,
-            valid_from: from,
-            valid_to: to,
-            holiday_weekdays: self.holiday_weekdays,
-        }
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the given date is out of the valid period
+    ///   or no business day is found after it within the valid period
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 4)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cal.next_bizday(ymd(2021, 1, 1)), Ok(ymd(2021, 1, 5)));
+    /// assert!(cal.next_bizday(ymd(2021, 1, 8)).is_err());
+    /// ```
+    #[inline]
+    pub fn next_bizday(&self, date: NaiveDate) -> Result<NaiveDate, CalendarError> {
+        let date = self.is_suppoted(date)?;
+        date.succ_opt()
+            .and_then(|d| self.iter_bizdays(d).next())
+            .ok_or_else(|| CalendarError::OutOfValidPeriod {
+                date,
+                valid_period: self.valid_period(),
+            })
     }
-}
 
-impl<H, B, V> CalendarBuilder<H, B, V, ()> {
-    /// Set the weekdays treated as holidays as default.
-    pub fn with_holiday_weekdays(
-        self,
-        holiday_weekdays: Vec<Weekday>,
-    ) -> CalendarBuilder<H, B, V, Vec<Weekday>> {
-        CalendarBuilder {
-            extra_holds: self.extra_holds,
-            extra_bizds: self.extra_bizds,
-            valid_from: self.valid_from,
-            valid_to: self.valid_to,
-            holiday_weekdays,
-        }
+    /// The last business day strictly before the given date.
+    ///
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the given date is out of the valid period
+    ///   or no business day is found before it within the valid period
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 4)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cal.prev_bizday(ymd(2021, 1, 5)), Ok(ymd(2021, 1, 1)));
+    /// assert!(cal.prev_bizday(ymd(2021, 1, 1)).is_err());
+    /// ```
+    #[inline]
+    pub fn prev_bizday(&self, date: NaiveDate) -> Result<NaiveDate, CalendarError> {
+        let date = self.is_suppoted(date)?;
+        date.pred_opt()
+            .and_then(|d| self.iter_bizdays(d).next_back())
+            .ok_or_else(|| CalendarError::OutOfValidPeriod {
+                date,
+                valid_period: self.valid_period(),
+            })
     }
-}
 
-impl CalendarBuilder<Vec<NaiveDate>, Vec<NaiveDate>, NaiveDate, Vec<Weekday>> {
-    /// Build a new calendar from the given data.
+    /// Adjust the given date to a business day with the given rule.
+    ///
+    /// This is a shorthand of [`HolidayAdj::adjust`].
     ///
     /// # Errors
-    /// - If the given extra holidays are holiday weekdays
-    /// - If the given extra business days are not holiday weekdays
-    /// - If the valid period is invalid (valie_to <= valid_from)
-    pub fn build(self) -> anyhow::Result<Calendar> {
-        Calendar::_new(
-            self.extra_holds,
-            self.extra_bizds,
-            self.valid_from,
-            self.valid_to,
-            self.holiday_weekdays,
-        )
+    /// See [`HolidayAdj::adjust`].
+    #[inline]
+    pub fn roll(&self, date: NaiveDate, adj: HolidayAdj) -> Result<NaiveDate, CalendarError> {
+        adj.adjust(date, self)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::vec;
+    /// Iterator over the business days from the given date.
+    ///
+    /// This iterator ends when iterated date is out of the valid period of the calendar.
+    /// The first date of the iterator is the given date if it is a business day.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 6)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut iter = cal.iter_bizdays(ymd(2021, 1, 1));
+    ///
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_bizdays(
+        &self,
+        start: NaiveDate,
+    ) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
+        DateIterator {
+            cur: start,
+            from: self.0.valid_from,
+            to: self.0.valid_to,
+        }
+        .filter(move |d| self.is_bizday(*d).unwrap_or(false))
+    }
 
-    use rstest::rstest;
+    /// Iterator over the business days within the given range.
+    ///
+    /// Unlike [`Calendar::iter_bizdays`], the iterator is bounded at both ends,
+    /// so [`DoubleEndedIterator::next_back`] yields the last business day of the range.
+    /// When the range is empty, the returned iterator yields nothing.
+    ///
+    /// # Errors
+    /// * [`CalendarError::Unbounded`]: When the range is unbounded
+    /// * [`CalendarError::OutOfValidPeriod`]: When the range contains a date which is out of the valid period
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 31))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 6)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let bds = cal.iter_bizdays_in(ymd(2021, 1, 4)..=ymd(2021, 1, 8)).unwrap();
+    /// assert_eq!(
+    ///     bds.collect::<Vec<_>>(),
+    ///     vec![ymd(2021, 1, 4), ymd(2021, 1, 5), ymd(2021, 1, 7), ymd(2021, 1, 8)]
+    /// );
+    ///
+    /// let mut bds = cal.iter_bizdays_in(ymd(2021, 1, 4)..ymd(2021, 1, 8)).unwrap();
+    /// assert_eq!(bds.next_back(), Some(ymd(2021, 1, 7)));
+    ///
+    /// // unbounded range is not supported
+    /// assert!(cal.iter_bizdays_in(ymd(2021, 1, 4)..).is_err());
+    /// ```
+    #[inline]
+    pub fn iter_bizdays_in<R>(
+        &self,
+        range: R,
+    ) -> Result<impl DoubleEndedIterator<Item = NaiveDate> + '_, CalendarError>
+    where
+        R: RangeBounds<NaiveDate>,
+    {
+        let range = self._half_open(range, "iterating business days")?;
+        let range = range.unwrap_or(self.0.valid_from..self.0.valid_from);
+        Ok(DateRange {
+            from: range.start,
+            to: range.end,
+        }
+        .filter(move |d| self.is_bizday(*d).unwrap_or(false)))
+    }
 
-    use super::*;
+    /// Iterator over the holidays from the given date.
+    ///
+    /// This iterator ends when iterated date is out of the valid period of the calendar.
+    /// The first date of the iterator is the given date if it is a holiday.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::Calendar;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+    ///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut iter = cal.iter_holidays(ymd(2021, 1, 1));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
+    /// assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_holidays(
+        &self,
+        start: NaiveDate,
+    ) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
+        DateIterator {
+            cur: start,
+            from: self.0.valid_from,
+            to: self.0.valid_to,
+        }
+        .filter(move |d| self.is_holiday(*d).unwrap_or(false))
+    }
+}
+
+//
+// operators
+//
+impl BitAnd for Calendar {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::all_closed_of([self, rhs]).expect("`Some` for non-empty iterator")
+    }
+}
+
+impl BitOr for Calendar {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::any_closed_of([self, rhs]).expect("`Some` for non-empty iterator")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// DateIterator
+// -----------------------------------------------------------------------------
+struct DateIterator {
+    cur: NaiveDate,
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+impl Iterator for DateIterator {
+    type Item = NaiveDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur < self.from || self.to <= self.cur {
+            return None;
+        }
+        let ret = self.cur;
+        self.cur = self.cur.checked_add_days(chrono::Days::new(1))?;
+        Some(ret)
+    }
+}
+impl DoubleEndedIterator for DateIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cur < self.from || self.to <= self.cur {
+            return None;
+        }
+        let ret = self.cur;
+        self.cur = self.cur.checked_sub_days(chrono::Days::new(1))?;
+        Some(ret)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// DateRange
+// -----------------------------------------------------------------------------
+/// Iterator over the half-open interval `[from, to)` of dates.
+struct DateRange {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+impl Iterator for DateRange {
+    type Item = NaiveDate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.to <= self.from {
+            return None;
+        }
+        let ret = self.from;
+        self.from = self.from.checked_add_days(chrono::Days::new(1))?;
+        Some(ret)
+    }
+}
+impl DoubleEndedIterator for DateRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.to <= self.from {
+            return None;
+        }
+        self.to = self.to.checked_sub_days(chrono::Days::new(1))?;
+        Some(self.to)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CalendarBuilder
+// -----------------------------------------------------------------------------
+/// Builder of a calendar
+///
+/// The [`Calendar`] consists of the three data, extra holidays, extra business days, and valid period.
+/// (See the documentation of [`Calendar`] for more details)
+///
+/// This builder provides methods to set these data and build a new calendar.
+/// Weekdays treated as holidays can be controlled by the method [`CalendarBuilder::with_holiday_weekdays`].
+/// For typical calendars which treat Saturday and Sunday as holidays,
+/// please set [`Weekday::Sat`] and [`Weekday::Sun`] to this method.
+///
+/// This builder has type parameters for each data.
+/// These are used to control builder methods and prevent multiple calls of the same method.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use qchrono::calendar::Calendar;
+///
+/// let ymd = |y: i32, m: u32, d: u32| {
+///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+/// };
+///
+/// let cal = Calendar::builder()
+///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 10))
+///     .with_extra_holidays(vec![ymd(2021, 1, 1)])
+///     .with_extra_business_days(vec![])
+///     .with_holiday_weekdays(vec![])
+///     .build();
+///
+/// ````
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalendarBuilder<H = (), B = (), V = (), W = ()> {
+    extra_holds: H,
+    extra_bizds: B,
+    valid_from: V,
+    valid_to: V,
+    holiday_weekdays: W,
+}
+
+//
+// construction
+//
+impl Default for CalendarBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            extra_holds: (),
+            extra_bizds: (),
+            valid_from: (),
+            valid_to: (),
+            holiday_weekdays: (),
+        }
+    }
+}
+
+impl CalendarBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B, V, W> CalendarBuilder<(), B, V, W> {
+    /// Set the extra holidays of the calendar.
+    ///
+    /// As `extra_holds`, this function expects that days which are non-business day weekdays.
+    pub fn with_extra_holidays(
+        self,
+        extra_holds: Vec<NaiveDate>,
+    ) -> CalendarBuilder<Vec<NaiveDate>, B, V, W> {
+        CalendarBuilder {
+            extra_holds,
+            extra_bizds: self.extra_bizds,
+            valid_from: self.valid_from,
+            valid_to: self.valid_to,
+            holiday_weekdays: self.holiday_weekdays,
+        }
+    }
+}
+
+impl<H, V, W> CalendarBuilder<H, (), V, W> {
+    /// Set the extra business days of the calendar.
+    ///
+    /// As `extra_bizds`, this function expects that days which are business day weekends.
+    pub fn with_extra_business_days(
+        self,
+        extra_bizds: Vec<NaiveDate>,
+    ) -> CalendarBuilder<H, Vec<NaiveDate>, V, W> {
+        CalendarBuilder {
+            extra_holds: self.extra_holds,
+            extra_bizds,
+            valid_from: self.valid_from,
+            valid_to: self.valid_to,
+            holiday_weekdays: self.holiday_weekdays,
+        }
+    }
+}
+
+impl<H, B, W> CalendarBuilder<H, B, (), W> {
+    /// Set the valid period of the calendar.
+    ///
+    /// The valid period is a half-open interval `[valid_from, valid_to)` and
+    /// `valid_from < valid_to` must hold.
+    pub fn with_valid_period(
+        self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> CalendarBuilder<H, B, NaiveDate, W> {
+        CalendarBuilder {
+            extra_holds: self.extra_holds,
+            extra_bizds: self.extra_bizds,
+            valid_from: from,
+            valid_to: to,
+            holiday_weekdays: self.holiday_weekdays,
+        }
+    }
+}
+
+impl<H, B, V> CalendarBuilder<H, B, V, ()> {
+    /// Set the weekdays treated as holidays as default.
+    pub fn with_holiday_weekdays(
+        self,
+        holiday_weekdays: Vec<Weekday>,
+    ) -> CalendarBuilder<H, B, V, Vec<Weekday>> {
+        CalendarBuilder {
+            extra_holds: self.extra_holds,
+            extra_bizds: self.extra_bizds,
+            valid_from: self.valid_from,
+            valid_to: self.valid_to,
+            holiday_weekdays,
+        }
+    }
+}
+
+impl<B, V> CalendarBuilder<Vec<NaiveDate>, B, V, Vec<Weekday>> {
+    /// Add holidays generated from recurring rules to the extra holidays.
+    ///
+    /// Rules are expanded for each year and only dates in `[from, to)` are added.
+    /// Fixed-date holidays are shifted by their [`super::Observance`] if they fall on holiday weekdays.
+    /// Shifted holidays avoid days which are already holidays, including those generated by rules,
+    /// e.g. Christmas on Sunday is observed on Tuesday if Boxing Day falls on Monday.
+    /// When several holidays are shifted at once, preceding rules take earlier days.
+    /// Generated dates on holiday weekdays are dropped since they are holidays anyway.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::{Calendar, HolidayRule, Observance};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// let rules = [
+    ///     HolidayRule::Fixed { month: 1, day: 1, observance: Observance::Nearest },
+    ///     HolidayRule::NthWeekday { month: 1, n: 3, weekday: Weekday::Mon },
+    /// ];
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2020, 1, 1), ymd(2030, 1, 1))
+    ///     .with_extra_holidays(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .with_holiday_rules(&rules, ymd(2020, 1, 1), ymd(2030, 1, 1))
+    ///     .with_extra_business_days(vec![])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // New Year's Day of 2022 is Saturday
+    /// assert!(cal.is_holiday(ymd(2021, 12, 31)).unwrap());
+    /// assert!(cal.is_holiday(ymd(2022, 1, 17)).unwrap());
+    /// ```
+    pub fn with_holiday_rules(
+        mut self,
+        rules: &[HolidayRule],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Self {
+        // holidays around new year can be shifted across the year boundary
+        let years = from.year().saturating_sub(1)..=to.year().saturating_add(1);
+        let is_weekend = |d: &NaiveDate| self.holiday_weekdays.contains(&d.weekday());
+
+        // shifted holidays must not be observed on days which are holidays without shifting
+        let mut taken: BTreeSet<_> = self.extra_holds.iter().copied().collect();
+        taken.extend(
+            years
+                .clone()
+                .flat_map(|y| rules.iter().filter_map(move |r| r.date_in(y)))
+                .filter(|d| !is_weekend(d)),
+        );
+        for year in years {
+            for rule in rules {
+                if let Some(d) = rule._observed_in(year, &self.holiday_weekdays, &taken) {
+                    taken.insert(d);
+                    if from <= d && d < to && !is_weekend(&d) {
+                        self.extra_holds.push(d);
+                    }
+                }
+            }
+        }
+        self
+    }
+}
+
+impl CalendarBuilder<Vec<NaiveDate>, Vec<NaiveDate>, NaiveDate, Vec<Weekday>> {
+    /// Build a new calendar from the given data.
+    ///
+    /// # Errors
+    /// - If the given extra holidays are holiday weekdays
+    /// - If the given extra business days are not holiday weekdays
+    /// - If the valid period is invalid (valie_to <= valid_from)
+    pub fn build(self) -> anyhow::Result<Calendar> {
+        Calendar::_new(
+            self.extra_holds,
+            self.extra_bizds,
+            self.valid_from,
+            self.valid_to,
+            self.holiday_weekdays,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::calendar::Observance;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let csv = "2021-01-01,holiday\n2021-01-11,holiday\n2021-01-16,business\n";
+
+        let cal =
+            Calendar::from_csv(csv.as_bytes(), ymd(2021, 1, 1), ymd(2022, 1, 1), false).unwrap();
+        let mut written = Vec::new();
+        cal.to_csv(&mut written).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 11)]);
+        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 16)]);
+        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
+        assert_eq!(String::from_utf8(written).unwrap(), csv);
+    }
+
+    #[test]
+    fn test_from_csv_lenient() {
+        // no marker, blank line, holiday on weekend and out of valid period
+        let csv = "2021-01-11\n\n 2021-01-03 , holiday\n2020-12-31\n2021-01-05,business\n";
+
+        let cal =
+            Calendar::from_csv(csv.as_bytes(), ymd(2021, 1, 1), ymd(2022, 1, 1), false).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 11)]);
+        assert_eq!(cal.extra_bizdays(), &[]);
+    }
+
+    #[test]
+    fn test_from_csv_weekend_as_bizday() {
+        let csv = "2021-01-03,holiday\n2021-01-09,business\n";
+
+        let cal =
+            Calendar::from_csv(csv.as_bytes(), ymd(2021, 1, 1), ymd(2022, 1, 1), true).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 3)]);
+        assert_eq!(cal.extra_bizdays(), &[]);
+        assert!(cal.holiday_weekdays().is_empty());
+    }
+
+    #[rstest]
+    #[case("2021-01-01\n2021-13-01\n", "line 2")]
+    #[case("2021-01-01\n\n2021-01-05,closed\n", "line 3")]
+    #[case("2021-01-01,holiday,extra\n", "line 1")]
+    #[case("holiday\n", "line 1")]
+    fn test_from_csv_err(#[case] csv: &str, #[case] expected: &str) {
+        let res = Calendar::from_csv(csv.as_bytes(), ymd(2021, 1, 1), ymd(2022, 1, 1), false);
+
+        let msg = format!("{:#}", res.unwrap_err());
+        assert!(msg.contains(expected), "{msg}");
+    }
+
+    #[test]
+    fn test_new_ok() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_ok());
+    }
+
+    #[test]
+    fn test_new_ok_dup() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 1), ymd(2021, 1, 5)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sun, Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_ok());
+    }
+
+    #[test]
+    fn test_new_ok_unsorted() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 5), ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_ok());
+    }
+
+    #[test]
+    fn test_new_ng_weekend_extra_hol() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_err());
+    }
+
+    #[test]
+    fn test_new_ok_weekend_extra_hol_with_treat_weekend_as_business_day() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![],
+        );
+
+        assert!(cal.is_ok());
+    }
+
+    #[test]
+    fn test_new_ng_weekday_extra_bd() {
+        let cal = Calendar::_new(
+            vec![],
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_err());
+    }
+
+    #[test]
+    fn test_new_ng_unsorted_period() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 10),
+            ymd(2021, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        );
+
+        assert!(cal.is_err());
+    }
+
+    #[test]
+    fn test_new_ng_extra_bd_with_treat_weekend_as_business_day() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![],
+        );
+
+        assert!(cal.is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(&cal).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "extra_holidays": ["2021-01-01"],
+                "extra_business_days": ["2021-01-02"],
+                "valid_from": "2021-01-01",
+                "valid_to": "2021-01-10",
+                "holiday_weekdays": ["Sat", "Sun"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let json = serde_json::json!({
+            "extra_holidays": ["2021-01-01"],
+            "extra_business_days": ["2021-01-02"],
+            "valid_from": "2021-01-01",
+            "valid_to": "2021-01-10",
+            "holiday_weekdays": ["Sat", "Sun"]
+        });
+
+        let cal: Calendar = serde_json::from_value(json).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
+        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2)]);
+        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
+        assert_eq!(
+            cal.valid_period(),
+            Range {
+                start: ymd(2021, 1, 1),
+                end: ymd(2021, 1, 10)
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_treat_weekend_as_business_day() {
+        let json = serde_json::json!({
+            "extra_holidays": ["2021-01-01"],
+            "extra_business_days": [],
+            "valid_from": "2021-01-01",
+            "valid_to": "2021-01-10",
+            "holiday_weekdays": []
+        });
+
+        let cal: Calendar = serde_json::from_value(json).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
+        assert_eq!(cal.extra_bizdays(), &[]);
+        assert!(cal.holiday_weekdays().is_empty());
+        assert_eq!(
+            cal.valid_period(),
+            Range {
+                start: ymd(2021, 1, 1),
+                end: ymd(2021, 1, 10)
+            }
+        );
+    }
+
+    #[test]
+    fn test_of_any_closed_empty() {
+        let cal = Calendar::any_closed_of([]);
+
+        assert!(cal.is_none());
+    }
+
+    #[test]
+    fn test_of_any_closed_single() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let cal = Calendar::any_closed_of([cal1.clone()]).unwrap();
+
+        assert_eq!(cal, cal1);
+    }
+
+    #[test]
+    fn test_of_any_closed_single_treat_weekend_as_business_day() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![],
+        )
+        .unwrap();
+
+        let cal = Calendar::any_closed_of([cal1.clone()]).unwrap();
+
+        assert_eq!(cal, cal1);
+    }
+
+    #[test]
+    fn test_of_any_closed_multiple() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2), ymd(2021, 1, 3)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 5)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
+        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2),]);
+        assert!(!cal.holiday_weekdays().is_empty());
+    }
+
+    #[test]
+    fn test_of_any_closed_multiple_treat_weekend_as_bizday() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 5)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
 
-    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
-        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
+        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2),]);
+        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
     }
 
     #[test]
-    fn test_new_ok() {
-        let cal = Calendar::_new(
+    fn test_of_any_closed_multiple_treat_weekend_as_bizday_all() {
+        let cal1 = Calendar::_new(
             vec![ymd(2021, 1, 1)],
             vec![],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![Weekday::Sat, Weekday::Sun],
-        );
-
-        assert!(cal.is_ok());
-    }
-
-    #[test]
-    fn test_new_ok_dup() {
-        let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 1), ymd(2021, 1, 5)],
+            vec![],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 5)],
             vec![],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![Weekday::Sun, Weekday::Sat, Weekday::Sun],
-        );
+            vec![],
+        )
+        .unwrap();
 
-        assert!(cal.is_ok());
+        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
+        assert_eq!(cal.extra_bizdays(), &[]);
+        assert!(cal.holiday_weekdays().is_empty());
     }
 
     #[test]
-    fn test_new_ok_unsorted() {
-        let cal = Calendar::_new(
-            vec![ymd(2021, 1, 5), ymd(2021, 1, 1)],
-            vec![],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
-            vec![Weekday::Sat, Weekday::Sun],
-        );
+    fn test_of_all_closed_empty() {
+        let cal = Calendar::all_closed_of([]);
 
-        assert!(cal.is_ok());
+        assert!(cal.is_none());
     }
 
     #[test]
-    fn test_new_ng_weekend_extra_hol() {
-        let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
-            vec![],
+    fn test_of_all_closed_single() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
             vec![Weekday::Sat, Weekday::Sun],
-        );
+        )
+        .unwrap();
 
-        assert!(cal.is_err());
+        let cal = Calendar::all_closed_of([cal1.clone()]).unwrap();
+
+        assert_eq!(cal, cal1);
     }
 
     #[test]
-    fn test_new_ok_weekend_extra_hol_with_treat_weekend_as_business_day() {
-        let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+    fn test_of_all_closed_single_treat_weekend_as_business_day() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
             vec![],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
             vec![],
-        );
+        )
+        .unwrap();
 
-        assert!(cal.is_ok());
+        let cal = Calendar::all_closed_of([cal1.clone()]).unwrap();
+
+        assert_eq!(cal, cal1);
     }
 
     #[test]
-    fn test_new_ng_weekday_extra_bd() {
-        let cal = Calendar::_new(
-            vec![],
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+    fn test_of_all_closed_multiple() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![ymd(2021, 1, 2), ymd(2021, 1, 3)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
             vec![Weekday::Sat, Weekday::Sun],
-        );
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 5)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
 
-        assert!(cal.is_err());
+        let cal = Calendar::all_closed_of([cal1, cal2]).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
+        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2), ymd(2021, 1, 3)]);
+        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
     }
 
     #[test]
-    fn test_new_ng_unsorted_period() {
-        let cal = Calendar::_new(
+    fn test_of_all_closed_multiple_treat_weekend_as_bizday() {
+        let cal1 = Calendar::_new(
             vec![ymd(2021, 1, 1)],
             vec![],
+            ymd(2021, 1, 1),
             ymd(2021, 1, 10),
+            vec![],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 5)],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
             vec![Weekday::Sat, Weekday::Sun],
-        );
+        )
+        .unwrap();
 
-        assert!(cal.is_err());
+        let cal = Calendar::all_closed_of([cal1, cal2]).unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
+        assert_eq!(cal.extra_bizdays(), &[]);
+        assert_eq!(cal.holiday_weekdays(), &[]);
     }
 
     #[test]
-    fn test_new_ng_extra_bd_with_treat_weekend_as_business_day() {
-        let cal = Calendar::_new(
+    fn test_of_xor_holidays_empty() {
+        let cal = Calendar::xor_holidays_of([]);
+
+        assert!(cal.is_none());
+    }
+
+    #[test]
+    fn test_of_xor_holidays_single() {
+        let cal1 = Calendar::_new(
             vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![],
-        );
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
 
-        assert!(cal.is_err());
+        let cal = Calendar::xor_holidays_of([cal1.clone()]).unwrap();
+
+        assert_eq!(cal, cal1);
     }
 
     #[test]
-    fn test_serialize() {
-        let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
+    fn test_of_xor_holidays_multiple() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 4), ymd(2021, 1, 12)],
+            vec![ymd(2021, 1, 2), ymd(2021, 1, 3)],
+            ymd(2020, 12, 31),
+            ymd(2021, 1, 15),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 5), ymd(2021, 1, 21)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            ymd(2021, 1, 31),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let json = serde_json::to_value(&cal).unwrap();
+        let cal = Calendar::xor_holidays_of([cal1, cal2]).unwrap();
 
+        assert_eq!(cal.valid_period(), ymd(2021, 1, 1)..ymd(2021, 1, 15));
         assert_eq!(
-            json,
-            serde_json::json!({
-                "extra_holidays": ["2021-01-01"],
-                "extra_business_days": ["2021-01-02"],
-                "valid_from": "2021-01-01",
-                "valid_to": "2021-01-10",
-                "holiday_weekdays": ["Sat", "Sun"]
-            })
+            cal.extra_holidays(),
+            &[
+                ymd(2021, 1, 3),
+                ymd(2021, 1, 4),
+                ymd(2021, 1, 5),
+                ymd(2021, 1, 12)
+            ]
+        );
+        assert_eq!(cal.extra_bizdays(), &[]);
+        assert_eq!(cal.holiday_weekdays(), &[]);
+        let holidays = cal.iter_holidays(ymd(2021, 1, 1)).collect::<Vec<_>>();
+        assert_eq!(
+            holidays,
+            vec![
+                ymd(2021, 1, 3),
+                ymd(2021, 1, 4),
+                ymd(2021, 1, 5),
+                ymd(2021, 1, 12)
+            ]
         );
     }
 
     #[test]
-    fn test_deserialize() {
-        let json = serde_json::json!({
-            "extra_holidays": ["2021-01-01"],
-            "extra_business_days": ["2021-01-02"],
-            "valid_from": "2021-01-01",
-            "valid_to": "2021-01-10",
-            "holiday_weekdays": ["Sat", "Sun"]
-        });
+    fn test_of_xor_holidays_multiple_treat_weekend_as_bizday() {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 5)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            vec![ymd(2021, 1, 5)],
+            vec![ymd(2021, 1, 2)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
 
-        let cal: Calendar = serde_json::from_value(json).unwrap();
+        let cal = Calendar::xor_holidays_of([cal1, cal2]).unwrap();
 
         assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
         assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2)]);
         assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
-        assert_eq!(
-            cal.valid_period(),
-            Range {
-                start: ymd(2021, 1, 1),
-                end: ymd(2021, 1, 10)
-            }
-        );
     }
 
     #[test]
-    fn test_deserialize_treat_weekend_as_business_day() {
-        let json = serde_json::json!({
-            "extra_holidays": ["2021-01-01"],
-            "extra_business_days": [],
-            "valid_from": "2021-01-01",
-            "valid_to": "2021-01-10",
-            "holiday_weekdays": []
-        });
+    fn test_of_xor_holidays_no_overlap() {
+        let cal1 =
+            Calendar::_new(vec![], vec![], ymd(2021, 1, 1), ymd(2021, 1, 10), vec![]).unwrap();
+        let cal2 =
+            Calendar::_new(vec![], vec![], ymd(2021, 1, 10), ymd(2021, 1, 20), vec![]).unwrap();
 
-        let cal: Calendar = serde_json::from_value(json).unwrap();
+        let cal = Calendar::xor_holidays_of([cal1, cal2]);
+
+        assert!(cal.is_none());
+    }
+
+    #[test]
+    fn test_with_extended_period() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 4)],
+            vec![ymd(2021, 1, 9)],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let extended = cal.with_extended_period(ymd(2021, 2, 1)).unwrap();
+
+        assert_eq!(extended.valid_period(), ymd(2021, 1, 1)..ymd(2021, 2, 1));
+        assert_eq!(extended.extra_holidays(), cal.extra_holidays());
+        assert_eq!(extended.extra_bizdays(), cal.extra_bizdays());
+        for date in (DateRange {
+            from: ymd(2021, 1, 1),
+            to: ymd(2021, 1, 10),
+        }) {
+            assert_eq!(extended.is_holiday(date), cal.is_holiday(date));
+        }
+        for date in (DateRange {
+            from: ymd(2021, 1, 10),
+            to: ymd(2021, 2, 1),
+        }) {
+            let is_weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+            assert_eq!(extended.is_holiday(date), Ok(is_weekend));
+        }
+        assert_eq!(cal.with_extended_period(ymd(2021, 1, 10)).unwrap(), cal);
+    }
+
+    #[test]
+    fn test_with_extended_period_err() {
+        let cal = Calendar::_new(
+            vec![],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let res = cal.with_extended_period(ymd(2021, 1, 9));
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_valid_period() {
+        let cal = Calendar::_new(
+            vec![],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
 
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
-        assert_eq!(cal.extra_bizdays(), &[]);
-        assert!(cal.holiday_weekdays().is_empty());
         assert_eq!(
             cal.valid_period(),
             Range {
@@ -1083,15 +2341,26 @@ mod tests {
     }
 
     #[test]
-    fn test_of_any_closed_empty() {
-        let cal = Calendar::any_closed_of([]);
+    fn test_validate() {
+        let cal = Calendar::_new(
+            vec![],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
 
-        assert!(cal.is_none());
+        assert!(cal.is_suppoted(ymd(2020, 12, 31)).is_err());
+        assert!(cal.is_suppoted(ymd(2021, 1, 1)).is_ok());
+        assert!(cal.is_suppoted(ymd(2021, 1, 9)).is_ok());
+        assert!(cal.is_suppoted(ymd(2021, 1, 10)).is_err());
+        assert!(cal.is_suppoted(ymd(2021, 1, 11)).is_err());
     }
 
     #[test]
-    fn test_of_any_closed_single() {
-        let cal1 = Calendar::_new(
+    fn test_is_holiday() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
@@ -1100,15 +2369,24 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::any_closed_of([cal1.clone()]).unwrap();
-
-        assert_eq!(cal, cal1);
+        assert!(cal.is_holiday(ymd(2020, 12, 30)).is_err());
+        assert!(cal.is_holiday(ymd(2020, 12, 31)).is_err());
+        assert!(cal.is_holiday(ymd(2021, 1, 1)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 2)).unwrap());
+        assert!(cal.is_holiday(ymd(2021, 1, 3)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 4)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 5)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 6)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 7)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 8)).unwrap());
+        assert!(cal.is_holiday(ymd(2021, 1, 9)).unwrap());
+        assert!(cal.is_holiday(ymd(2021, 1, 10)).is_err());
     }
 
     #[test]
-    fn test_of_any_closed_single_treat_weekend_as_business_day() {
-        let cal1 = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
+    fn test_is_holiday_treat_weekend_as_business_day() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
             vec![],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
@@ -1116,23 +2394,18 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::any_closed_of([cal1.clone()]).unwrap();
-
-        assert_eq!(cal, cal1);
+        assert!(cal.is_holiday(ymd(2020, 12, 30)).is_err());
+        assert!(cal.is_holiday(ymd(2020, 12, 31)).is_err());
+        assert!(cal.is_holiday(ymd(2021, 1, 1)).unwrap());
+        assert!(cal.is_holiday(ymd(2021, 1, 2)).unwrap());
+        assert!(!cal.is_holiday(ymd(2021, 1, 3)).unwrap()); // Sunday
+        assert!(cal.is_holiday(ymd(2021, 1, 10)).is_err());
     }
 
     #[test]
-    fn test_of_any_closed_multiple() {
-        let cal1 = Calendar::_new(
+    fn test_is_business_day() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2), ymd(2021, 1, 3)],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
-            vec![Weekday::Sat, Weekday::Sun],
-        )
-        .unwrap();
-        let cal2 = Calendar::_new(
-            vec![ymd(2021, 1, 5)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
@@ -1140,25 +2413,39 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
-
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
-        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2),]);
-        assert!(!cal.holiday_weekdays().is_empty());
+        assert!(!cal.is_bizday(ymd(2021, 1, 1)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 2)).unwrap());
+        assert!(!cal.is_bizday(ymd(2021, 1, 3)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 4)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 5)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 6)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 7)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 8)).unwrap());
+        assert!(!cal.is_bizday(ymd(2021, 1, 9)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 10)).is_err());
     }
 
     #[test]
-    fn test_of_any_closed_multiple_treat_weekend_as_bizday() {
-        let cal1 = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
+    fn test_is_business_day_treat_weekend_as_business_day() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
             vec![],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
             vec![],
         )
         .unwrap();
-        let cal2 = Calendar::_new(
-            vec![ymd(2021, 1, 5)],
+
+        assert!(!cal.is_bizday(ymd(2021, 1, 1)).unwrap());
+        assert!(!cal.is_bizday(ymd(2021, 1, 2)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 3)).unwrap());
+        assert!(cal.is_bizday(ymd(2021, 1, 10)).is_err());
+    }
+
+    #[test]
+    fn test_iter_bizdays() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
@@ -1166,49 +2453,42 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
+        let mut iter = cal.iter_bizdays(ymd(2021, 1, 1));
 
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
-        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2),]);
-        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 6)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn test_of_any_closed_multiple_treat_weekend_as_bizday_all() {
-        let cal1 = Calendar::_new(
+    fn test_iter_bizdays_rev() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
-            vec![],
-        )
-        .unwrap();
-        let cal2 = Calendar::_new(
-            vec![ymd(2021, 1, 5)],
-            vec![],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![],
+            vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let cal = Calendar::any_closed_of([cal1, cal2]).unwrap();
-
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1), ymd(2021, 1, 5)]);
-        assert_eq!(cal.extra_bizdays(), &[]);
-        assert!(cal.holiday_weekdays().is_empty());
-    }
-
-    #[test]
-    fn test_of_all_closed_empty() {
-        let cal = Calendar::all_closed_of([]);
+        let mut iter = cal.iter_bizdays(ymd(2021, 1, 9)).rev();
 
-        assert!(cal.is_none());
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 6)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn test_of_all_closed_single() {
-        let cal1 = Calendar::_new(
+    fn test_iter_holidays() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
@@ -1217,39 +2497,74 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::all_closed_of([cal1.clone()]).unwrap();
+        let mut iter = cal.iter_holidays(ymd(2021, 1, 1));
 
-        assert_eq!(cal, cal1);
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
-    fn test_of_all_closed_single_treat_weekend_as_business_day() {
-        let cal1 = Calendar::_new(
+    fn test_iter_holidays_rev() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![],
+            vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let cal = Calendar::all_closed_of([cal1.clone()]).unwrap();
+        let mut iter = cal.iter_holidays(ymd(2021, 1, 9)).rev();
 
-        assert_eq!(cal, cal1);
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
+        assert_eq!(iter.next(), None);
     }
 
-    #[test]
-    fn test_of_all_closed_multiple() {
-        let cal1 = Calendar::_new(
+    #[rstest]
+    #[case(ymd(2021, 1, 1)..ymd(2021, 1, 8), vec![ymd(2021, 1, 2), ymd(2021, 1, 4), ymd(2021, 1, 5), ymd(2021, 1, 6), ymd(2021, 1, 7)])]
+    #[case(ymd(2021, 1, 4)..ymd(2021, 1, 4), vec![])]
+    #[case(ymd(2021, 1, 9)..ymd(2021, 1, 10), vec![])]
+    fn test_iter_bizdays_in_excl(
+        #[case] range: Range<NaiveDate>,
+        #[case] expected: Vec<NaiveDate>,
+    ) {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2), ymd(2021, 1, 3)],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
-        let cal2 = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 5)],
+
+        let tested = cal
+            .iter_bizdays_in(range.clone())
+            .unwrap()
+            .collect::<Vec<_>>();
+        let rev = cal
+            .iter_bizdays_in(range)
+            .unwrap()
+            .rev()
+            .collect::<Vec<_>>();
+
+        assert_eq!(tested, expected);
+        assert_eq!(rev, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 1, 1)..=ymd(2021, 1, 8), vec![ymd(2021, 1, 2), ymd(2021, 1, 4), ymd(2021, 1, 5), ymd(2021, 1, 6), ymd(2021, 1, 7), ymd(2021, 1, 8)])]
+    #[case(ymd(2021, 1, 4)..=ymd(2021, 1, 4), vec![ymd(2021, 1, 4)])]
+    #[case(ymd(2021, 1, 5)..=ymd(2021, 1, 4), vec![])]
+    fn test_iter_bizdays_in_incl(
+        #[case] range: std::ops::RangeInclusive<NaiveDate>,
+        #[case] expected: Vec<NaiveDate>,
+    ) {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
@@ -1257,25 +2572,46 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::all_closed_of([cal1, cal2]).unwrap();
+        let tested = cal
+            .iter_bizdays_in(range.clone())
+            .unwrap()
+            .collect::<Vec<_>>();
+        let rev = cal
+            .iter_bizdays_in(range)
+            .unwrap()
+            .rev()
+            .collect::<Vec<_>>();
 
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
-        assert_eq!(cal.extra_bizdays(), &[ymd(2021, 1, 2), ymd(2021, 1, 3)]);
-        assert_eq!(cal.holiday_weekdays(), &[Weekday::Sat, Weekday::Sun]);
+        assert_eq!(tested, expected);
+        assert_eq!(rev, expected.into_iter().rev().collect::<Vec<_>>());
     }
 
     #[test]
-    fn test_of_all_closed_multiple_treat_weekend_as_bizday() {
-        let cal1 = Calendar::_new(
+    fn test_iter_bizdays_in_both_ends() {
+        let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![],
+            vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
-            vec![],
+            vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
-        let cal2 = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 5)],
+
+        let mut iter = cal
+            .iter_bizdays_in(ymd(2021, 1, 4)..=ymd(2021, 1, 6))
+            .unwrap();
+
+        assert_eq!(iter.next_back(), Some(ymd(2021, 1, 6)));
+        assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
+        assert_eq!(iter.next_back(), Some(ymd(2021, 1, 5)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_bizdays_in_err() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
             vec![ymd(2021, 1, 2)],
             ymd(2021, 1, 1),
             ymd(2021, 1, 10),
@@ -1283,215 +2619,278 @@ mod tests {
         )
         .unwrap();
 
-        let cal = Calendar::all_closed_of([cal1, cal2]).unwrap();
+        let unbounded_end = cal.iter_bizdays_in(ymd(2021, 1, 4)..).err();
+        let unbounded_stt = cal.iter_bizdays_in(..=ymd(2021, 1, 4)).err();
+        let out_of_period = cal
+            .iter_bizdays_in(ymd(2021, 1, 4)..=ymd(2021, 1, 10))
+            .err();
 
-        assert_eq!(cal.extra_holidays(), &[ymd(2021, 1, 1)]);
-        assert_eq!(cal.extra_bizdays(), &[]);
-        assert_eq!(cal.holiday_weekdays(), &[]);
+        assert!(matches!(
+            unbounded_end,
+            Some(CalendarError::Unbounded { .. })
+        ));
+        assert!(matches!(
+            unbounded_stt,
+            Some(CalendarError::Unbounded { .. })
+        ));
+        assert!(matches!(
+            out_of_period,
+            Some(CalendarError::OutOfValidPeriod { .. })
+        ));
     }
 
-    #[test]
-    fn test_valid_period() {
+    #[rstest]
+    #[case(ymd(2021, 1, 1), 0)]
+    #[case(ymd(2021, 1, 3), 0)]
+    #[case(ymd(2021, 1, 4), 1)]
+    #[case(ymd(2021, 1, 8), 5)]
+    #[case(ymd(2021, 1, 9), 5)]
+    #[case(ymd(2021, 12, 31), 260)]
+    fn test_bizday_of_year(#[case] d: NaiveDate, #[case] expected: usize) {
         let cal = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
             vec![],
-            vec![],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            ymd(2020, 1, 1),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert_eq!(
-            cal.valid_period(),
-            Range {
-                start: ymd(2021, 1, 1),
-                end: ymd(2021, 1, 10)
-            }
-        );
+        let tested = cal.bizday_of_year(d);
+
+        assert_eq!(tested, Ok(expected));
     }
 
-    #[test]
-    fn test_validate() {
+    #[rstest]
+    #[case(ymd(2021, 4, 28), ymd(2021, 4, 29))]
+    #[case(ymd(2021, 4, 29), ymd(2021, 5, 4))] // consecutive holidays and weekend
+    #[case(ymd(2021, 4, 30), ymd(2021, 5, 4))]
+    #[case(ymd(2021, 5, 1), ymd(2021, 5, 4))]
+    #[case(ymd(2021, 5, 7), ymd(2021, 5, 10))] // Fri -> Mon
+    #[case(ymd(2021, 5, 8), ymd(2021, 5, 10))]
+    #[case(ymd(2021, 6, 4), ymd(2021, 6, 5))] // extra business day on Saturday
+    fn test_next_bizday(#[case] d: NaiveDate, #[case] expected: NaiveDate) {
         let cal = Calendar::_new(
-            vec![],
-            vec![],
+            vec![ymd(2021, 4, 30), ymd(2021, 5, 3)],
+            vec![ymd(2021, 6, 5)],
             ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert!(cal.is_suppoted(ymd(2020, 12, 31)).is_err());
-        assert!(cal.is_suppoted(ymd(2021, 1, 1)).is_ok());
-        assert!(cal.is_suppoted(ymd(2021, 1, 9)).is_ok());
-        assert!(cal.is_suppoted(ymd(2021, 1, 10)).is_err());
-        assert!(cal.is_suppoted(ymd(2021, 1, 11)).is_err());
+        let tested = cal.next_bizday(d);
+
+        assert_eq!(tested, Ok(expected));
     }
 
-    #[test]
-    fn test_is_holiday() {
+    #[rstest]
+    #[case(ymd(2021, 4, 30), ymd(2021, 4, 29))]
+    #[case(ymd(2021, 5, 4), ymd(2021, 4, 29))] // consecutive holidays and weekend
+    #[case(ymd(2021, 5, 3), ymd(2021, 4, 29))]
+    #[case(ymd(2021, 5, 10), ymd(2021, 5, 7))] // Mon -> Fri
+    #[case(ymd(2021, 5, 9), ymd(2021, 5, 7))]
+    #[case(ymd(2021, 6, 6), ymd(2021, 6, 5))] // extra business day on Saturday
+    fn test_prev_bizday(#[case] d: NaiveDate, #[case] expected: NaiveDate) {
         let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
+            vec![ymd(2021, 4, 30), ymd(2021, 5, 3)],
+            vec![ymd(2021, 6, 5)],
             ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert!(cal.is_holiday(ymd(2020, 12, 30)).is_err());
-        assert!(cal.is_holiday(ymd(2020, 12, 31)).is_err());
-        assert!(cal.is_holiday(ymd(2021, 1, 1)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 2)).unwrap());
-        assert!(cal.is_holiday(ymd(2021, 1, 3)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 4)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 5)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 6)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 7)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 8)).unwrap());
-        assert!(cal.is_holiday(ymd(2021, 1, 9)).unwrap());
-        assert!(cal.is_holiday(ymd(2021, 1, 10)).is_err());
+        let tested = cal.prev_bizday(d);
+
+        assert_eq!(tested, Ok(expected));
     }
 
-    #[test]
-    fn test_is_holiday_treat_weekend_as_business_day() {
+    #[rstest]
+    #[case(ymd(2020, 12, 31))]
+    #[case(ymd(2021, 12, 31))] // no business day after in the valid period
+    #[case(ymd(2022, 1, 1))]
+    fn test_next_bizday_err(#[case] d: NaiveDate) {
         let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
             vec![],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
             vec![],
+            ymd(2021, 1, 1),
+            ymd(2022, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert!(cal.is_holiday(ymd(2020, 12, 30)).is_err());
-        assert!(cal.is_holiday(ymd(2020, 12, 31)).is_err());
-        assert!(cal.is_holiday(ymd(2021, 1, 1)).unwrap());
-        assert!(cal.is_holiday(ymd(2021, 1, 2)).unwrap());
-        assert!(!cal.is_holiday(ymd(2021, 1, 3)).unwrap()); // Sunday
-        assert!(cal.is_holiday(ymd(2021, 1, 10)).is_err());
+        let tested = cal.next_bizday(d);
+
+        assert!(matches!(
+            tested,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
     }
 
-    #[test]
-    fn test_is_business_day() {
+    #[rstest]
+    #[case(ymd(2020, 12, 31))]
+    #[case(ymd(2021, 1, 1))] // no business day before in the valid period
+    #[case(ymd(2022, 1, 1))]
+    fn test_prev_bizday_err(#[case] d: NaiveDate) {
         let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
+            vec![],
+            vec![],
             ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert!(!cal.is_bizday(ymd(2021, 1, 1)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 2)).unwrap());
-        assert!(!cal.is_bizday(ymd(2021, 1, 3)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 4)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 5)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 6)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 7)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 8)).unwrap());
-        assert!(!cal.is_bizday(ymd(2021, 1, 9)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 10)).is_err());
+        let tested = cal.prev_bizday(d);
+
+        assert!(matches!(
+            tested,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
     }
 
-    #[test]
-    fn test_is_business_day_treat_weekend_as_business_day() {
+    #[rstest]
+    #[case(HolidayAdj::Following, ymd(2021, 5, 1), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::Preceding, ymd(2021, 5, 1), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::ModifiedPreceding, ymd(2021, 5, 1), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::Following, ymd(2021, 5, 4), ymd(2021, 5, 4))]
+    fn test_roll(#[case] adj: HolidayAdj, #[case] d: NaiveDate, #[case] expected: NaiveDate) {
         let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1), ymd(2021, 1, 2)],
+            vec![ymd(2021, 4, 30), ymd(2021, 5, 3)],
             vec![],
             ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
-            vec![],
+            ymd(2022, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        assert!(!cal.is_bizday(ymd(2021, 1, 1)).unwrap());
-        assert!(!cal.is_bizday(ymd(2021, 1, 2)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 3)).unwrap());
-        assert!(cal.is_bizday(ymd(2021, 1, 10)).is_err());
+        let tested = cal.roll(d, adj);
+
+        assert_eq!(tested, Ok(expected));
     }
 
     #[test]
-    fn test_iter_bizdays() {
+    fn test_year_summaries() {
         let cal = Calendar::_new(
-            vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            vec![ymd(2021, 1, 1), ymd(2021, 5, 3), ymd(2021, 12, 31)],
+            vec![ymd(2021, 6, 5)],
+            ymd(2020, 1, 1),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let mut iter = cal.iter_bizdays(ymd(2021, 1, 1));
+        let holidays = cal.holidays_in_year(2021).unwrap();
+        let bizdays = cal.business_days_in_year(2021).unwrap();
 
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 6)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
-        assert_eq!(iter.next(), None);
+        // 2021 has 104 weekend days
+        assert_eq!(holidays.len(), 104 + 3 - 1);
+        assert_eq!(bizdays, 365 - holidays.len());
+        assert_eq!(
+            bizdays,
+            cal.iter_bizdays_in(ymd(2021, 1, 1)..ymd(2022, 1, 1))
+                .unwrap()
+                .count()
+        );
+        assert!(holidays.windows(2).all(|w| w[0] < w[1]));
+        assert!(holidays.contains(&ymd(2021, 5, 3)));
+        assert!(holidays.contains(&ymd(2021, 12, 31)));
+        assert!(!holidays.contains(&ymd(2021, 6, 5)));
+        assert!(holidays.iter().all(|d| d.year() == 2021));
     }
 
-    #[test]
-    fn test_iter_bizdays_rev() {
+    #[rstest]
+    #[case(2019)]
+    #[case(2022)]
+    #[case(i32::MAX)]
+    fn test_year_summaries_err(#[case] year: i32) {
+        let cal = Calendar::_new(
+            vec![],
+            vec![],
+            ymd(2020, 1, 1),
+            ymd(2021, 12, 31),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        let holidays = cal.holidays_in_year(year);
+        let bizdays = cal.business_days_in_year(year);
+
+        assert!(matches!(
+            holidays,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
+        assert!(matches!(
+            bizdays,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 1, 4))]
+    #[case(ymd(2022, 1, 4))]
+    fn test_bizday_of_year_err(#[case] d: NaiveDate) {
         let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            vec![],
+            ymd(2021, 1, 2),
+            ymd(2022, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let mut iter = cal.iter_bizdays(ymd(2021, 1, 9)).rev();
+        let tested = cal.bizday_of_year(d);
 
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 8)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 7)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 6)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 5)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 4)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 2)));
-        assert_eq!(iter.next(), None);
+        assert!(matches!(
+            tested,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
     }
 
-    #[test]
-    fn test_iter_holidays() {
+    #[rstest]
+    #[case(1, ymd(2021, 1, 4))]
+    #[case(5, ymd(2021, 1, 8))]
+    #[case(6, ymd(2021, 1, 11))]
+    #[case(260, ymd(2021, 12, 31))]
+    fn test_nth_bizday_of_year(#[case] n: usize, #[case] expected: NaiveDate) {
         let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            vec![],
+            ymd(2020, 1, 1),
+            ymd(2023, 1, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let mut iter = cal.iter_holidays(ymd(2021, 1, 1));
+        let tested = cal.nth_bizday_of_year(2021, n);
 
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
-        assert_eq!(iter.next(), None);
+        assert_eq!(tested, Ok(expected));
+        assert_eq!(cal.bizday_of_year(expected), Ok(n));
     }
 
-    #[test]
-    fn test_iter_holidays_rev() {
+    #[rstest]
+    #[case(2021, 0, CalendarError::OrdinalOutOfYear { year: 2021, ordinal: 0 })]
+    #[case(2021, 261, CalendarError::OrdinalOutOfYear { year: 2021, ordinal: 261 })]
+    #[case(2019, 1, CalendarError::OutOfValidPeriod { date: ymd(2019, 1, 1), valid_period: ymd(2020, 1, 1)..ymd(2022, 6, 1) })]
+    #[case(2022, 200, CalendarError::OutOfValidPeriod { date: ymd(2022, 12, 31), valid_period: ymd(2020, 1, 1)..ymd(2022, 6, 1) })]
+    fn test_nth_bizday_of_year_err(
+        #[case] year: i32,
+        #[case] n: usize,
+        #[case] expected: CalendarError,
+    ) {
         let cal = Calendar::_new(
             vec![ymd(2021, 1, 1)],
-            vec![ymd(2021, 1, 2)],
-            ymd(2021, 1, 1),
-            ymd(2021, 1, 10),
+            vec![],
+            ymd(2020, 1, 1),
+            ymd(2022, 6, 1),
             vec![Weekday::Sat, Weekday::Sun],
         )
         .unwrap();
 
-        let mut iter = cal.iter_holidays(ymd(2021, 1, 9)).rev();
+        let tested = cal.nth_bizday_of_year(year, n);
 
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 9)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 3)));
-        assert_eq!(iter.next(), Some(ymd(2021, 1, 1)));
-        assert_eq!(iter.next(), None);
+        assert_eq!(tested, Err(expected));
     }
 
     #[rstest_reuse::template]
@@ -1627,6 +3026,126 @@ mod tests {
         assert_eq!(incl.ok(), incl_exp);
     }
 
+    #[rstest_reuse::apply(calendar_template)]
+    fn test_num_bizdays_to_tenor(
+        cal: Calendar,
+        #[values(
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            ymd(2021, 1, 13),
+            ymd(2021, 1, 31),
+            ymd(2021, 2, 28)
+        )]
+        stt: NaiveDate,
+        #[values(
+            Tenor::Days(0),
+            Tenor::Days(3),
+            Tenor::Days(-3),
+            Tenor::Weeks(2),
+            Tenor::Months(1),
+            Tenor::Months(3),
+            Tenor::Years(1)
+        )]
+        tenor: Tenor,
+    ) {
+        let expected = cal.num_bizdays(stt..tenor.apply(stt));
+
+        let tested = cal.num_bizdays_to_tenor(stt, tenor);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest_reuse::apply(calendar_template)]
+    fn test_accrued_bizdays(
+        cal: Calendar,
+        #[values(
+            NaiveDate::MIN,
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 10),
+            ymd(2021, 1, 13),
+            NaiveDate::MAX
+        )]
+        stt: NaiveDate,
+        #[values(
+            NaiveDate::MIN,
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 13),
+            ymd(2021, 1, 20),
+            NaiveDate::MAX
+        )]
+        end: NaiveDate,
+    ) {
+        let count = cal.num_bizdays(stt..end);
+
+        let unit = cal.accrued_bizdays(stt..end, |_| 1.0);
+        let double = cal.accrued_bizdays(stt..end, |_| 2.0);
+
+        assert_eq!(unit, count.clone().map(|n| n as f64));
+        assert_eq!(double, count.map(|n| 2. * n as f64));
+    }
+
+    #[test]
+    fn test_accrued_bizdays_weight() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 1, 6)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2021, 2, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        // weight is the day of month: 4 + 5 + 7 + 8
+        let tested = cal.accrued_bizdays(ymd(2021, 1, 2)..=ymd(2021, 1, 9), |d| d.day() as f64);
+
+        assert_eq!(tested, Ok(24.));
+    }
+
+    #[test]
+    fn test_num_bizdays_to_tenor_month_end() {
+        let cal = Calendar::_new(
+            vec![ymd(2021, 3, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2022, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+
+        // 2021-02-28 + 1M = 2021-03-31 with month-end stickiness
+        let tested = cal.num_bizdays_to_tenor(ymd(2021, 2, 28), Tenor::Months(1));
+
+        assert_eq!(tested, cal.num_bizdays(ymd(2021, 2, 28)..ymd(2021, 3, 31)));
+        assert_eq!(tested, Ok(21));
+    }
+
+    #[rstest_reuse::apply(calendar_template)]
+    fn test_iter_bizdays_in_consistent_with_num_bizdays(
+        cal: Calendar,
+        #[values(
+            NaiveDate::MIN,
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 5),
+            ymd(2021, 1, 10),
+            NaiveDate::MAX
+        )]
+        stt: NaiveDate,
+        #[values(
+            NaiveDate::MIN,
+            ymd(2021, 1, 1),
+            ymd(2021, 1, 5),
+            ymd(2021, 1, 10),
+            NaiveDate::MAX
+        )]
+        end: NaiveDate,
+    ) {
+        let excl = cal.iter_bizdays_in(stt..end).map(|it| it.count());
+        let incl = cal.iter_bizdays_in(stt..=end).map(|it| it.count());
+
+        assert_eq!(excl, cal.num_bizdays(stt..end));
+        assert_eq!(incl, cal.num_bizdays(stt..=end));
+    }
+
     #[test]
     fn test_bitor() {
         let cal1 = Calendar::_new(
@@ -1674,4 +3193,231 @@ mod tests {
 
         assert_eq!(cal, Calendar::all_closed_of([cal1, cal2]).unwrap());
     }
+
+    #[test]
+    fn test_with_holiday_rules() {
+        let rules = [
+            HolidayRule::Fixed {
+                month: 1,
+                day: 1,
+                observance: Observance::Nearest,
+            },
+            HolidayRule::NthWeekday {
+                month: 1,
+                n: 3,
+                weekday: Weekday::Mon,
+            },
+            HolidayRule::LastWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+            },
+            HolidayRule::Fixed {
+                month: 7,
+                day: 4,
+                observance: Observance::Nearest,
+            },
+            HolidayRule::NthWeekday {
+                month: 11,
+                n: 4,
+                weekday: Weekday::Thu,
+            },
+            HolidayRule::Fixed {
+                month: 12,
+                day: 25,
+                observance: Observance::Nearest,
+            },
+        ];
+        let (from, to) = (ymd(2020, 1, 1), ymd(2027, 1, 1));
+
+        let cal = Calendar::builder()
+            .with_valid_period(from, to)
+            .with_extra_holidays(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .with_holiday_rules(&rules, from, to)
+            .with_extra_business_days(vec![])
+            .build()
+            .unwrap();
+
+        // 6 holidays for each of 7 years
+        assert_eq!(cal.extra_holidays().len(), 42);
+        for d in [
+            ymd(2020, 1, 1),
+            ymd(2020, 1, 20),
+            ymd(2020, 7, 3), // Saturday to Friday
+            ymd(2021, 5, 31),
+            ymd(2021, 7, 5), // Sunday to Monday
+            ymd(2021, 11, 25),
+            ymd(2021, 12, 24), // Saturday to Friday
+            ymd(2021, 12, 31), // New Year's Day of 2022
+            ymd(2022, 12, 26), // Sunday to Monday
+            ymd(2023, 1, 2),   // Sunday to Monday
+            ymd(2026, 5, 25),
+        ] {
+            assert!(cal.is_holiday(d).unwrap(), "{d}");
+        }
+        for d in [ymd(2022, 1, 3), ymd(2021, 7, 2), ymd(2020, 7, 6)] {
+            assert!(cal.is_bizday(d).unwrap(), "{d}");
+        }
+    }
+
+    #[test]
+    fn test_with_holiday_rules_following() {
+        let rules = [
+            HolidayRule::Fixed {
+                month: 12,
+                day: 25,
+                observance: Observance::Following,
+            },
+            HolidayRule::Fixed {
+                month: 12,
+                day: 26,
+                observance: Observance::Following,
+            },
+        ];
+        let (from, to) = (ymd(2020, 1, 1), ymd(2023, 1, 1));
+
+        let cal = Calendar::builder()
+            .with_valid_period(from, to)
+            .with_extra_holidays(vec![ymd(2021, 12, 27)])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .with_holiday_rules(&rules, from, to)
+            .with_extra_business_days(vec![])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            cal.extra_holidays(),
+            &[
+                ymd(2020, 12, 25),
+                ymd(2020, 12, 28), // Saturday to Monday
+                ymd(2021, 12, 27), // given explicitly
+                ymd(2021, 12, 28), // Saturday, but Monday is already a holiday
+                ymd(2021, 12, 29), // Sunday, but Monday and Tuesday are already holidays
+                ymd(2022, 12, 26), // Boxing Day on Monday
+                ymd(2022, 12, 27), // Sunday, but Monday is Boxing Day
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_holiday_rules_out_of_period() {
+        let rules = [HolidayRule::Fixed {
+            month: 1,
+            day: 1,
+            observance: Observance::Nearest,
+        }];
+
+        let cal = Calendar::builder()
+            .with_valid_period(ymd(2020, 1, 1), ymd(2030, 1, 1))
+            .with_extra_holidays(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .with_holiday_rules(&rules, ymd(2021, 6, 1), ymd(2022, 6, 1))
+            .with_extra_business_days(vec![])
+            .build()
+            .unwrap();
+
+        assert_eq!(cal.extra_holidays(), &[ymd(2021, 12, 31)]);
+    }
+
+    #[test]
+    fn test_with_holiday_rules_entire_range() {
+        let rules = [
+            HolidayRule::Fixed {
+                month: 1,
+                day: 1,
+                observance: Observance::Following,
+            },
+            HolidayRule::NthWeekday {
+                month: 1,
+                n: 3,
+                weekday: Weekday::Mon,
+            },
+        ];
+
+        let cal = Calendar::builder()
+            .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+            .with_extra_holidays(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .with_holiday_rules(&rules, NaiveDate::MIN, NaiveDate::MAX)
+            .with_extra_business_days(vec![])
+            .build()
+            .unwrap();
+
+        // New Year's Day of 2022 is Saturday
+        assert!(cal.is_holiday(ymd(2022, 1, 3)).unwrap());
+        assert!(cal.is_holiday(ymd(2022, 1, 17)).unwrap());
+        assert!(cal.is_bizday(ymd(2022, 1, 4)).unwrap());
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 3, 4)..ymd(2021, 7, 1), Ok(true))]
+    #[case(ymd(2021, 3, 1)..ymd(2021, 3, 2), Ok(true))]
+    #[case(ymd(2021, 3, 3)..ymd(2021, 3, 4), Ok(false))]
+    #[case(ymd(2021, 1, 1)..ymd(2021, 3, 3), Ok(true))]
+    #[case(ymd(2021, 1, 1)..ymd(2021, 3, 4), Ok(false))]
+    #[case(ymd(2022, 3, 1)..ymd(2022, 3, 1), Ok(true))]
+    #[case(ymd(2020, 12, 1)..ymd(2021, 1, 10), Err(ymd(2020, 12, 1)))]
+    #[case(ymd(2021, 6, 1)..ymd(2021, 7, 2), Err(ymd(2021, 7, 1)))]
+    fn test_holidays_eq(#[case] over: Range<NaiveDate>, #[case] expected: Result<bool, NaiveDate>) {
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2021, 1, 1),
+            ymd(2022, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+        // different valid period, a holiday out of the overlap and an additional holiday on 2021-03-03
+        let cal2 = Calendar::_new(
+            vec![ymd(2020, 12, 25), ymd(2021, 1, 1), ymd(2021, 3, 3)],
+            vec![],
+            ymd(2020, 7, 1),
+            ymd(2021, 7, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+        let tested = cal1.holidays_eq(&cal2, over.clone());
+        let rev = cal2.holidays_eq(&cal1, over);
+
+        match expected {
+            Ok(expected) => {
+                assert_eq!(tested, Ok(expected));
+                assert_eq!(rev, Ok(expected));
+            }
+            Err(date) => {
+                assert!(
+                    matches!(tested, Err(CalendarError::OutOfValidPeriod { date: d, .. }) if d == date)
+                );
+                assert!(
+                    matches!(rev, Err(CalendarError::OutOfValidPeriod { date: d, .. }) if d == date)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_holidays_eq_different_representation() {
+        let (from, to) = (ymd(2021, 1, 1), ymd(2021, 4, 1));
+        let cal1 = Calendar::_new(
+            vec![ymd(2021, 1, 1)],
+            vec![],
+            ymd(2020, 1, 1),
+            ymd(2022, 1, 1),
+            vec![Weekday::Sat, Weekday::Sun],
+        )
+        .unwrap();
+        let cal2 = Calendar::_new(
+            cal1.iter_holidays(from).take_while(|d| d < &to).collect(),
+            vec![],
+            from,
+            to,
+            vec![],
+        )
+        .unwrap();
+
+        let tested = cal1.holidays_eq(&cal2, from..to);
+
+        assert_ne!(cal1, cal2);
+        assert_eq!(tested, Ok(true));
+    }
 }
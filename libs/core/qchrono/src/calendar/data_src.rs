@@ -12,6 +12,11 @@ pub trait CalendarSrc {
     fn get_calendar_atom(&self, req: &CalendarSymAtom) -> anyhow::Result<Calendar>;
 
     /// Get a calendar data. Not only atomic but also composed of multiple atomic calendars are supported.
+    ///
+    /// Each atomic calendar in `req` is fetched once with [`CalendarSrc::get_calendar_atom`]
+    /// and they are folded with [`Calendar::any_closed_of`] for `|` and [`Calendar::all_closed_of`] for `&`.
+    /// As described in [`CalendarSym`], `&` binds tighter than `|`,
+    /// so `A|B&C` is resolved as `cal_a | (cal_b & cal_c)`.
     fn get_calendar(&self, req: &CalendarSym) -> anyhow::Result<Calendar> {
         let leaves = req
             .leaves()
@@ -138,6 +143,14 @@ mod tests {
         "NYK|TKY&LDN".parse().unwrap(), 3,
         Ok(get_cal(&"NYK".parse().unwrap()).unwrap() | (get_cal(&"TKY".parse().unwrap()).unwrap() & get_cal(&"LDN".parse().unwrap()).unwrap()))
     )]
+    #[case(
+        "(NYK|TKY)&LDN".parse().unwrap(), 3,
+        Ok((get_cal(&"NYK".parse().unwrap()).unwrap() | get_cal(&"TKY".parse().unwrap()).unwrap()) & get_cal(&"LDN".parse().unwrap()).unwrap())
+    )]
+    #[case(
+        "NYK|NYK&TKY".parse().unwrap(), 2,
+        Ok(get_cal(&"NYK".parse().unwrap()).unwrap() | (get_cal(&"NYK".parse().unwrap()).unwrap() & get_cal(&"TKY".parse().unwrap()).unwrap()))
+    )]
     #[case(
         "XXX".parse().unwrap(), 1,
         Err("not found".to_owned())
@@ -2,7 +2,7 @@ use chrono::Datelike;
 
 use crate::timepoint::Date;
 
-use super::Calendar;
+use super::{Calendar, CalendarError};
 
 // -----------------------------------------------------------------------------
 // HolidayAdj
@@ -21,39 +21,285 @@ use super::Calendar;
 )]
 #[serde(rename_all = "snake_case")]
 pub enum HolidayAdj {
+    /// The date is used as is even if it is a holiday.
+    Unadjusted,
+    /// Roll forward to the next business day.
     Following,
+    /// Roll forward to the next business day unless it is in the next month,
+    /// in which case roll backward to the previous business day.
     ModifiedFollowing,
+    /// Roll backward to the previous business day.
     Preceding,
+    /// Roll backward to the previous business day unless it is in the previous month,
+    /// in which case roll forward to the next business day.
     ModifiedPreceding,
+    /// Roll to the business day which is fewer calendar days away.
+    /// When the next and the previous business days are equally distant,
+    /// the next one is taken if `prefer_following` is `true`, otherwise the previous one.
+    Nearest { prefer_following: bool },
 }
 
 impl HolidayAdj {
     /// Adjust the date according to the holiday adjustment rule.
     ///
-    /// This returns [None] if the date is out of supported range.
-    pub fn adjust(&self, d: Date, cal: &Calendar) -> Option<Date> {
-        if cal.is_bizday(d).ok()? {
-            return Some(d);
+    /// # Errors
+    /// * [`CalendarError::OutOfValidPeriod`]: When the date is out of the valid period of the calendar
+    ///   or no business day is found in the rolling direction within the valid period.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use qchrono::calendar::{Calendar, HolidayAdj};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| {
+    ///     NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    /// };
+    ///
+    /// let cal = Calendar::builder()
+    ///     .with_valid_period(ymd(2021, 1, 1), ymd(2021, 12, 31))
+    ///     .with_extra_holidays(vec![])
+    ///     .with_extra_business_days(vec![])
+    ///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let d = ymd(2021, 7, 31); // Saturday
+    /// assert_eq!(HolidayAdj::Unadjusted.adjust(d, &cal), Ok(d));
+    /// assert_eq!(HolidayAdj::Following.adjust(d, &cal), Ok(ymd(2021, 8, 2)));
+    /// assert_eq!(HolidayAdj::ModifiedFollowing.adjust(d, &cal), Ok(ymd(2021, 7, 30)));
+    /// assert_eq!(HolidayAdj::Preceding.adjust(d, &cal), Ok(ymd(2021, 7, 30)));
+    ///
+    /// let d = ymd(2021, 8, 1); // Sunday
+    /// let nearest = HolidayAdj::Nearest { prefer_following: false };
+    /// assert_eq!(nearest.adjust(d, &cal), Ok(ymd(2021, 8, 2)));
+    /// ```
+    pub fn adjust(&self, d: Date, cal: &Calendar) -> Result<Date, CalendarError> {
+        if cal.is_bizday(d)? {
+            return Ok(d);
         }
+        let not_found = || CalendarError::OutOfValidPeriod {
+            date: d,
+            valid_period: cal.valid_period(),
+        };
         match self {
-            HolidayAdj::Following => cal.iter_bizdays(d).next(),
-            HolidayAdj::ModifiedFollowing => {
-                let nxt = cal.iter_bizdays(d).next()?;
-                if nxt.month() == d.month() {
-                    Some(nxt)
-                } else {
-                    HolidayAdj::Preceding.adjust(d, cal)
-                }
-            }
-            HolidayAdj::Preceding => cal.iter_bizdays(d).next_back(),
-            HolidayAdj::ModifiedPreceding => {
-                let prev = cal.iter_bizdays(d).next_back()?;
-                if prev.month() == d.month() {
-                    Some(prev)
-                } else {
-                    HolidayAdj::Following.adjust(d, cal)
+            HolidayAdj::Unadjusted => Ok(d),
+            HolidayAdj::Following => cal.iter_bizdays(d).next().ok_or_else(not_found),
+            HolidayAdj::ModifiedFollowing => match cal.iter_bizdays(d).next() {
+                Some(nxt) if nxt.month() == d.month() => Ok(nxt),
+                _ => HolidayAdj::Preceding.adjust(d, cal),
+            },
+            HolidayAdj::Preceding => cal.iter_bizdays(d).next_back().ok_or_else(not_found),
+            HolidayAdj::ModifiedPreceding => match cal.iter_bizdays(d).next_back() {
+                Some(prev) if prev.month() == d.month() => Ok(prev),
+                _ => HolidayAdj::Following.adjust(d, cal),
+            },
+            HolidayAdj::Nearest { prefer_following } => {
+                let prev = cal.iter_bizdays(d).next_back();
+                let next = cal.iter_bizdays(d).next();
+                match (prev, next) {
+                    (Some(prev), Some(next)) => {
+                        let (to_prev, to_next) = (d - prev, next - d);
+                        if to_next < to_prev || (to_next == to_prev && *prefer_following) {
+                            Ok(next)
+                        } else {
+                            Ok(prev)
+                        }
+                    }
+                    (prev, next) => prev.or(next).ok_or_else(not_found),
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn calendar() -> Calendar {
+        Calendar::builder()
+            .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+            .with_extra_holidays(vec![ymd(2021, 4, 30), ymd(2021, 5, 3), ymd(2021, 12, 31)])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case(HolidayAdj::Unadjusted, "unadjusted")]
+    #[case(HolidayAdj::Following, "following")]
+    #[case(HolidayAdj::ModifiedFollowing, "modified_following")]
+    #[case(HolidayAdj::Preceding, "preceding")]
+    #[case(HolidayAdj::ModifiedPreceding, "modified_preceding")]
+    fn test_serde(#[case] adj: HolidayAdj, #[case] expected: &str) {
+        let json = serde_json::to_value(adj).unwrap();
+        let deserialized: HolidayAdj = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(json, serde_json::json!(expected));
+        assert_eq!(deserialized, adj);
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn test_serde_nearest(#[case] prefer_following: bool) {
+        let adj = HolidayAdj::Nearest { prefer_following };
+
+        let json = serde_json::to_value(adj).unwrap();
+        let deserialized: HolidayAdj = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "nearest": { "prefer_following": prefer_following } })
+        );
+        assert_eq!(deserialized, adj);
+    }
+
+    #[rstest]
+    // business day is not adjusted
+    #[case(HolidayAdj::Unadjusted, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Following, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::ModifiedFollowing, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Preceding, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::ModifiedPreceding, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    // 2021-04-30(Fri) to 2021-05-03(Mon) are holidays
+    #[case(HolidayAdj::Unadjusted, ymd(2021, 4, 30), ymd(2021, 4, 30))]
+    #[case(HolidayAdj::Following, ymd(2021, 4, 30), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::ModifiedFollowing, ymd(2021, 4, 30), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Preceding, ymd(2021, 5, 1), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::ModifiedPreceding, ymd(2021, 5, 1), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::ModifiedFollowing, ymd(2021, 5, 2), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::ModifiedPreceding, ymd(2021, 4, 30), ymd(2021, 4, 29))]
+    // 2021-05-01(Sat) is nearer to 2021-04-29(Thu) and 2021-05-02(Sun) to 2021-05-04(Tue)
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 4, 29), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 4, 30), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 5, 1), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Nearest { prefer_following: false }, ymd(2021, 5, 1), ymd(2021, 4, 29))]
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 5, 3), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::Nearest { prefer_following: false }, ymd(2021, 5, 3), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 5, 2), ymd(2021, 5, 4))]
+    #[case(HolidayAdj::Nearest { prefer_following: false }, ymd(2021, 5, 2), ymd(2021, 5, 4))]
+    // no business day after 2021-12-31 in the valid period
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 12, 31), ymd(2021, 12, 30))]
+    // 2021-07-31(Sat) and 2021-08-01(Sun)
+    #[case(HolidayAdj::Nearest { prefer_following: true }, ymd(2021, 7, 31), ymd(2021, 7, 30))]
+    #[case(HolidayAdj::Nearest { prefer_following: false }, ymd(2021, 8, 1), ymd(2021, 8, 2))]
+    fn test_adjust(#[case] adj: HolidayAdj, #[case] d: NaiveDate, #[case] expected: NaiveDate) {
+        let cal = calendar();
+
+        let tested = adj.adjust(d, &cal);
+
+        assert_eq!(tested, Ok(expected));
+    }
+
+    #[rstest]
+    // 2021-06-16(Wed) is 1 day away from both
+    #[case(true, ymd(2021, 6, 16), ymd(2021, 6, 17))]
+    #[case(false, ymd(2021, 6, 16), ymd(2021, 6, 15))]
+    // 2021-09-19(Sun) is 2 days away from both
+    #[case(true, ymd(2021, 9, 19), ymd(2021, 9, 21))]
+    #[case(false, ymd(2021, 9, 19), ymd(2021, 9, 17))]
+    fn test_adjust_nearest_tie(
+        #[case] prefer_following: bool,
+        #[case] d: NaiveDate,
+        #[case] expected: NaiveDate,
+    ) {
+        let cal = Calendar::builder()
+            .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+            .with_extra_holidays(vec![ymd(2021, 6, 16), ymd(2021, 9, 20)])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap();
+        let adj = HolidayAdj::Nearest { prefer_following };
+
+        let tested = adj.adjust(d, &cal);
+
+        assert_eq!(tested, Ok(expected));
+    }
+
+    #[test]
+    fn test_adjust_nearest_not_found() {
+        let cal = Calendar::builder()
+            .with_valid_period(ymd(2021, 1, 1), ymd(2021, 1, 4))
+            .with_extra_holidays(vec![ymd(2021, 1, 1)])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap();
+        let adj = HolidayAdj::Nearest {
+            prefer_following: true,
+        };
+
+        let tested = adj.adjust(ymd(2021, 1, 2), &cal);
+
+        assert!(matches!(
+            tested,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(HolidayAdj::Following, ymd(2021, 12, 31))]
+    #[case(HolidayAdj::Preceding, ymd(2021, 1, 1))]
+    fn test_adjust_not_found(#[case] adj: HolidayAdj, #[case] d: NaiveDate) {
+        let cal = Calendar::builder()
+            .with_valid_period(ymd(2021, 1, 1), ymd(2022, 1, 1))
+            .with_extra_holidays(vec![ymd(2021, 1, 1), ymd(2021, 12, 31)])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap();
+
+        let tested = adj.adjust(d, &cal);
+
+        assert!(matches!(
+            tested,
+            Err(CalendarError::OutOfValidPeriod { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_adjust_out_of_valid_period(
+        #[values(
+            HolidayAdj::Unadjusted,
+            HolidayAdj::Following,
+            HolidayAdj::ModifiedFollowing,
+            HolidayAdj::Preceding,
+            HolidayAdj::ModifiedPreceding,
+            HolidayAdj::Nearest { prefer_following: true }
+        )]
+        adj: HolidayAdj,
+    ) {
+        let cal = calendar();
+
+        let tested = adj.adjust(ymd(2022, 1, 1), &cal);
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    fn test_modified_keeps_month(
+        #[values(HolidayAdj::ModifiedFollowing, HolidayAdj::ModifiedPreceding)] adj: HolidayAdj,
+    ) {
+        let cal = calendar();
+
+        for d in ymd(2021, 1, 1)
+            .iter_days()
+            .take_while(|d| d < &ymd(2022, 1, 1))
+        {
+            let tested = adj.adjust(d, &cal).unwrap();
+
+            assert_eq!(tested.month(), d.month(), "{adj} changes month of {d}");
+            assert!(cal.is_bizday(tested).unwrap());
+        }
+    }
+}
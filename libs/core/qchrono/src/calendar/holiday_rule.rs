@@ -0,0 +1,252 @@
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::timepoint::DateExtensions;
+
+// -----------------------------------------------------------------------------
+// Observance
+// -----------------------------------------------------------------------------
+/// How a fixed-date holiday is observed when it falls on a holiday weekday, e.g. a weekend.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Observance {
+    /// The holiday is not shifted, hence it is absorbed into the holiday weekday.
+    #[default]
+    Unshifted,
+    /// The holiday is observed on the next available day, e.g. Saturday and Sunday to Monday.
+    Following,
+    /// The holiday is observed on the nearest available day, e.g. Saturday to Friday and Sunday to Monday.
+    /// If the previous and the next available days are equally distant, the next one is taken.
+    Nearest,
+}
+
+// -----------------------------------------------------------------------------
+// HolidayRule
+// -----------------------------------------------------------------------------
+/// Rule of a holiday which recurs every year.
+///
+/// Rules are expanded into extra holidays by [`super::CalendarBuilder::with_holiday_rules`].
+///
+/// # Example
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use qchrono::calendar::{HolidayRule, Observance};
+///
+/// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// let new_year = HolidayRule::Fixed { month: 1, day: 1, observance: Observance::Nearest };
+/// let mlk = HolidayRule::NthWeekday { month: 1, n: 3, weekday: Weekday::Mon };
+/// let memorial = HolidayRule::LastWeekday { month: 5, weekday: Weekday::Mon };
+///
+/// assert_eq!(new_year.date_in(2022), Some(ymd(2022, 1, 1)));
+/// assert_eq!(mlk.date_in(2022), Some(ymd(2022, 1, 17)));
+/// assert_eq!(memorial.date_in(2022), Some(ymd(2022, 5, 30)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HolidayRule {
+    /// The same day of every year, e.g. January 1st.
+    Fixed {
+        month: u32,
+        day: u32,
+        #[serde(default)]
+        observance: Observance,
+    },
+    /// The `n`-th (1-based) weekday of the month, e.g. the 3rd Monday of January.
+    NthWeekday { month: u32, n: u8, weekday: Weekday },
+    /// The last weekday of the month, e.g. the last Monday of May.
+    LastWeekday { month: u32, weekday: Weekday },
+}
+
+//
+// methods
+//
+impl HolidayRule {
+    /// Date of the holiday in the given year before shifting by [`Observance`].
+    ///
+    /// `None` is returned when the date does not exist in the year, e.g. February 29th in non-leap years.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match self {
+            HolidayRule::Fixed { month, day, .. } => NaiveDate::from_ymd_opt(year, *month, *day),
+            HolidayRule::NthWeekday { month, n, weekday } => {
+                NaiveDate::from_ymd_opt(year, *month, 1)?.nth_weekday(*n, *weekday)
+            }
+            HolidayRule::LastWeekday { month, weekday } => {
+                let eom = NaiveDate::from_ymd_opt(year, *month, 1)?.end_of_month();
+                let back =
+                    (7 + eom.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+                eom.checked_sub_days(Days::new(back as u64))
+            }
+        }
+    }
+
+    /// Date on which the holiday is observed in the given year.
+    ///
+    /// A day is not available for observance if it is a holiday weekday or already `taken`.
+    pub(super) fn _observed_in(
+        &self,
+        year: i32,
+        holiday_weekdays: &[Weekday],
+        taken: &BTreeSet<NaiveDate>,
+    ) -> Option<NaiveDate> {
+        let date = self.date_in(year)?;
+        let observance = match self {
+            HolidayRule::Fixed { observance, .. } => *observance,
+            _ => Observance::Unshifted,
+        };
+        let is_available =
+            |d: &NaiveDate| !holiday_weekdays.contains(&d.weekday()) && !taken.contains(d);
+        if observance == Observance::Unshifted || !holiday_weekdays.contains(&date.weekday()) {
+            return Some(date);
+        }
+        // a week and a few more days are enough to skip holiday weekdays and taken days
+        for n in 1..=14 {
+            let next = date.checked_add_days(Days::new(n));
+            let prev = date.checked_sub_days(Days::new(n));
+            let candidates = match observance {
+                Observance::Following => [next, None],
+                _ => [next, prev],
+            };
+            if let Some(d) = candidates.into_iter().flatten().find(is_available) {
+                return Some(d);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    const WEEKENDS: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+    #[rstest]
+    #[case(HolidayRule::Fixed { month: 7, day: 4, observance: Observance::Unshifted }, 2021, Some(ymd(2021, 7, 4)))]
+    #[case(HolidayRule::Fixed { month: 2, day: 29, observance: Observance::Unshifted }, 2024, Some(ymd(2024, 2, 29)))]
+    #[case(HolidayRule::Fixed { month: 2, day: 29, observance: Observance::Unshifted }, 2023, None)]
+    #[case(HolidayRule::Fixed { month: 13, day: 1, observance: Observance::Unshifted }, 2023, None)]
+    #[case(HolidayRule::NthWeekday { month: 1, n: 3, weekday: Weekday::Mon }, 2021, Some(ymd(2021, 1, 18)))]
+    #[case(HolidayRule::NthWeekday { month: 11, n: 4, weekday: Weekday::Thu }, 2021, Some(ymd(2021, 11, 25)))]
+    #[case(HolidayRule::NthWeekday { month: 2, n: 5, weekday: Weekday::Mon }, 2021, None)]
+    #[case(HolidayRule::LastWeekday { month: 5, weekday: Weekday::Mon }, 2021, Some(ymd(2021, 5, 31)))]
+    #[case(HolidayRule::LastWeekday { month: 5, weekday: Weekday::Mon }, 2022, Some(ymd(2022, 5, 30)))]
+    #[case(HolidayRule::LastWeekday { month: 2, weekday: Weekday::Thu }, 2024, Some(ymd(2024, 2, 29)))]
+    fn test_date_in(
+        #[case] rule: HolidayRule,
+        #[case] year: i32,
+        #[case] expected: Option<NaiveDate>,
+    ) {
+        let tested = rule.date_in(year);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    // 2021-07-04 is Sunday and 2020-07-04 is Saturday
+    #[case(Observance::Unshifted, 2021, ymd(2021, 7, 4))]
+    #[case(Observance::Following, 2021, ymd(2021, 7, 5))]
+    #[case(Observance::Nearest, 2021, ymd(2021, 7, 5))]
+    #[case(Observance::Unshifted, 2020, ymd(2020, 7, 4))]
+    #[case(Observance::Following, 2020, ymd(2020, 7, 6))]
+    #[case(Observance::Nearest, 2020, ymd(2020, 7, 3))]
+    #[case(Observance::Nearest, 2022, ymd(2022, 7, 4))]
+    fn test_observed_in(
+        #[case] observance: Observance,
+        #[case] year: i32,
+        #[case] expected: NaiveDate,
+    ) {
+        let rule = HolidayRule::Fixed {
+            month: 7,
+            day: 4,
+            observance,
+        };
+
+        let tested = rule._observed_in(year, &WEEKENDS, &BTreeSet::new());
+
+        assert_eq!(tested, Some(expected));
+    }
+
+    #[test]
+    fn test_observed_in_skips_taken() {
+        // 2021-12-25 is Saturday and 2021-12-26 is Sunday
+        let christmas = HolidayRule::Fixed {
+            month: 12,
+            day: 25,
+            observance: Observance::Following,
+        };
+        let boxing_day = HolidayRule::Fixed {
+            month: 12,
+            day: 26,
+            observance: Observance::Following,
+        };
+
+        let first = christmas
+            ._observed_in(2021, &WEEKENDS, &BTreeSet::new())
+            .unwrap();
+        let second = boxing_day
+            ._observed_in(2021, &WEEKENDS, &BTreeSet::from([first]))
+            .unwrap();
+
+        assert_eq!(first, ymd(2021, 12, 27));
+        assert_eq!(second, ymd(2021, 12, 28));
+    }
+
+    #[test]
+    fn test_serde() {
+        let rules = vec![
+            HolidayRule::Fixed {
+                month: 1,
+                day: 1,
+                observance: Observance::Nearest,
+            },
+            HolidayRule::NthWeekday {
+                month: 1,
+                n: 3,
+                weekday: Weekday::Mon,
+            },
+            HolidayRule::LastWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+            },
+        ];
+
+        let json = serde_json::to_value(&rules).unwrap();
+        let deserialized: Vec<HolidayRule> = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "type": "fixed", "month": 1, "day": 1, "observance": "nearest" },
+                { "type": "nth_weekday", "month": 1, "n": 3, "weekday": "Mon" },
+                { "type": "last_weekday", "month": 5, "weekday": "Mon" },
+            ])
+        );
+        assert_eq!(deserialized, rules);
+    }
+
+    #[test]
+    fn test_deserialize_default_observance() {
+        let json = serde_json::json!({ "type": "fixed", "month": 1, "day": 1 });
+
+        let tested: HolidayRule = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            tested,
+            HolidayRule::Fixed {
+                month: 1,
+                day: 1,
+                observance: Observance::Unshifted
+            }
+        );
+    }
+}
@@ -1,3 +1,9 @@
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail};
 use qmath::ext::num::Zero;
 
 // -----------------------------------------------------------------------------
@@ -81,6 +87,241 @@ impl Duration {
     }
 }
 
+//
+// ser/de
+//
+impl Duration {
+    /// Format the duration as an ISO-8601 duration string.
+    ///
+    /// Days are the largest unit because months and years are not fixed lengths of time.
+    /// Negative durations are prefixed with `-` and sub-second parts are written
+    /// with the minimum number of fractional digits.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::duration::Duration;
+    ///
+    /// let d = Duration::with_hours(1) + Duration::with_mins(30);
+    /// assert_eq!(d.to_iso8601(), "PT1H30M");
+    ///
+    /// let d = -(Duration::with_days(1) + Duration::with_millisecs(500));
+    /// assert_eq!(d.to_iso8601(), "-P1DT0.5S");
+    ///
+    /// let d = Duration::with_secs(0);
+    /// assert_eq!(d.to_iso8601(), "PT0S");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let sign = if self.inner < chrono::Duration::zero() {
+            "-"
+        } else {
+            ""
+        };
+        let abs = self.inner.abs();
+        let (secs, nanos) = (abs.num_seconds(), abs.subsec_nanos());
+        let (days, rem) = (secs / 86_400, secs % 86_400);
+        let (h, m, s) = (rem / 3_600, rem % 3_600 / 60, rem % 60);
+
+        let mut res = format!("{sign}P");
+        if days != 0 {
+            let _ = write!(res, "{days}D");
+        }
+        if days == 0 || rem != 0 || nanos != 0 {
+            res.push('T');
+            if h != 0 {
+                let _ = write!(res, "{h}H");
+            }
+            if m != 0 {
+                let _ = write!(res, "{m}M");
+            }
+            if nanos != 0 {
+                let frac = format!("{nanos:09}");
+                let _ = write!(res, "{s}.{}S", frac.trim_end_matches('0'));
+            } else if s != 0 || (h == 0 && m == 0) {
+                let _ = write!(res, "{s}S");
+            }
+        }
+        res
+    }
+}
+
+/// Parse an ISO-8601 duration string.
+///
+/// The accepted format is `[-+]P[n]W[n]DT[n]H[n]M[n]S` where each component is optional
+/// but at least one of them must exist. Only seconds can have a fractional part, up to nanoseconds.
+/// Years and months are not supported because they are not fixed lengths of time.
+impl FromStr for Duration {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const DATE_UNITS: [(char, i64); 2] = [('W', 604_800), ('D', 86_400)];
+        const TIME_UNITS: [(char, i64); 3] = [('H', 3_600), ('M', 60), ('S', 1)];
+        let org = s;
+        let (neg, s) = match s.chars().next() {
+            Some('-') => (true, &s[1..]),
+            Some('+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let Some(s) = s.strip_prefix('P') else {
+            bail!("invalid duration string: '{org}'. Expected format is P[n]W[n]DT[n]H[n]M[n]S");
+        };
+        let (date, time) = match s.split_once('T') {
+            Some((_, "")) => {
+                bail!("invalid duration string: '{org}'. Time part is empty after 'T'")
+            }
+            Some((date, time)) => (date, time),
+            None => (s, ""),
+        };
+        if date.is_empty() && time.is_empty() {
+            bail!("invalid duration string: '{org}'. Expected format is P[n]W[n]DT[n]H[n]M[n]S");
+        }
+
+        let overflow = || anyhow!("invalid duration string: '{org}'. Duration is too large");
+        let mut secs: i64 = 0;
+        let mut nanos: i64 = 0;
+        for (part, units) in [(date, &DATE_UNITS[..]), (time, &TIME_UNITS[..])] {
+            let mut last_unit = None;
+            let mut rest = part;
+            while !rest.is_empty() {
+                let n_len = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(rest.len());
+                let (n, tail) = rest.split_at(n_len);
+                let Some(unit) = tail.chars().next() else {
+                    bail!(
+                        "invalid duration string: '{org}'. Unit is missing after the number '{n}'"
+                    );
+                };
+                let Some(pos) = units.iter().position(|(u, _)| u == &unit) else {
+                    bail!("invalid duration string: '{org}'. Unknown unit '{unit}'. Expected either of W, D before 'T' and H, M, S after 'T'");
+                };
+                if n.is_empty() {
+                    bail!("invalid duration string: '{org}'. Number is missing before the unit '{unit}'");
+                }
+                if last_unit.is_some_and(|last| pos <= last) {
+                    bail!("invalid duration string: '{org}'. Units must appear once in the order of W, D, H, M, S");
+                }
+                let (int, frac) = match n.split_once('.') {
+                    Some((int, frac)) => (int, Some(frac)),
+                    None => (n, None),
+                };
+                let int = int.parse::<i64>().map_err(|_| {
+                    anyhow!("invalid duration string: '{org}'. Fail to parse the number part '{n}'")
+                })?;
+                if let Some(frac) = frac {
+                    if unit != 'S' {
+                        bail!("invalid duration string: '{org}'. Only seconds can have a fractional part");
+                    }
+                    if frac.is_empty()
+                        || 9 < frac.len()
+                        || !frac.chars().all(|c| c.is_ascii_digit())
+                    {
+                        bail!("invalid duration string: '{org}'. Fractional seconds must have 1 to 9 digits");
+                    }
+                    nanos = format!("{frac:0<9}").parse()?;
+                }
+                secs = int
+                    .checked_mul(units[pos].1)
+                    .and_then(|v| secs.checked_add(v))
+                    .ok_or_else(overflow)?;
+                last_unit = Some(pos);
+                rest = &tail[unit.len_utf8()..];
+            }
+        }
+
+        let res = chrono::Duration::try_seconds(secs)
+            .and_then(|d| d.checked_add(&chrono::Duration::nanoseconds(nanos)))
+            .ok_or_else(overflow)?;
+        Ok(if neg { -res } else { res }.into())
+    }
+}
+
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&self.to_iso8601())
+    }
+}
+
+/// Deserialize from an ISO-8601 duration string or a number of seconds.
+///
+/// Fractional seconds given as a number are rounded to nanoseconds.
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Duration;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("ISO-8601 duration string or number of seconds")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Duration, E> {
+                Duration::from_str(v).map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Duration, E> {
+                Duration::try_with_secs(v)
+                    .ok_or_else(|| E::custom(format!("duration is too large: {v} seconds")))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Duration, E> {
+                i64::try_from(v)
+                    .ok()
+                    .and_then(Duration::try_with_secs)
+                    .ok_or_else(|| E::custom(format!("duration is too large: {v} seconds")))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Duration, E> {
+                let err = || E::custom(format!("duration is too large: {v} seconds"));
+                if !v.is_finite() {
+                    return Err(err());
+                }
+                let secs = v.trunc();
+                if secs < i64::MIN as f64 || i64::MAX as f64 <= secs {
+                    return Err(err());
+                }
+                let nanos = ((v - secs) * 1e9).round() as i64;
+                Duration::try_with_secs(secs as i64)
+                    .map(|d| d + Duration::with_nanosecs(nanos))
+                    .ok_or_else(err)
+            }
+        }
+
+        d.deserialize_any(Visitor)
+    }
+}
+
+impl schemars::JsonSchema for Duration {
+    fn schema_name() -> String {
+        "Duration".to_string()
+    }
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        "qchrono::Duration".into()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut iso = schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            ..Default::default()
+        };
+        iso.string().pattern = Some(
+            r"^[-+]?P(?!$)(\d+W)?(\d+D)?(T(?=\d)(\d+H)?(\d+M)?(\d+(\.\d{1,9})?S)?)?$".to_string(),
+        );
+        let secs = schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Number.into()),
+            ..Default::default()
+        };
+
+        let mut obj = schemars::schema::SchemaObject::default();
+        obj.metadata().description = Some(
+            "Duration. ISO-8601 duration string with days as the largest unit (e.g. PT1H30M, -P1DT0.5S) or number of seconds"
+                .to_string(),
+        );
+        obj.subschemas().any_of = Some(vec![iso.into(), secs.into()]);
+        obj.into()
+    }
+}
+
 //
 // methods
 //
@@ -109,6 +350,88 @@ impl Duration {
         let nano = self.inner.subsec_nanos() as f64 / 1_000_000_000.0;
         sec + nano
     }
+
+    /// Get the total number of seconds including the sub-second part.
+    ///
+    /// This is an alias of [`Duration::approx_secs`],
+    /// so the same floating point limitation applies to huge durations.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::duration::Duration;
+    ///
+    /// let d = Duration::with_mins(1) + Duration::with_millisecs(250);
+    /// assert_eq!(d.as_seconds_f64(), 60.25);
+    /// ```
+    #[inline]
+    pub fn as_seconds_f64(&self) -> f64 {
+        self.approx_secs()
+    }
+
+    /// Get the number of whole days, truncated toward zero.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::duration::Duration;
+    ///
+    /// assert_eq!(Duration::with_hours(49).as_days(), 2);
+    /// assert_eq!(Duration::with_hours(-49).as_days(), -2);
+    /// ```
+    #[inline]
+    pub fn as_days(&self) -> i64 {
+        self.inner.num_days()
+    }
+
+    /// Get the number of whole hours, truncated toward zero.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::duration::Duration;
+    ///
+    /// assert_eq!(Duration::with_mins(150).as_hours(), 2);
+    /// assert_eq!(Duration::with_mins(-150).as_hours(), -2);
+    /// ```
+    #[inline]
+    pub fn as_hours(&self) -> i64 {
+        self.inner.num_hours()
+    }
+}
+
+/// Compact human readable form like `1d 2h 3m`.
+///
+/// Zero components are omitted and sub-second parts are written as fractional seconds, e.g. `1m 0.5s`.
+/// Negative durations are prefixed with `-`, e.g. `-1d 2h`, and zero is written as `0s`.
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.inner.is_zero() {
+            return f.write_str("0s");
+        }
+        if self.inner < chrono::Duration::zero() {
+            f.write_char('-')?;
+        }
+        let abs = self.inner.abs();
+        let (secs, nanos) = (abs.num_seconds(), abs.subsec_nanos());
+        let comps = [
+            (secs / 86_400, "d"),
+            (secs % 86_400 / 3_600, "h"),
+            (secs % 3_600 / 60, "m"),
+        ];
+        let mut sep = "";
+        for (n, unit) in comps {
+            if n != 0 {
+                write!(f, "{sep}{n}{unit}")?;
+                sep = " ";
+            }
+        }
+        let s = secs % 60;
+        if nanos != 0 {
+            let frac = format!("{nanos:09}");
+            write!(f, "{sep}{s}.{}s", frac.trim_end_matches('0'))?;
+        } else if s != 0 {
+            write!(f, "{sep}{s}s")?;
+        }
+        Ok(())
+    }
 }
 
 //
@@ -184,16 +507,77 @@ impl qmath::num::RelPos for Duration {
             Some(num / den)
         }
     }
+
+    /// The result is rounded to nanoseconds.
+    #[inline]
+    fn at_pos(left: &Self, right: &Self, pos: &f64) -> Self {
+        let span = (*right - *left).approx_secs();
+        *left + Duration::with_nanosecs((span * pos * 1e9).round() as i64)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qmath::num::RelPos;
     use rstest::rstest;
 
     #[test]
     fn test() {}
 
+    #[rstest]
+    #[case(Duration::zero(), "PT0S")]
+    #[case(Duration::with_secs(1), "PT1S")]
+    #[case(Duration::with_hours(1) + Duration::with_mins(30), "PT1H30M")]
+    #[case(Duration::with_days(2) + Duration::with_secs(3), "P2DT3S")]
+    #[case(Duration::with_days(7), "P7D")]
+    #[case(Duration::with_millisecs(1_500), "PT1.5S")]
+    #[case(Duration::with_nanosecs(1), "PT0.000000001S")]
+    #[case(Duration::with_secs(-1), "-PT1S")]
+    #[case(-(Duration::with_hours(25) + Duration::with_millisecs(250)), "-P1DT1H0.25S")]
+    #[case(Duration::with_nanosecs(-1), "-PT0.000000001S")]
+    fn test_serde_roundtrip(#[case] dur: Duration, #[case] expected: &str) {
+        let serialized = serde_json::to_string(&dur).unwrap();
+        let deserialized: Duration = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(serialized, format!("\"{expected}\""));
+        assert_eq!(deserialized, dur);
+    }
+
+    #[rstest]
+    #[case("\"P1W2D\"", Duration::with_days(9))]
+    #[case("\"+PT90M\"", Duration::with_mins(90))]
+    #[case("\"PT0.5S\"", Duration::with_millisecs(500))]
+    #[case("\"-P1DT12H\"", Duration::with_hours(-36))]
+    #[case("3600", Duration::with_hours(1))]
+    #[case("-30", Duration::with_secs(-30))]
+    #[case("1.25", Duration::with_millisecs(1_250))]
+    #[case("-0.000001", Duration::with_microsecs(-1))]
+    fn test_deserialize(#[case] json: &str, #[case] expected: Duration) {
+        let tested: Duration = serde_json::from_str(json).unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("P")]
+    #[case("PT")]
+    #[case("1H")]
+    #[case("P1M")]
+    #[case("PT1D")]
+    #[case("PT1M1H")]
+    #[case("PT1.5M")]
+    #[case("PT1.S")]
+    #[case("PT0.1234567891S")]
+    #[case("PTH")]
+    #[case("PT1")]
+    fn test_from_str_err(#[case] s: &str) {
+        let tested = Duration::from_str(s);
+
+        assert!(tested.is_err());
+    }
+
     #[rstest]
     #[case(Duration::zero(), 0.)]
     #[case(Duration::with_secs(1), 1.)]
@@ -209,4 +593,50 @@ mod tests {
 
         assert_eq!(tested, expected);
     }
+
+    #[test]
+    fn test_decomposition() {
+        let dur = Duration::with_days(1)
+            + Duration::with_hours(2)
+            + Duration::with_mins(3)
+            + Duration::with_millisecs(4_500);
+
+        assert_eq!(dur.as_days(), 1);
+        assert_eq!(dur.as_hours(), 26);
+        assert_eq!(dur.as_seconds_f64(), 93_784.5);
+        assert_eq!((-dur).as_days(), -1);
+        assert_eq!((-dur).as_hours(), -26);
+        assert_eq!((-dur).as_seconds_f64(), -93_784.5);
+    }
+
+    #[rstest]
+    #[case(Duration::zero(), "0s")]
+    #[case(Duration::with_days(1) + Duration::with_hours(2) + Duration::with_mins(3), "1d 2h 3m")]
+    #[case(Duration::with_days(1) + Duration::with_mins(3), "1d 3m")]
+    #[case(Duration::with_mins(1) + Duration::with_millisecs(500), "1m 0.5s")]
+    #[case(Duration::with_hours(5) + Duration::with_secs(7), "5h 7s")]
+    #[case(Duration::with_nanosecs(1), "0.000000001s")]
+    #[case(-(Duration::with_days(1) + Duration::with_hours(2)), "-1d 2h")]
+    #[case(Duration::with_secs(-90), "-1m 30s")]
+    fn test_display(#[case] dur: Duration, #[case] expected: &str) {
+        let tested = dur.to_string();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(0.0, Duration::with_hours(1))]
+    #[case(0.5, Duration::with_hours(2))]
+    #[case(1.0, Duration::with_hours(3))]
+    #[case(1.5, Duration::with_hours(4))]
+    #[case(-0.5, Duration::zero())]
+    fn test_at_pos(#[case] pos: f64, #[case] expected: Duration) {
+        let left = Duration::with_hours(1);
+        let right = Duration::with_hours(3);
+
+        let tested = Duration::at_pos(&left, &right, &pos);
+
+        assert_eq!(tested, expected);
+        assert_eq!(tested.relpos_between(&left, &right), Some(pos));
+    }
 }
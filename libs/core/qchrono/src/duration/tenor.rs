@@ -5,7 +5,9 @@ use std::{
 };
 
 use anyhow::bail;
-use chrono::{Days, Months, NaiveDate};
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+use crate::timepoint::DateExtensions;
 
 // -----------------------------------------------------------------------------
 // Tenor
@@ -33,39 +35,81 @@ impl Display for Tenor {
     }
 }
 
+/// Parse a tenor string.
+///
+/// Both of ISO-8601 period syntax, e.g. `P3M`, and the short form without `P`, e.g. `3M`, are accepted
+/// with an optional sign. Components must appear in the order of `Y`, `M`, `W` and `D`.
+///
+/// Composite periods are supported as long as they can be represented by a single unit:
+/// - `Y` and `M` are combined into months, e.g. `P1Y6M` is parsed as `Tenor::Months(18)`
+/// - `W` and `D` are combined into days, e.g. `P1W2D` is parsed as `Tenor::Days(9)`
+///
+/// Mixing month-based and day-based components, e.g. `P1M2D`, is not supported.
 impl FromStr for Tenor {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const UNITS: [char; 4] = ['Y', 'M', 'W', 'D'];
+        let org = s;
         let (sign, s) = match s.chars().next() {
             Some('-') => (-1, &s[1..]),
             Some('+') => (1, &s[1..]),
             _ => (1, s),
         };
+        let s = s.strip_prefix('P').unwrap_or(s);
+        if s.is_empty() {
+            bail!("invalid tenor string: '{org}'. Expected format is P[n]Y[n]M[n]W[n]D or its short form like 3M");
+        }
 
-        let Some(s) = s.strip_prefix('P') else {
-            return Err(anyhow::anyhow!(
-                "invalid tenor string: {}. Expected format is either of P[n]D, P[n]W, P[n]M, P[n]Y",
-                s
-            ));
-        };
-        let n = match s.chars().last() {
-            Some(c) if ['D', 'W', 'M', 'Y'].contains(&c) => &s[..s.len() - 1],
-            _ => {
-                bail!(
-                    "invalid tenor string: {s}. Expected format is either of P[n]D, P[n]W, P[n]M, P[n]Y"
+        let mut comps: [Option<i16>; 4] = [None; 4];
+        let mut last_unit = None;
+        let mut rest = s;
+        while !rest.is_empty() {
+            let n_len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (n, tail) = rest.split_at(n_len);
+            let Some(unit) = tail.chars().next() else {
+                bail!("invalid tenor string: '{org}'. Unit is missing after the number '{n}'");
+            };
+            let Some(pos) = UNITS.iter().position(|u| u == &unit) else {
+                bail!("invalid tenor string: '{org}'. Unknown unit '{unit}'. Expected either of Y, M, W, D");
+            };
+            if n.is_empty() {
+                bail!("invalid tenor string: '{org}'. Number is missing before the unit '{unit}'");
+            }
+            if last_unit.is_some_and(|last| pos <= last) {
+                bail!("invalid tenor string: '{org}'. Units must appear once in the order of Y, M, W, D");
+            }
+            let n = n.parse::<i16>().map_err(|_| {
+                anyhow::anyhow!(
+                    "invalid tenor string: '{org}'. Fail to parse the number part '{n}'"
                 )
+            })?;
+            comps[pos] = Some(n);
+            last_unit = Some(pos);
+            rest = &tail[unit.len_utf8()..];
+        }
+
+        let overflow = || anyhow::anyhow!("invalid tenor string: '{org}'. Tenor is too large");
+        match comps {
+            [Some(y), None, None, None] => Ok(Tenor::Years(sign * y)),
+            [None, Some(m), None, None] => Ok(Tenor::Months(sign * m)),
+            [None, None, Some(w), None] => Ok(Tenor::Weeks(sign * w)),
+            [None, None, None, Some(d)] => Ok(Tenor::Days(sign * d)),
+            [y, m, None, None] => {
+                let m = (y.unwrap_or(0) as i32) * 12 + m.unwrap_or(0) as i32;
+                let m = i16::try_from(m).map_err(|_| overflow())?;
+                Ok(Tenor::Months(sign * m))
             }
-        };
-        let n = n.parse::<i16>().map_err(|_| {
-            anyhow::anyhow!("invalid tenor string: {s}. Fail to parse the number part '{n}'")
-        })?;
-        match s.chars().last() {
-            Some('D') => Ok(Tenor::Days(sign * n)),
-            Some('W') => Ok(Tenor::Weeks(sign * n)),
-            Some('M') => Ok(Tenor::Months(sign * n)),
-            Some('Y') => Ok(Tenor::Years(sign * n)),
-            _ => unreachable!(),
+            [None, None, w, d] => {
+                let d = (w.unwrap_or(0) as i32) * 7 + d.unwrap_or(0) as i32;
+                let d = i16::try_from(d).map_err(|_| overflow())?;
+                Ok(Tenor::Days(sign * d))
+            }
+            _ => bail!(
+                "invalid tenor string: '{org}'. Mixing year/month and week/day components is not supported"
+            ),
         }
     }
 }
@@ -96,12 +140,146 @@ impl schemars::JsonSchema for Tenor {
             instance_type: Some(schemars::schema::InstanceType::String.into()),
             ..Default::default()
         };
-        obj.metadata().description = Some("Tenor string. e.g. P1D, P1W, P1M, P1Y".to_string());
-        obj.string().pattern = Some(r#"^[-+]?P\d+[DWMY]$"#.to_string());
+        obj.metadata().description = Some(
+            "Tenor string. ISO-8601 period (e.g. P1D, P2W, P3M, P1Y, P1Y6M) or its short form without 'P' (e.g. 3M, 1Y)"
+                .to_string(),
+        );
+        obj.string().pattern = Some(r"^[-+]?P?(?=\d)(\d+Y)?(\d+M)?(\d+W)?(\d+D)?$".to_string());
         obj.into()
     }
 }
 
+//
+// methods
+//
+impl Tenor {
+    /// Apply the tenor to the given date with month-end stickiness.
+    ///
+    /// Days and weeks are simply added.
+    /// For months and years, the result follows the month-end rule:
+    /// - if the given date is the last day of its month, the result is the last day of the target month.
+    /// - otherwise, the day of month is kept and clamped to the last day of the target month if it does not exist.
+    ///
+    /// Hence, `1M` from Jan 31 lands on Feb 28 (or Feb 29 in leap years)
+    /// and `1M` from Feb 28, 2019 lands on Mar 31.
+    /// This differs from `date + tenor`, which does not stick to month ends.
+    ///
+    /// Similar to `date + tenor`, the result saturates at [`NaiveDate::MIN`] and [`NaiveDate::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use qchrono::duration::Tenor;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(Tenor::Months(1).apply(ymd(2021, 1, 31)), ymd(2021, 2, 28));
+    /// assert_eq!(Tenor::Months(1).apply(ymd(2021, 2, 28)), ymd(2021, 3, 31));
+    /// assert_eq!(Tenor::Months(1).apply(ymd(2021, 2, 27)), ymd(2021, 3, 27));
+    /// ```
+    pub fn apply(&self, date: NaiveDate) -> NaiveDate {
+        let res = date + *self;
+        match self {
+            Tenor::Days(_) | Tenor::Weeks(_) => res,
+            Tenor::Months(_) | Tenor::Years(_) => {
+                if date.is_end_of_month() && res != NaiveDate::MIN && res != NaiveDate::MAX {
+                    res.end_of_month()
+                } else {
+                    res
+                }
+            }
+        }
+    }
+
+    /// Whether the tenor goes backward, i.e. its count is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::duration::Tenor;
+    ///
+    /// assert!(Tenor::Months(-3).is_negative());
+    /// assert!(!Tenor::Days(0).is_negative());
+    /// assert!((-Tenor::Years(1)).is_negative());
+    /// ```
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Tenor::Days(n) | Tenor::Weeks(n) | Tenor::Months(n) | Tenor::Years(n) => *n < 0,
+        }
+    }
+
+    /// Approximate number of days used only for ordering tenors.
+    #[inline]
+    fn _approx_days(&self) -> i32 {
+        match self {
+            Tenor::Days(n) => *n as i32,
+            Tenor::Weeks(n) => *n as i32 * 7,
+            Tenor::Months(n) => *n as i32 * 30,
+            Tenor::Years(n) => *n as i32 * 365,
+        }
+    }
+
+    #[inline]
+    fn _unit_rank(&self) -> u8 {
+        match self {
+            Tenor::Days(_) => 0,
+            Tenor::Weeks(_) => 1,
+            Tenor::Months(_) => 2,
+            Tenor::Years(_) => 3,
+        }
+    }
+
+    /// The next IMM date strictly after the given date.
+    ///
+    /// IMM dates are the third Wednesdays of March, June, September and December.
+    /// `None` is returned if the IMM date is out of the range of [`NaiveDate`].
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use qchrono::duration::Tenor;
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(Tenor::next_imm(ymd(2021, 1, 1)), Some(ymd(2021, 3, 17)));
+    /// assert_eq!(Tenor::next_imm(ymd(2021, 3, 17)), Some(ymd(2021, 6, 16)));
+    /// assert_eq!(Tenor::next_imm(NaiveDate::MAX), None);
+    /// ```
+    pub fn next_imm(date: NaiveDate) -> Option<NaiveDate> {
+        Tenor::nth_imm(date, 1)
+    }
+
+    /// The `n`-th IMM date strictly after the given date, that is, quarterly IMM rolls.
+    ///
+    /// `n = 1` is equivalent to [`Tenor::next_imm`] and `n = 0` returns the given date as is.
+    /// `None` is returned if the IMM date is out of the range of [`NaiveDate`].
+    pub fn nth_imm(date: NaiveDate, n: u32) -> Option<NaiveDate> {
+        if n == 0 {
+            return Some(date);
+        }
+        let quarter_end = (date.month0() / 3) * 3 + 3; // 3, 6, 9, 12
+        let imm = _third_wednesday(date.year(), quarter_end)?;
+        let first = if date < imm {
+            imm
+        } else {
+            let (y, m) = if quarter_end == 12 {
+                (date.year().checked_add(1)?, 3)
+            } else {
+                (date.year(), quarter_end + 3)
+            };
+            _third_wednesday(y, m)?
+        };
+        let months = (n as i64 - 1) * 3 + first.month0() as i64;
+        let year = i32::try_from(first.year() as i64 + months / 12).ok()?;
+        _third_wednesday(year, (months % 12) as u32 + 1)
+    }
+}
+
+#[inline]
+fn _third_wednesday(year: i32, month: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, 1).and_then(|d| d.nth_weekday(3, Weekday::Wed))
+}
+
 //
 // ops
 //
@@ -119,6 +297,45 @@ impl Neg for Tenor {
     }
 }
 
+/// Tenors are ordered by approximate lengths in days, where a week is 7 days,
+/// a month is 30 days and a year is 365 days.
+/// This convention is for ordering only and not used for [`Tenor::apply`].
+///
+/// Tenors with the same approximate length are ordered by units, `D < W < M < Y`,
+/// so the ordering is total and consistent with [`Eq`].
+/// Since actual lengths of months and years vary,
+/// `a < b` does not guarantee `a.apply(d) <= b.apply(d)` for every date
+/// when their lengths are close, e.g. `1M` and `29D` from February 1st.
+///
+/// # Example
+/// ```
+/// use qchrono::duration::Tenor;
+///
+/// let mut tenors: Vec<Tenor> = ["1Y", "-1M", "6M", "2W", "1M"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// tenors.sort();
+///
+/// let sorted: Vec<_> = tenors.iter().map(|t| t.to_string()).collect();
+/// assert_eq!(sorted, vec!["-P1M", "P2W", "P1M", "P6M", "P1Y"]);
+/// ```
+impl Ord for Tenor {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self._approx_days()
+            .cmp(&other._approx_days())
+            .then_with(|| self._unit_rank().cmp(&other._unit_rank()))
+    }
+}
+
+impl PartialOrd for Tenor {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Mul<i16> for Tenor {
     type Output = Self;
 
@@ -269,11 +486,21 @@ mod tests {
     #[rstest]
     #[case::empty("")]
     #[case::whitespace(" ")]
-    #[case::without_prefix("1D")]
-    #[case::without_prefix("1W")]
-    #[case::without_prefix("1M")]
-    #[case::without_prefix("1Y")]
+    #[case::prefix_only("P")]
+    #[case::prefix_only("-P")]
+    #[case::sign_only("+")]
+    #[case::invalid_suffix("3X")]
     #[case::invalid_suffix("P1X")]
+    #[case::missing_number("PM")]
+    #[case::missing_number("P1YM")]
+    #[case::missing_unit("P1")]
+    #[case::missing_unit("P1Y6")]
+    #[case::unordered("P6M1Y")]
+    #[case::duplicated("P1M1M")]
+    #[case::mixed("P1M2D")]
+    #[case::mixed("P1Y1W")]
+    #[case::overflow("P2731Y1M")]
+    #[case::lower_case("P1m")]
     #[case::invalid_suffix("P1DW")]
     #[case::invalid_number("P1.0D")]
     #[case::invalid_number("P1.0W")]
@@ -291,6 +518,46 @@ mod tests {
         assert!(tested.is_err());
     }
 
+    #[rstest]
+    #[case("1D", Tenor::Days(1))]
+    #[case("2W", Tenor::Weeks(2))]
+    #[case("3M", Tenor::Months(3))]
+    #[case("6M", Tenor::Months(6))]
+    #[case("1Y", Tenor::Years(1))]
+    #[case("-1Y", Tenor::Years(-1))]
+    #[case("+1Y", Tenor::Years(1))]
+    #[case("P1Y6M", Tenor::Months(18))]
+    #[case("-P1Y6M", Tenor::Months(-18))]
+    #[case("1Y6M", Tenor::Months(18))]
+    #[case("P0Y6M", Tenor::Months(6))]
+    #[case("P1W2D", Tenor::Days(9))]
+    #[case("1W2D", Tenor::Days(9))]
+    fn test_from_str_extended(#[case] s: &str, #[case] expected: Tenor) {
+        let tested = Tenor::from_str(s).unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case("P", "Expected format")]
+    #[case("3X", "Unknown unit 'X'")]
+    #[case("P1", "Unit is missing")]
+    #[case("P1M2D", "Mixing")]
+    fn test_from_str_err_message(#[case] s: &str, #[case] expected: &str) {
+        let tested = Tenor::from_str(s).unwrap_err().to_string();
+
+        assert!(tested.contains(expected), "{tested}");
+    }
+
+    #[rstest]
+    #[case("\"P1Y6M\"", Tenor::Months(18))]
+    #[case("\"3M\"", Tenor::Months(3))]
+    fn test_deserialize(#[case] json: &str, #[case] expected: Tenor) {
+        let tested: Tenor = serde_json::from_str(json).unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
     #[rstest]
     fn test_neg(#[values(0, 1, -1, 42, -42)] n: i16) {
         // days
@@ -326,6 +593,95 @@ mod tests {
         assert_eq!(tested, expected);
     }
 
+    #[rstest]
+    #[case(Tenor::Days(-1), true)]
+    #[case(Tenor::Days(0), false)]
+    #[case(Tenor::Weeks(1), false)]
+    #[case(Tenor::Months(-3), true)]
+    #[case(Tenor::Years(0), false)]
+    #[case(Tenor::Years(-1), true)]
+    fn test_is_negative(#[case] tenor: Tenor, #[case] expected: bool) {
+        let tested = tenor.is_negative();
+
+        assert_eq!(tested, expected);
+        if tenor != -tenor {
+            assert_eq!((-tenor).is_negative(), !expected);
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        let y1 = Tenor::Years(1);
+        let m6 = Tenor::Months(6);
+        let m1 = Tenor::Months(1);
+
+        assert!(y1 > m6);
+        assert!(m6 > m1);
+        assert!(m1 > -m1);
+        assert!(-m1 > -m6);
+        assert!(-m6 > -y1);
+    }
+
+    #[rstest]
+    #[case(Tenor::Days(7), Tenor::Weeks(1))]
+    #[case(Tenor::Days(30), Tenor::Months(1))]
+    #[case(Tenor::Months(12), Tenor::Years(1))]
+    #[case(Tenor::Weeks(4), Tenor::Months(1))]
+    #[case(Tenor::Days(-1), Tenor::Days(0))]
+    #[case(Tenor::Years(-1), Tenor::Months(-12))]
+    fn test_ord_lt(#[case] lhs: Tenor, #[case] rhs: Tenor) {
+        assert!(lhs < rhs);
+        assert!(rhs > lhs);
+        assert_ne!(lhs, rhs);
+        assert_eq!(lhs.cmp(&rhs), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn test_ord_sort() {
+        let mut tenors = vec![
+            Tenor::Years(1),
+            Tenor::Months(-1),
+            Tenor::Days(0),
+            Tenor::Weeks(2),
+            Tenor::Months(6),
+            Tenor::Days(1),
+            Tenor::Months(12),
+        ];
+
+        tenors.sort();
+
+        assert_eq!(
+            tenors,
+            vec![
+                Tenor::Months(-1),
+                Tenor::Days(0),
+                Tenor::Days(1),
+                Tenor::Weeks(2),
+                Tenor::Months(6),
+                Tenor::Months(12),
+                Tenor::Years(1),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case((2021, 1, 15), Tenor::Days(20))]
+    #[case((2021, 1, 15), Tenor::Weeks(3))]
+    #[case((2021, 1, 15), Tenor::Months(3))]
+    #[case((2021, 1, 31), Tenor::Months(3))]
+    #[case((2020, 2, 29), Tenor::Years(4))]
+    fn test_neg_apply(#[case] base: (i32, u32, u32), #[case] tenor: Tenor) {
+        let date = NaiveDate::from_ymd_opt(base.0, base.1, base.2).unwrap();
+
+        let forward = tenor.apply(date);
+        let backward = (-tenor).apply(forward);
+
+        assert_eq!(backward, date);
+        assert!(date < forward);
+        assert!((-tenor).apply(date) < date);
+        assert!(-tenor < tenor);
+    }
+
     #[rstest]
     fn test_mul(#[values(0, 1, -1, 42, -42)] n: i16, #[values(0, 1, -1, 42, -42)] m: i16) {
         // days
@@ -491,4 +847,114 @@ mod tests {
 
         assert_eq!(tested, expected);
     }
+
+    #[rstest]
+    #[case::typical((2021, 1, 15), Tenor::Months(1), (2021, 2, 15))]
+    #[case::typical((2021, 1, 15), Tenor::Months(-1), (2020, 12, 15))]
+    #[case::typical((2021, 1, 15), Tenor::Years(1), (2022, 1, 15))]
+    #[case::typical((2021, 1, 15), Tenor::Days(20), (2021, 2, 4))]
+    #[case::typical((2021, 1, 15), Tenor::Weeks(-2), (2021, 1, 1))]
+    #[case::clamp((2021, 1, 30), Tenor::Months(1), (2021, 2, 28))]
+    #[case::clamp((2020, 1, 30), Tenor::Months(1), (2020, 2, 29))]
+    #[case::eom((2021, 1, 31), Tenor::Months(1), (2021, 2, 28))]
+    #[case::eom((2020, 1, 31), Tenor::Months(1), (2020, 2, 29))]
+    #[case::eom((2021, 2, 28), Tenor::Months(1), (2021, 3, 31))]
+    #[case::eom((2020, 2, 29), Tenor::Months(1), (2020, 3, 31))]
+    #[case::eom((2020, 2, 28), Tenor::Months(1), (2020, 3, 28))]
+    #[case::eom((2021, 4, 30), Tenor::Months(-1), (2021, 3, 31))]
+    #[case::eom((2020, 2, 29), Tenor::Years(1), (2021, 2, 28))]
+    #[case::eom((2019, 2, 28), Tenor::Years(1), (2020, 2, 29))]
+    #[case::eom((2021, 1, 31), Tenor::Days(28), (2021, 2, 28))]
+    fn test_apply(
+        #[case] base: (i32, u32, u32),
+        #[case] tenor: Tenor,
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let date = NaiveDate::from_ymd_opt(base.0, base.1, base.2).unwrap();
+        let expected = NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+
+        let tested = tenor.apply(date);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MAX, Tenor::Months(1), NaiveDate::MAX)]
+    #[case(NaiveDate::MAX, Tenor::Years(1), NaiveDate::MAX)]
+    #[case(NaiveDate::MIN, Tenor::Months(-1), NaiveDate::MIN)]
+    #[case(NaiveDate::MIN, Tenor::Years(-1), NaiveDate::MIN)]
+    fn test_apply_saturate(
+        #[case] date: NaiveDate,
+        #[case] tenor: Tenor,
+        #[case] expected: NaiveDate,
+    ) {
+        let tested = tenor.apply(date);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case((2021, 1, 1), (2021, 3, 17))]
+    #[case((2021, 3, 16), (2021, 3, 17))]
+    #[case((2021, 3, 17), (2021, 6, 16))]
+    #[case((2021, 4, 1), (2021, 6, 16))]
+    #[case((2021, 9, 15), (2021, 12, 15))]
+    #[case((2021, 12, 15), (2022, 3, 16))]
+    #[case((2021, 12, 31), (2022, 3, 16))]
+    #[case((2024, 2, 29), (2024, 3, 20))]
+    fn test_next_imm(#[case] base: (i32, u32, u32), #[case] expected: (i32, u32, u32)) {
+        let date = NaiveDate::from_ymd_opt(base.0, base.1, base.2).unwrap();
+        let expected = NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+
+        let tested = Tenor::next_imm(date).unwrap();
+
+        assert_eq!(tested, expected);
+        assert_eq!(tested.weekday(), Weekday::Wed);
+    }
+
+    #[rstest]
+    #[case((2021, 1, 1), 0, (2021, 1, 1))]
+    #[case((2021, 1, 1), 1, (2021, 3, 17))]
+    #[case((2021, 1, 1), 2, (2021, 6, 16))]
+    #[case((2021, 1, 1), 4, (2021, 12, 15))]
+    #[case((2021, 1, 1), 5, (2022, 3, 16))]
+    #[case((2021, 12, 15), 4, (2022, 12, 21))]
+    fn test_nth_imm(
+        #[case] base: (i32, u32, u32),
+        #[case] n: u32,
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let date = NaiveDate::from_ymd_opt(base.0, base.1, base.2).unwrap();
+        let expected = NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+
+        let tested = Tenor::nth_imm(date, n);
+
+        assert_eq!(tested, Some(expected));
+    }
+
+    #[rstest]
+    #[case(NaiveDate::MAX, 1)]
+    #[case(NaiveDate::from_ymd_opt(262142, 12, 20).unwrap(), 1)]
+    #[case(NaiveDate::from_ymd_opt(262142, 1, 1).unwrap(), 5)]
+    #[case(NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), u32::MAX)]
+    fn test_nth_imm_out_of_range(#[case] date: NaiveDate, #[case] n: u32) {
+        let tested = Tenor::nth_imm(date, n);
+
+        assert_eq!(tested, None);
+    }
+
+    #[test]
+    fn test_next_imm_near_max() {
+        let date = NaiveDate::from_ymd_opt(262142, 1, 1).unwrap();
+
+        let tested = Tenor::nth_imm(date, 4);
+
+        assert_eq!(
+            tested,
+            NaiveDate::from_ymd_opt(262142, 12, 1)
+                .unwrap()
+                .nth_weekday(3, Weekday::Wed)
+        );
+        assert!(tested.is_some());
+    }
 }
@@ -4,4 +4,5 @@ pub use rstest_reuse;
 pub mod calendar;
 pub mod duration;
 pub mod ext;
+pub mod schedule;
 pub mod timepoint;
@@ -0,0 +1,418 @@
+use anyhow::{bail, ensure};
+
+use crate::{
+    calendar::{Calendar, HolidayAdj},
+    duration::Tenor,
+    timepoint::Date,
+};
+
+// -----------------------------------------------------------------------------
+// StubConvention
+// -----------------------------------------------------------------------------
+/// Where an irregular period is placed when the schedule is not divisible by the frequency.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StubConvention {
+    /// Dates are rolled backward from the termination date
+    /// and the first period is shorter than the frequency.
+    ShortFront,
+    /// Dates are rolled backward from the termination date
+    /// and the short first period is merged into the next one.
+    LongFront,
+    /// Dates are rolled forward from the effective date
+    /// and the last period is shorter than the frequency.
+    ShortBack,
+    /// Dates are rolled forward from the effective date
+    /// and the short last period is merged into the previous one.
+    LongBack,
+}
+
+// -----------------------------------------------------------------------------
+// generate
+// -----------------------------------------------------------------------------
+/// Generate accrual periods between `effective` and `termination`.
+///
+/// Unadjusted boundaries are generated by applying multiples of `frequency`
+/// to the anchor date, i.e. the termination date for front stubs and the effective date for back stubs,
+/// so that month-end stickiness of [`Tenor::apply`] is kept throughout the schedule.
+/// Then each boundary, including the effective and termination dates, is adjusted with `roll`.
+///
+/// No stub is generated when the period is divisible by the frequency.
+/// Long stubs are generated only when there are at least two periods.
+///
+/// # Errors
+/// - When `effective` is not before `termination`.
+/// - When `frequency` does not move dates forward.
+/// - When a boundary can not be adjusted with the calendar.
+///
+/// # Example
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use qchrono::{
+///     calendar::{Calendar, HolidayAdj},
+///     duration::Tenor,
+///     schedule::{generate, StubConvention},
+/// };
+///
+/// let ymd = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let cal = Calendar::builder()
+///     .with_valid_period(ymd(2021, 1, 1), ymd(2022, 12, 31))
+///     .with_extra_holidays(vec![])
+///     .with_extra_business_days(vec![])
+///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+///     .build()
+///     .unwrap();
+///
+/// let schedule = generate(
+///     ymd(2021, 1, 15),
+///     ymd(2021, 12, 15),
+///     Tenor::Months(3),
+///     StubConvention::ShortFront,
+///     HolidayAdj::ModifiedFollowing,
+///     &cal,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     schedule,
+///     vec![
+///         (ymd(2021, 1, 15), ymd(2021, 3, 15)),
+///         (ymd(2021, 3, 15), ymd(2021, 6, 15)),
+///         (ymd(2021, 6, 15), ymd(2021, 9, 15)),
+///         (ymd(2021, 9, 15), ymd(2021, 12, 15)),
+///     ]
+/// );
+/// ```
+pub fn generate(
+    effective: Date,
+    termination: Date,
+    frequency: Tenor,
+    stub: StubConvention,
+    roll: HolidayAdj,
+    cal: &Calendar,
+) -> anyhow::Result<Vec<(Date, Date)>> {
+    ensure!(
+        effective < termination,
+        "effective date({effective}) must be before termination date({termination})"
+    );
+    ensure!(
+        effective < frequency.apply(effective),
+        "frequency({frequency}) must be positive"
+    );
+    let mut dates = match stub {
+        StubConvention::ShortFront | StubConvention::LongFront => {
+            let mut dates = _roll(termination, -frequency, |d| effective < d)?;
+            dates.reverse();
+            dates
+        }
+        StubConvention::ShortBack | StubConvention::LongBack => {
+            _roll(effective, frequency, |d| d < termination)?
+        }
+    };
+    // dates are strictly inside of (effective, termination) here
+    let has_stub = match stub {
+        StubConvention::ShortFront | StubConvention::LongFront => dates
+            .first()
+            .map_or(true, |&d| (-frequency).apply(d) != effective),
+        StubConvention::ShortBack | StubConvention::LongBack => dates
+            .last()
+            .map_or(true, |&d| frequency.apply(d) != termination),
+    };
+    if has_stub && !dates.is_empty() {
+        match stub {
+            StubConvention::LongFront => {
+                dates.remove(0);
+            }
+            StubConvention::LongBack => {
+                dates.pop();
+            }
+            StubConvention::ShortFront | StubConvention::ShortBack => {}
+        }
+    }
+
+    let mut bounds = Vec::with_capacity(dates.len() + 2);
+    for d in std::iter::once(effective)
+        .chain(dates)
+        .chain(std::iter::once(termination))
+    {
+        bounds.push(roll.adjust(d, cal)?);
+    }
+    Ok(bounds.windows(2).map(|w| (w[0], w[1])).collect())
+}
+
+/// Roll `anchor` by multiples of `step` while `cond` holds, excluding the anchor itself.
+///
+/// Days and weeks are rolled from the previous date since they have no end-of-month rule.
+/// Months and years are rolled from the anchor to keep its day of month,
+/// and it is an error if the multiple of `step` overflows.
+fn _roll(anchor: Date, step: Tenor, cond: impl Fn(Date) -> bool) -> anyhow::Result<Vec<Date>> {
+    let mut dates = Vec::new();
+    match step {
+        Tenor::Days(_) | Tenor::Weeks(_) => {
+            let mut prev = anchor;
+            loop {
+                let d = step.apply(prev);
+                if d == prev || !cond(d) {
+                    break;
+                }
+                dates.push(d);
+                prev = d;
+            }
+        }
+        Tenor::Months(m) | Tenor::Years(m) => {
+            for n in 1..=i16::MAX {
+                let Some(count) = m.checked_mul(n) else {
+                    break;
+                };
+                let d = match step {
+                    Tenor::Months(_) => Tenor::Months(count),
+                    _ => Tenor::Years(count),
+                }
+                .apply(anchor);
+                if !cond(d) {
+                    return Ok(dates);
+                }
+                dates.push(d);
+            }
+            bail!("too many periods of {step} from {anchor}");
+        }
+    }
+    Ok(dates)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> Date {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn cal() -> Calendar {
+        Calendar::builder()
+            .with_valid_period(ymd(2020, 1, 1), ymd(2022, 12, 31))
+            .with_extra_holidays(vec![])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case(StubConvention::ShortFront, vec![
+        (ymd(2021, 1, 15), ymd(2021, 3, 15)),
+        (ymd(2021, 3, 15), ymd(2021, 6, 15)),
+        (ymd(2021, 6, 15), ymd(2021, 9, 15)),
+        (ymd(2021, 9, 15), ymd(2021, 12, 15)),
+    ])]
+    #[case(StubConvention::LongFront, vec![
+        (ymd(2021, 1, 15), ymd(2021, 6, 15)),
+        (ymd(2021, 6, 15), ymd(2021, 9, 15)),
+        (ymd(2021, 9, 15), ymd(2021, 12, 15)),
+    ])]
+    #[case(StubConvention::ShortBack, vec![
+        (ymd(2021, 1, 15), ymd(2021, 4, 15)),
+        (ymd(2021, 4, 15), ymd(2021, 7, 15)),
+        (ymd(2021, 7, 15), ymd(2021, 10, 15)),
+        (ymd(2021, 10, 15), ymd(2021, 12, 15)),
+    ])]
+    #[case(StubConvention::LongBack, vec![
+        (ymd(2021, 1, 15), ymd(2021, 4, 15)),
+        (ymd(2021, 4, 15), ymd(2021, 7, 15)),
+        (ymd(2021, 7, 15), ymd(2021, 12, 15)),
+    ])]
+    fn test_generate_11m(#[case] stub: StubConvention, #[case] expected: Vec<(Date, Date)>) {
+        let tested = generate(
+            ymd(2021, 1, 15),
+            ymd(2021, 12, 15),
+            Tenor::Months(3),
+            stub,
+            HolidayAdj::ModifiedFollowing,
+            &cal(),
+        )
+        .unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(StubConvention::ShortFront, vec![
+        (ymd(2021, 1, 29), ymd(2021, 3, 31)),
+        (ymd(2021, 3, 31), ymd(2021, 6, 30)),
+        (ymd(2021, 6, 30), ymd(2021, 9, 30)),
+        (ymd(2021, 9, 30), ymd(2021, 12, 31)),
+    ])]
+    #[case(StubConvention::LongFront, vec![
+        (ymd(2021, 1, 29), ymd(2021, 6, 30)),
+        (ymd(2021, 6, 30), ymd(2021, 9, 30)),
+        (ymd(2021, 9, 30), ymd(2021, 12, 31)),
+    ])]
+    #[case(StubConvention::ShortBack, vec![
+        (ymd(2021, 1, 29), ymd(2021, 4, 30)),
+        (ymd(2021, 4, 30), ymd(2021, 7, 30)),
+        (ymd(2021, 7, 30), ymd(2021, 10, 29)),
+        (ymd(2021, 10, 29), ymd(2021, 12, 31)),
+    ])]
+    #[case(StubConvention::LongBack, vec![
+        (ymd(2021, 1, 29), ymd(2021, 4, 30)),
+        (ymd(2021, 4, 30), ymd(2021, 7, 30)),
+        (ymd(2021, 7, 30), ymd(2021, 12, 31)),
+    ])]
+    fn test_generate_11m_eom_adjusted(
+        #[case] stub: StubConvention,
+        #[case] expected: Vec<(Date, Date)>,
+    ) {
+        let tested = generate(
+            ymd(2021, 1, 31),
+            ymd(2021, 12, 31),
+            Tenor::Months(3),
+            stub,
+            HolidayAdj::ModifiedFollowing,
+            &cal(),
+        )
+        .unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    fn test_generate_regular(
+        #[values(
+            StubConvention::ShortFront,
+            StubConvention::LongFront,
+            StubConvention::ShortBack,
+            StubConvention::LongBack
+        )]
+        stub: StubConvention,
+    ) {
+        let tested = generate(
+            ymd(2021, 3, 15),
+            ymd(2021, 12, 15),
+            Tenor::Months(3),
+            stub,
+            HolidayAdj::Unadjusted,
+            &cal(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tested,
+            vec![
+                (ymd(2021, 3, 15), ymd(2021, 6, 15)),
+                (ymd(2021, 6, 15), ymd(2021, 9, 15)),
+                (ymd(2021, 9, 15), ymd(2021, 12, 15)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_generate_single_stub(
+        #[values(
+            StubConvention::ShortFront,
+            StubConvention::LongFront,
+            StubConvention::ShortBack,
+            StubConvention::LongBack
+        )]
+        stub: StubConvention,
+    ) {
+        let tested = generate(
+            ymd(2021, 1, 15),
+            ymd(2021, 3, 1),
+            Tenor::Months(3),
+            stub,
+            HolidayAdj::Unadjusted,
+            &cal(),
+        )
+        .unwrap();
+
+        assert_eq!(tested, vec![(ymd(2021, 1, 15), ymd(2021, 3, 1))]);
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 12, 15), ymd(2021, 1, 15), Tenor::Months(3))]
+    #[case(ymd(2021, 1, 15), ymd(2021, 1, 15), Tenor::Months(3))]
+    #[case(ymd(2021, 1, 15), ymd(2021, 12, 15), Tenor::Months(0))]
+    #[case(ymd(2021, 1, 15), ymd(2021, 12, 15), Tenor::Months(-3))]
+    #[case(ymd(2021, 1, 15), ymd(2023, 12, 15), Tenor::Months(3))]
+    fn test_generate_err(#[case] effective: Date, #[case] termination: Date, #[case] freq: Tenor) {
+        let tested = generate(
+            effective,
+            termination,
+            freq,
+            StubConvention::ShortFront,
+            HolidayAdj::ModifiedFollowing,
+            &cal(),
+        );
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    #[case(Tenor::Days(1), 36525)]
+    #[case(Tenor::Weeks(1), 5218)]
+    fn test_generate_long(
+        #[case] freq: Tenor,
+        #[case] expected_len: usize,
+        #[values(StubConvention::ShortFront, StubConvention::ShortBack)] stub: StubConvention,
+    ) {
+        let cal = Calendar::builder()
+            .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+            .with_extra_holidays(vec![])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![])
+            .build()
+            .unwrap();
+        let (effective, termination) = (ymd(2000, 1, 1), ymd(2100, 1, 1));
+
+        let tested = generate(
+            effective,
+            termination,
+            freq,
+            stub,
+            HolidayAdj::Unadjusted,
+            &cal,
+        )
+        .unwrap();
+
+        assert_eq!(tested.len(), expected_len);
+        assert_eq!(tested.first().unwrap().0, effective);
+        assert_eq!(tested.last().unwrap().1, termination);
+        assert!(tested.windows(2).all(|w| w[0].1 == w[1].0));
+    }
+
+    #[test]
+    fn test_generate_err_too_many_periods() {
+        let cal = Calendar::builder()
+            .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+            .with_extra_holidays(vec![])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![])
+            .build()
+            .unwrap();
+
+        let tested = generate(
+            ymd(2000, 1, 1),
+            ymd(5000, 1, 1),
+            Tenor::Months(1),
+            StubConvention::ShortBack,
+            HolidayAdj::Unadjusted,
+            &cal,
+        );
+
+        assert!(tested.is_err());
+    }
+}
@@ -2,6 +2,6 @@ mod date;
 mod datetime;
 mod timezone;
 
-pub use date::{Date, Weekday};
-pub use datetime::DateTime;
+pub use date::{Date, DateExtensions, Weekday};
+pub use datetime::{DateTime, DstPolicy, TimeUnit};
 pub use timezone::{Tz, TzOffset};
@@ -1,2 +1,171 @@
+use chrono::{Datelike, Months};
+
 pub type Date = chrono::NaiveDate;
 pub type Weekday = chrono::Weekday;
+
+// -----------------------------------------------------------------------------
+// DateExtensions
+// -----------------------------------------------------------------------------
+/// Month-based helpers for [`Date`].
+///
+/// Methods which can overflow saturate to [`Date::MIN`] or [`Date::MAX`]
+/// as arithmetic with [`crate::duration::Tenor`] does.
+pub trait DateExtensions: Sized {
+    /// The first day of the month of the date.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{Date, DateExtensions};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| Date::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2024, 2, 15).start_of_month(), ymd(2024, 2, 1));
+    /// ```
+    fn start_of_month(&self) -> Self;
+
+    /// The last day of the month of the date.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{Date, DateExtensions};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| Date::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2024, 2, 15).end_of_month(), ymd(2024, 2, 29));
+    /// assert_eq!(ymd(2023, 2, 15).end_of_month(), ymd(2023, 2, 28));
+    /// ```
+    fn end_of_month(&self) -> Self;
+
+    /// Whether the date is the last day of its month.
+    fn is_end_of_month(&self) -> bool;
+
+    /// Add months to the date. When the day does not exist in the target month,
+    /// the result is clamped to the end of that month.
+    ///
+    /// Unlike [`crate::duration::Tenor::apply`], month-end stickiness is not applied,
+    /// that is, `Feb 28` plus one month is `Mar 28` even in a non-leap year.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{Date, DateExtensions};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| Date::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2024, 1, 31).add_months_clamped(1), ymd(2024, 2, 29));
+    /// assert_eq!(ymd(2024, 3, 31).add_months_clamped(-1), ymd(2024, 2, 29));
+    /// assert_eq!(ymd(2023, 2, 28).add_months_clamped(1), ymd(2023, 3, 28));
+    /// ```
+    fn add_months_clamped(&self, n: i32) -> Self;
+
+    /// The `n`-th given weekday in the month of the date, e.g. the 3rd Wednesday.
+    ///
+    /// `n` is 1-based. `None` is returned when `n` is zero or the month does not have
+    /// the `n`-th weekday, e.g. the 5th Monday of most months.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{Date, DateExtensions, Weekday};
+    ///
+    /// let ymd = |y: i32, m: u32, d: u32| Date::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2024, 3, 1).nth_weekday(3, Weekday::Wed), Some(ymd(2024, 3, 20)));
+    /// assert_eq!(ymd(2024, 2, 1).nth_weekday(5, Weekday::Fri), None);
+    /// ```
+    fn nth_weekday(&self, n: u8, weekday: Weekday) -> Option<Self>;
+}
+
+impl DateExtensions for Date {
+    #[inline]
+    fn start_of_month(&self) -> Self {
+        self.with_day(1)
+            .expect("the first day of month always exists")
+    }
+
+    #[inline]
+    fn end_of_month(&self) -> Self {
+        self.start_of_month()
+            .checked_add_months(Months::new(1))
+            .and_then(|d| d.pred_opt())
+            .unwrap_or(Date::MAX)
+    }
+
+    #[inline]
+    fn is_end_of_month(&self) -> bool {
+        self.succ_opt().map_or(true, |d| d.month() != self.month())
+    }
+
+    #[inline]
+    fn add_months_clamped(&self, n: i32) -> Self {
+        if n >= 0 {
+            self.checked_add_months(Months::new(n.unsigned_abs()))
+                .unwrap_or(Date::MAX)
+        } else {
+            self.checked_sub_months(Months::new(n.unsigned_abs()))
+                .unwrap_or(Date::MIN)
+        }
+    }
+
+    #[inline]
+    fn nth_weekday(&self, n: u8, weekday: Weekday) -> Option<Self> {
+        Date::from_weekday_of_month_opt(self.year(), self.month(), weekday, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn ymd(y: i32, m: u32, d: u32) -> Date {
+        Date::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 2, 1), ymd(2024, 2, 1), ymd(2024, 2, 29))]
+    #[case(ymd(2024, 2, 29), ymd(2024, 2, 1), ymd(2024, 2, 29))]
+    #[case(ymd(2023, 2, 14), ymd(2023, 2, 1), ymd(2023, 2, 28))]
+    #[case(ymd(2000, 2, 14), ymd(2000, 2, 1), ymd(2000, 2, 29))]
+    #[case(ymd(1900, 2, 14), ymd(1900, 2, 1), ymd(1900, 2, 28))]
+    #[case(ymd(2023, 12, 31), ymd(2023, 12, 1), ymd(2023, 12, 31))]
+    #[case(ymd(2023, 4, 10), ymd(2023, 4, 1), ymd(2023, 4, 30))]
+    fn test_start_end_of_month(#[case] date: Date, #[case] start: Date, #[case] end: Date) {
+        assert_eq!(date.start_of_month(), start);
+        assert_eq!(date.end_of_month(), end);
+        assert_eq!(date.is_end_of_month(), date == end);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 1, 31), 1, ymd(2024, 2, 29))]
+    #[case(ymd(2023, 1, 31), 1, ymd(2023, 2, 28))]
+    #[case(ymd(2024, 2, 29), 12, ymd(2025, 2, 28))]
+    #[case(ymd(2024, 2, 29), 48, ymd(2028, 2, 29))]
+    #[case(ymd(2024, 2, 29), 1, ymd(2024, 3, 29))]
+    #[case(ymd(2024, 3, 31), -1, ymd(2024, 2, 29))]
+    #[case(ymd(2024, 5, 31), -3, ymd(2024, 2, 29))]
+    #[case(ymd(2024, 5, 15), 0, ymd(2024, 5, 15))]
+    #[case(Date::MAX, 1, Date::MAX)]
+    #[case(Date::MIN, -1, Date::MIN)]
+    fn test_add_months_clamped(#[case] date: Date, #[case] n: i32, #[case] expected: Date) {
+        let tested = date.add_months_clamped(n);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(ymd(2024, 3, 31), 3, Weekday::Wed, Some(ymd(2024, 3, 20)))]
+    #[case(ymd(2021, 3, 1), 3, Weekday::Wed, Some(ymd(2021, 3, 17)))]
+    #[case(ymd(2024, 2, 10), 5, Weekday::Thu, Some(ymd(2024, 2, 29)))]
+    #[case(ymd(2023, 2, 10), 5, Weekday::Thu, None)]
+    #[case(ymd(2024, 2, 10), 1, Weekday::Thu, Some(ymd(2024, 2, 1)))]
+    #[case(ymd(2024, 2, 10), 0, Weekday::Thu, None)]
+    fn test_nth_weekday(
+        #[case] date: Date,
+        #[case] n: u8,
+        #[case] weekday: Weekday,
+        #[case] expected: Option<Date>,
+    ) {
+        let tested = date.nth_weekday(n, weekday);
+
+        assert_eq!(tested, expected);
+    }
+}
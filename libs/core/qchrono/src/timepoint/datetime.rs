@@ -8,9 +8,69 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     duration::{Duration, Tenor},
-    timepoint::Tz,
+    timepoint::{Tz, TzOffset},
 };
 
+// -----------------------------------------------------------------------------
+// DstPolicy
+// -----------------------------------------------------------------------------
+/// Policy to resolve a local datetime which is ambiguous in a timezone.
+///
+/// On fall-back DST transition, a local datetime can correspond to two instants.
+/// A local datetime in the spring-forward gap corresponds to no instant
+/// and it is rejected regardless of the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DstPolicy {
+    /// Take the earlier instant.
+    Earliest,
+    /// Take the later instant.
+    Latest,
+    /// Reject ambiguous local datetimes.
+    #[default]
+    Reject,
+}
+
+// -----------------------------------------------------------------------------
+// TimeUnit
+// -----------------------------------------------------------------------------
+/// Granularity to truncate or round a datetime to.
+///
+/// See [`DateTime::truncate_to`] and [`DateTime::round_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl TimeUnit {
+    /// Truncate a local datetime to this unit.
+    #[inline]
+    fn _truncate(&self, dt: chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        use chrono::Timelike;
+        let (h, m, s) = (dt.hour(), dt.minute(), dt.second());
+        let time = match self {
+            TimeUnit::Second => chrono::NaiveTime::from_hms_opt(h, m, s),
+            TimeUnit::Minute => chrono::NaiveTime::from_hms_opt(h, m, 0),
+            TimeUnit::Hour => chrono::NaiveTime::from_hms_opt(h, 0, 0),
+            TimeUnit::Day => chrono::NaiveTime::from_hms_opt(0, 0, 0),
+        };
+        dt.date()
+            .and_time(time.expect("Truncated time-of-day is valid"))
+    }
+
+    #[inline]
+    fn _delta(&self) -> chrono::TimeDelta {
+        match self {
+            TimeUnit::Second => chrono::TimeDelta::seconds(1),
+            TimeUnit::Minute => chrono::TimeDelta::minutes(1),
+            TimeUnit::Hour => chrono::TimeDelta::hours(1),
+            TimeUnit::Day => chrono::TimeDelta::days(1),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // DateTime
 // -----------------------------------------------------------------------------
@@ -38,7 +98,7 @@ impl Eq for DateTime {}
 impl PartialOrd for DateTime {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.inner.cmp(&other.inner))
+        Some(self.cmp(other))
     }
 }
 impl Ord for DateTime {
@@ -138,7 +198,7 @@ impl FromStr for DateTime {
                 let tz = Tz::from_str(tz)
                     .with_context(|| format!("parse '{}' to timezone", &caps["timezone"]))?;
                 match tp.and_local_timezone(tz) {
-                    chrono::LocalResult::Single(tp) => return Ok(tp.into()),
+                    chrono::LocalResult::Single(tp) => Ok(tp.into()),
                     chrono::LocalResult::Ambiguous(_, _) => {
                         anyhow::bail!("parse '{}' to datetime. Ambiguous datetime", tp)
                     }
@@ -404,6 +464,56 @@ impl std::ops::Sub<Duration> for DateTime {
 // methods
 //
 impl DateTime {
+    /// Create a datetime from a local datetime in the given timezone.
+    ///
+    /// This is same as [`DateTime::new_with_policy`] with [`DstPolicy::Reject`].
+    ///
+    /// # Errors
+    /// - When the local datetime is ambiguous or does not exist in the timezone.
+    #[inline]
+    pub fn new(datetime: chrono::NaiveDateTime, tz: Tz) -> anyhow::Result<Self> {
+        Self::new_with_policy(datetime, tz, DstPolicy::Reject)
+    }
+
+    /// Create a datetime from a local datetime in the given timezone,
+    /// resolving DST issues with the given policy.
+    ///
+    /// # Errors
+    /// - When the local datetime does not exist in the timezone.
+    /// - When the local datetime is ambiguous and the policy is [`DstPolicy::Reject`].
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{DateTime, DstPolicy, Tz};
+    ///
+    /// let tz: Tz = "America/New_York".parse().unwrap();
+    /// let local = "2023-11-05T01:30:00".parse().unwrap();
+    ///
+    /// let earliest = DateTime::new_with_policy(local, tz, DstPolicy::Earliest).unwrap();
+    /// let latest = DateTime::new_with_policy(local, tz, DstPolicy::Latest).unwrap();
+    ///
+    /// assert_eq!(earliest, "2023-11-05T01:30:00-04:00".parse().unwrap());
+    /// assert_eq!(latest, "2023-11-05T01:30:00-05:00".parse().unwrap());
+    /// assert!(DateTime::new(local, tz).is_err());
+    /// ```
+    pub fn new_with_policy(
+        datetime: chrono::NaiveDateTime,
+        tz: Tz,
+        policy: DstPolicy,
+    ) -> anyhow::Result<Self> {
+        match (datetime.and_local_timezone(tz), policy) {
+            (LocalResult::Single(dt), _) => Ok(dt.into()),
+            (LocalResult::Ambiguous(dt, _), DstPolicy::Earliest) => Ok(dt.into()),
+            (LocalResult::Ambiguous(_, dt), DstPolicy::Latest) => Ok(dt.into()),
+            (LocalResult::Ambiguous(_, _), DstPolicy::Reject) => {
+                anyhow::bail!("'{}' is ambiguous in timezone {:?}", datetime, tz)
+            }
+            (LocalResult::None, _) => {
+                anyhow::bail!("'{}' does not exist in timezone {:?}", datetime, tz)
+            }
+        }
+    }
+
     #[inline]
     pub fn date(&self) -> NaiveDate {
         self.inner.date_naive()
@@ -413,20 +523,167 @@ impl DateTime {
         self.inner.time()
     }
 
-    #[inline]
-    pub fn add_tenor(&self, tenor: Tenor) -> LocalResult<Self> {
-        let dt = (self.date() + tenor).and_time(self.time());
-        match dt.and_local_timezone(self.inner.timezone()) {
-            chrono::LocalResult::Single(dt) => LocalResult::Single(dt.into()),
-            chrono::LocalResult::Ambiguous(e, l) => LocalResult::Ambiguous(e.into(), l.into()),
-            chrono::LocalResult::None => LocalResult::None,
-        }
+    /// Add a [`Tenor`] to the date component keeping the local time-of-day and timezone.
+    ///
+    /// The date is shifted with [`Tenor::apply`], so the end-of-month rule is respected.
+    /// When the shifted local datetime is ambiguous due to DST, the earliest one is taken.
+    ///
+    /// # Errors
+    /// - When the shifted local datetime does not exist in the timezone, e.g. in a spring-forward gap.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::DateTime;
+    ///
+    /// let dt: DateTime = "2024-01-31T10:00:00[Asia/Tokyo]".parse().unwrap();
+    ///
+    /// let res = dt.add_tenor("1M".parse().unwrap()).unwrap();
+    ///
+    /// assert_eq!(res, "2024-02-29T10:00:00[Asia/Tokyo]".parse().unwrap());
+    /// ```
+    pub fn add_tenor(&self, tenor: Tenor) -> anyhow::Result<Self> {
+        let dt = tenor.apply(self.date()).and_time(self.time());
+        Self::new_with_policy(dt, self.timezone(), DstPolicy::Earliest).with_context(|| {
+            format!(
+                "Add tenor result is invalid. dt: {}, tenor: {}",
+                self, tenor
+            )
+        })
     }
 
     #[inline]
     pub fn timezone(&self) -> Tz {
         self.inner.timezone()
     }
+
+    /// Offset from UTC of this datetime as it appears on the wall clock.
+    ///
+    /// For a fixed offset timezone, this is the offset itself as written.
+    /// For an IANA timezone, this is the offset resolved at this instant, so DST is taken into account.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::DateTime;
+    ///
+    /// let dt: DateTime = "2021-07-01T10:42:11-04:00[America/New_York]".parse().unwrap();
+    ///
+    /// assert_eq!(dt.written_offset().seconds(), -4 * 3600);
+    /// ```
+    #[inline]
+    pub fn written_offset(&self) -> TzOffset {
+        *self.inner.offset()
+    }
+
+    /// Truncate this datetime to the given unit on the wall clock of its own timezone.
+    ///
+    /// Hence truncation to [`TimeUnit::Day`] yields the local midnight, not the UTC midnight.
+    /// Timezone is kept.
+    ///
+    /// Truncated local datetime is resolved to an instant as follows:
+    /// - If it is ambiguous due to DST, the later instant which is not after this datetime is taken.
+    /// - If it does not exist, e.g. midnight skipped by DST, the instant at which the gap ends is taken.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{DateTime, TimeUnit};
+    ///
+    /// let dt: DateTime = "2024-03-10T15:42:11-04:00[America/New_York]".parse().unwrap();
+    ///
+    /// let hour = dt.truncate_to(TimeUnit::Hour);
+    /// let day = dt.truncate_to(TimeUnit::Day);
+    ///
+    /// assert_eq!(hour, "2024-03-10T15:00:00-04:00".parse().unwrap());
+    /// assert_eq!(day, "2024-03-10T00:00:00-05:00".parse().unwrap());
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> Self {
+        let local = unit._truncate(self.inner.naive_local());
+        _resolve_floor(local, self.timezone(), &self.inner).into()
+    }
+
+    /// Round this datetime to the nearest boundary of the given unit
+    /// on the wall clock of its own timezone.
+    ///
+    /// Boundaries are [`DateTime::truncate_to`] and the next one on the wall clock,
+    /// which is the repeated same local datetime if the wall clock is turned back in between,
+    /// and the nearer one in elapsed time is taken. A datetime just in the middle is rounded up.
+    /// Because elapsed time is compared, a day with DST transition is split
+    /// at the middle of its actual length, not at the local noon.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::{DateTime, TimeUnit};
+    ///
+    /// let dt: DateTime = "2024-06-01T10:29:30+09:00".parse().unwrap();
+    ///
+    /// assert_eq!(dt.round_to(TimeUnit::Minute), "2024-06-01T10:30:00+09:00".parse().unwrap());
+    /// assert_eq!(dt.round_to(TimeUnit::Hour), "2024-06-01T10:00:00+09:00".parse().unwrap());
+    /// ```
+    pub fn round_to(&self, unit: TimeUnit) -> Self {
+        let tz = self.timezone();
+        let local = unit._truncate(self.inner.naive_local());
+        let floor = _resolve_floor(local, tz, &self.inner);
+        if floor == self.inner {
+            return floor.into();
+        }
+        // the same local boundary can come again after this datetime on fall-back transition
+        let ceil = match local.and_local_timezone(tz) {
+            LocalResult::Ambiguous(_, latest) if self.inner < latest => latest,
+            _ => _resolve_ceil(local + unit._delta(), tz, &self.inner),
+        };
+        if self.inner - floor < ceil - self.inner {
+            floor.into()
+        } else {
+            ceil.into()
+        }
+    }
+}
+
+/// Resolve a local datetime to the latest instant which is not after the pivot if possible.
+fn _resolve_floor(
+    local: chrono::NaiveDateTime,
+    tz: Tz,
+    pivot: &chrono::DateTime<Tz>,
+) -> chrono::DateTime<Tz> {
+    match local.and_local_timezone(tz) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, latest) => {
+            if &latest <= pivot {
+                latest
+            } else {
+                earliest
+            }
+        }
+        LocalResult::None => _resolve_gap(local, tz),
+    }
+}
+
+/// Resolve a local datetime to the earliest instant which is after the pivot if possible.
+fn _resolve_ceil(
+    local: chrono::NaiveDateTime,
+    tz: Tz,
+    pivot: &chrono::DateTime<Tz>,
+) -> chrono::DateTime<Tz> {
+    match local.and_local_timezone(tz) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, latest) => {
+            if pivot < &earliest {
+                earliest
+            } else {
+                latest
+            }
+        }
+        LocalResult::None => _resolve_gap(local, tz),
+    }
+}
+
+/// Resolve a local datetime in a DST gap with the offset before the gap.
+///
+/// For the start of the gap, this is the instant at which the gap ends.
+fn _resolve_gap(local: chrono::NaiveDateTime, tz: Tz) -> chrono::DateTime<Tz> {
+    use chrono::{Offset, TimeZone};
+    let before = tz.offset_from_utc_datetime(&(local - chrono::TimeDelta::days(1)));
+    let utc = local - before.fix();
+    tz.from_utc_datetime(&utc)
 }
 
 #[cfg(test)]
@@ -602,4 +859,292 @@ mod tests {
 
         assert_eq!(tested, s);
     }
+
+    #[rstest]
+    #[case::eom(
+        "2023-01-31T10:00:00+09:00[Asia/Tokyo]",
+        "1M",
+        "2023-02-28T10:00:00+09:00[Asia/Tokyo]"
+    )]
+    #[case::eom_leap(
+        "2024-02-29T10:00:00+09:00[Asia/Tokyo]",
+        "1Y",
+        "2025-02-28T10:00:00+09:00[Asia/Tokyo]"
+    )]
+    #[case::negative(
+        "2023-03-31T10:00:00+09:00[Asia/Tokyo]",
+        "-1M",
+        "2023-02-28T10:00:00+09:00[Asia/Tokyo]"
+    )]
+    #[case::across_dst(
+        "2023-03-01T09:00:00-05:00[America/New_York]",
+        "1M",
+        "2023-04-01T09:00:00-04:00[America/New_York]"
+    )]
+    #[case::ambiguous_takes_earliest(
+        "2022-11-05T01:30:00-04:00[America/New_York]",
+        "1Y",
+        "2023-11-05T01:30:00-04:00[America/New_York]"
+    )]
+    #[case::ambiguous_takes_earliest(
+        "2022-11-05T01:30:00-04:00[America/New_York]",
+        "1Y",
+        "2023-11-05T05:30:00Z"
+    )]
+    fn test_add_tenor(#[case] dt: &str, #[case] tenor: &str, #[case] expected: &str) {
+        let dt = DateTime::from_str(dt).unwrap();
+        let tenor = Tenor::from_str(tenor).unwrap();
+        let expected = DateTime::from_str(expected).unwrap();
+
+        let tested = dt.add_tenor(tenor).unwrap();
+
+        assert_eq!(tested, expected);
+        assert_eq!(tested.time(), dt.time());
+        assert_eq!(tested.timezone(), dt.timezone());
+    }
+
+    #[rstest]
+    #[case::spring_forward_gap("2022-03-12T02:30:00-05:00[America/New_York]", "1Y")]
+    #[case::spring_forward_gap("2023-02-12T02:30:00-05:00[America/New_York]", "1M")]
+    fn test_add_tenor_err(#[case] dt: &str, #[case] tenor: &str) {
+        let dt = DateTime::from_str(dt).unwrap();
+        let tenor = Tenor::from_str(tenor).unwrap();
+
+        let tested = dt.add_tenor(tenor);
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    #[case::single("2023-06-01T12:00:00", "America/New_York", "2023-06-01T12:00:00-04:00")]
+    #[case::fixed("2023-11-05T01:30:00", "-05:00", "2023-11-05T01:30:00-05:00")]
+    fn test_new(#[case] local: &str, #[case] tz: &str, #[case] expected: &str) {
+        let local = local.parse().unwrap();
+        let tz = Tz::from_str(tz).unwrap();
+        let expected = DateTime::from_str(expected).unwrap();
+
+        for policy in [DstPolicy::Earliest, DstPolicy::Latest, DstPolicy::Reject] {
+            let tested = DateTime::new_with_policy(local, tz, policy).unwrap();
+
+            assert_eq!(tested, expected);
+            assert_eq!(tested.timezone(), tz);
+        }
+        assert_eq!(DateTime::new(local, tz).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::earliest(DstPolicy::Earliest, "2023-11-05T01:30:00-04:00")]
+    #[case::latest(DstPolicy::Latest, "2023-11-05T01:30:00-05:00")]
+    fn test_new_ambiguous(#[case] policy: DstPolicy, #[case] expected: &str) {
+        let local = "2023-11-05T01:30:00".parse().unwrap();
+        let tz = Tz::from_str("America/New_York").unwrap();
+        let expected = DateTime::from_str(expected).unwrap();
+
+        let tested = DateTime::new_with_policy(local, tz, policy).unwrap();
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case::ambiguous("2023-11-05T01:30:00", DstPolicy::Reject)]
+    #[case::nonexistent("2023-03-12T02:30:00", DstPolicy::Earliest)]
+    #[case::nonexistent("2023-03-12T02:30:00", DstPolicy::Latest)]
+    #[case::nonexistent("2023-03-12T02:30:00", DstPolicy::Reject)]
+    fn test_new_err(#[case] local: &str, #[case] policy: DstPolicy) {
+        let local = local.parse().unwrap();
+        let tz = Tz::from_str("America/New_York").unwrap();
+
+        let tested = DateTime::new_with_policy(local, tz, policy);
+
+        assert!(tested.is_err());
+        if policy == DstPolicy::Reject {
+            assert!(DateTime::new(local, tz).is_err());
+        }
+    }
+
+    #[rstest]
+    #[case("2021-01-01T10:42:11+09:00", 9 * 3600)]
+    #[case("2021-01-01T10:42:11Z", 0)]
+    #[case("2021-01-01T10:42:11-05:23", -(5 * 3600 + 23 * 60))]
+    #[case("2021-01-01T10:42:11+09:00[Asia/Tokyo]", 9 * 3600)]
+    #[case("2021-01-01T10:42:11-05:00[America/New_York]", -5 * 3600)]
+    #[case("2021-07-01T10:42:11-04:00[America/New_York]", -4 * 3600)]
+    fn test_written_offset(#[case] s: &str, #[case] expected: i32) {
+        let dt = DateTime::from_str(s).unwrap();
+
+        let tested = dt.written_offset();
+
+        assert_eq!(tested.seconds(), expected);
+        assert_eq!(dt.time(), "10:42:11".parse().unwrap());
+    }
+
+    #[rstest]
+    #[case("2021-01-01T10:42:11+09:00")]
+    #[case("2021-01-01T10:42:11Z")]
+    fn test_written_offset_roundtrip(#[case] s: &str) {
+        use chrono::TimeZone;
+        let dt = DateTime::from_str(s).unwrap();
+        let offset = chrono::Offset::fix(&dt.written_offset());
+
+        let tested = offset
+            .from_local_datetime(&dt.date().and_time(dt.time()))
+            .unwrap();
+
+        assert_eq!(DateTime::from(tested), dt);
+        assert_eq!(
+            tested.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+            s
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "2024-06-01T12:34:56.789+09:00",
+        TimeUnit::Second,
+        "2024-06-01T12:34:56+09:00"
+    )]
+    #[case(
+        "2024-06-01T12:34:56.789+09:00",
+        TimeUnit::Minute,
+        "2024-06-01T12:34:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T12:34:56.789+09:00",
+        TimeUnit::Hour,
+        "2024-06-01T12:00:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T12:34:56.789+09:00",
+        TimeUnit::Day,
+        "2024-06-01T00:00:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T00:00:00+09:00",
+        TimeUnit::Day,
+        "2024-06-01T00:00:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T05:34:56+05:30",
+        TimeUnit::Hour,
+        "2024-06-01T05:00:00+05:30"
+    )]
+    #[case::spring_forward_day(
+        "2024-03-10T15:42:11-04:00[America/New_York]",
+        TimeUnit::Day,
+        "2024-03-10T00:00:00-05:00"
+    )]
+    #[case::fall_back_day(
+        "2024-11-03T15:42:11-05:00[America/New_York]",
+        TimeUnit::Day,
+        "2024-11-03T00:00:00-04:00"
+    )]
+    #[case::fall_back_first(
+        "2024-11-03T01:42:11-04:00[America/New_York]",
+        TimeUnit::Hour,
+        "2024-11-03T01:00:00-04:00"
+    )]
+    #[case::fall_back_second(
+        "2024-11-03T01:42:11-05:00[America/New_York]",
+        TimeUnit::Hour,
+        "2024-11-03T01:00:00-05:00"
+    )]
+    #[case::midnight_skipped(
+        "2022-09-11T12:00:00-03:00[America/Santiago]",
+        TimeUnit::Day,
+        "2022-09-11T01:00:00-03:00"
+    )]
+    fn test_truncate_to(#[case] dt: &str, #[case] unit: TimeUnit, #[case] expected: &str) {
+        let dt = DateTime::from_str(dt).unwrap();
+        let expected = DateTime::from_str(expected).unwrap();
+
+        let tested = dt.truncate_to(unit);
+
+        assert_eq!(tested, expected);
+        assert_eq!(tested.timezone(), dt.timezone());
+        assert_eq!(
+            tested.written_offset().seconds(),
+            expected.written_offset().seconds()
+        );
+        assert!(tested <= dt);
+    }
+
+    #[test]
+    fn test_truncate_to_day_is_local_midnight() {
+        let dt = DateTime::from_str("2024-03-10T22:00:00-04:00[America/New_York]").unwrap();
+
+        let tested = dt.truncate_to(TimeUnit::Day);
+
+        assert_eq!(tested.date(), dt.date());
+        assert_eq!(tested.time(), chrono::NaiveTime::MIN);
+        // 2024-03-11T02:00:00Z for the input, whose UTC midnight would be 2024-03-11T00:00:00Z
+        assert_eq!(tested, "2024-03-10T05:00:00Z".parse().unwrap());
+    }
+
+    #[rstest]
+    #[case(
+        "2024-06-01T10:29:29.999+09:00",
+        TimeUnit::Minute,
+        "2024-06-01T10:29:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T10:29:30+09:00",
+        TimeUnit::Minute,
+        "2024-06-01T10:30:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T10:29:30+09:00",
+        TimeUnit::Hour,
+        "2024-06-01T10:00:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T10:30:00+09:00",
+        TimeUnit::Hour,
+        "2024-06-01T11:00:00+09:00"
+    )]
+    #[case(
+        "2024-06-01T10:00:00+09:00",
+        TimeUnit::Hour,
+        "2024-06-01T10:00:00+09:00"
+    )]
+    #[case(
+        "2024-12-31T23:59:59.5+09:00",
+        TimeUnit::Second,
+        "2025-01-01T00:00:00+09:00"
+    )]
+    #[case(
+        "2024-12-31T12:00:00+09:00",
+        TimeUnit::Day,
+        "2025-01-01T00:00:00+09:00"
+    )]
+    // 23 hours day. 11 hours have passed at local noon.
+    #[case::spring_forward_day(
+        "2024-03-10T12:00:00-04:00[America/New_York]",
+        TimeUnit::Day,
+        "2024-03-10T00:00:00-05:00"
+    )]
+    // 25 hours day. 13 hours have passed at local noon.
+    #[case::fall_back_day(
+        "2024-11-03T12:00:00-05:00[America/New_York]",
+        TimeUnit::Day,
+        "2024-11-04T00:00:00-05:00"
+    )]
+    #[case::fall_back_first(
+        "2024-11-03T01:45:00-04:00[America/New_York]",
+        TimeUnit::Hour,
+        "2024-11-03T01:00:00-05:00"
+    )]
+    #[case::fall_back_second(
+        "2024-11-03T01:15:00-05:00[America/New_York]",
+        TimeUnit::Hour,
+        "2024-11-03T01:00:00-05:00"
+    )]
+    fn test_round_to(#[case] dt: &str, #[case] unit: TimeUnit, #[case] expected: &str) {
+        let dt = DateTime::from_str(dt).unwrap();
+        let expected = DateTime::from_str(expected).unwrap();
+
+        let tested = dt.round_to(unit);
+
+        assert_eq!(tested, expected);
+        assert_eq!(tested.timezone(), dt.timezone());
+    }
 }
@@ -21,6 +21,29 @@ impl chrono::Offset for TzOffset {
     }
 }
 
+//
+// methods
+//
+impl TzOffset {
+    /// Offset from UTC in seconds. Positive for east of UTC.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::TimeZone;
+    /// use qchrono::timepoint::{Tz, TzOffset};
+    ///
+    /// let tz: Tz = "+09:00".parse().unwrap();
+    /// let offset: TzOffset = tz.offset_from_utc_datetime(&chrono::NaiveDateTime::MIN);
+    ///
+    /// assert_eq!(offset.seconds(), 9 * 3600);
+    /// ```
+    #[inline]
+    pub fn seconds(&self) -> i32 {
+        use chrono::Offset;
+        self.fix().local_minus_utc()
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Tz
 // -----------------------------------------------------------------------------
@@ -144,6 +167,26 @@ mod tests {
     #[test]
     fn test() {}
 
+    #[rstest]
+    #[case("Z", 0)]
+    #[case("UTC", 0)]
+    #[case("+09:00", 9 * 3600)]
+    #[case("-05:30", -(5 * 3600 + 30 * 60))]
+    #[case("Asia/Tokyo", 9 * 3600)]
+    #[case("America/New_York", -5 * 3600)]
+    fn test_tz_offset_seconds(#[case] tz: &str, #[case] expected: i32) {
+        use chrono::TimeZone;
+        let tz = Tz::from_str(tz).unwrap();
+        let utc = chrono::NaiveDate::from_ymd_opt(2021, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        let tested = tz.offset_from_utc_datetime(&utc).seconds();
+
+        assert_eq!(tested, expected);
+    }
+
     #[rstest]
     #[case::ok("Z", Some(Tz::Utc))]
     #[case::ok("+09:00", chrono::FixedOffset::east_opt(9 * 3600).map(Into::into))]
@@ -1,5 +1,5 @@
 mod container;
 mod error;
 
-pub use container::FlatDict;
+pub use container::{FlatDict, MissingPolicy};
 pub use error::Error;
@@ -1,9 +1,30 @@
-use std::{borrow::Borrow, cmp::Ordering};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    io::Read,
+    marker::PhantomData,
+    ops::{Add, Bound, RangeBounds, Sub},
+};
 
 use itertools::Itertools;
 
 use super::Error;
 
+// -----------------------------------------------------------------------------
+// MissingPolicy
+// -----------------------------------------------------------------------------
+/// Policy for [`FlatDict::zip_with`] to handle keys which exist only in one of the dictionaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingPolicy {
+    /// Keep only keys which exist in both of the dictionaries.
+    Inner,
+    /// Keep all keys and fill a missing value with the value at the previous key.
+    /// Keys before the first key of the other dictionary are dropped because there are no values to carry.
+    ForwardFill,
+    /// Fail if key sets of the dictionaries differ.
+    Error,
+}
+
 // -----------------------------------------------------------------------------
 // FlatDict
 // -----------------------------------------------------------------------------
@@ -140,6 +161,127 @@ impl<K, V> FlatDict<K, V> {
     }
 }
 
+//
+// json
+//
+impl<K, V> FlatDict<K, V> {
+    /// Read a dictionary from a JSON array of `{"key": ..., "value": ...}` objects in a streaming manner.
+    ///
+    /// Unlike [`serde::Deserialize`], which collects all elements and sorts them,
+    /// elements are inserted one by one and keys must be strictly increasing in the input.
+    /// Hence no intermediate [`serde_json::Value`] or buffer of elements is created.
+    /// Since the reader is read in small pieces, wrap it with [`std::io::BufReader`] if it is unbuffered, e.g. a file.
+    ///
+    /// # Errors
+    /// - [`Error::AtIndex`] with [`Error::Unordered`], [`Error::Duplicated`] or [`Error::Unsortable`]
+    ///   if the key of the element at the index is not greater than the previous one
+    /// - [`Error::Json`] if the input is not a valid JSON array of key-value pairs
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::{Error, FlatDict};
+    ///
+    /// let json = r#"[{"key": 1, "value": 0.1}, {"key": 2, "value": 0.2}]"#;
+    /// let dict = FlatDict::<i32, f64>::from_json_reader(json.as_bytes()).unwrap();
+    /// assert_eq!(dict.keys(), &[1, 2]);
+    ///
+    /// let json = r#"[{"key": 2, "value": 0.2}, {"key": 1, "value": 0.1}]"#;
+    /// let err = FlatDict::<i32, f64>::from_json_reader(json.as_bytes()).unwrap_err();
+    /// assert!(matches!(err, Error::AtIndex { index: 1, .. }));
+    /// ```
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self, Error>
+    where
+        K: serde::de::DeserializeOwned + PartialOrd,
+        V: serde::de::DeserializeOwned,
+    {
+        use serde::de::DeserializeSeed;
+
+        let mut failure = None;
+        let seed = _StreamSeed {
+            failure: &mut failure,
+            _marker: PhantomData,
+        };
+        let mut de = serde_json::Deserializer::from_reader(reader);
+        let res = seed
+            .deserialize(&mut de)
+            .and_then(|dict| de.end().map(|_| dict));
+        match (res, failure) {
+            (_, Some(err)) => Err(err),
+            (Ok(dict), None) => Ok(dict),
+            (Err(err), None) => Err(Error::Json(err.to_string())),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct _Item<K, V> {
+    key: K,
+    value: V,
+}
+
+/// Deserializes a JSON array into [`FlatDict`] element by element.
+///
+/// Order violations are kept in `failure` to be reported as [`Error`] rather than a message.
+struct _StreamSeed<'a, K, V> {
+    failure: &'a mut Option<Error>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<'de, K, V> serde::de::DeserializeSeed<'de> for _StreamSeed<'_, K, V>
+where
+    K: serde::Deserialize<'de> + PartialOrd,
+    V: serde::Deserialize<'de>,
+{
+    type Value = FlatDict<K, V>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, K, V> serde::de::Visitor<'de> for _StreamSeed<'_, K, V>
+where
+    K: serde::Deserialize<'de> + PartialOrd,
+    V: serde::Deserialize<'de>,
+{
+    type Value = FlatDict<K, V>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of key-value pairs with strictly increasing keys")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut ks: Vec<K> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        let mut vs: Vec<V> = Vec::with_capacity(ks.capacity());
+        while let Some(_Item { key, value }) = seq.next_element::<_Item<K, V>>()? {
+            let err = match ks.last().map(|last| last.partial_cmp(&key)) {
+                None | Some(Some(Ordering::Less)) => None,
+                Some(None) => Some(Error::Unsortable),
+                Some(Some(Ordering::Greater)) => Some(Error::Unordered),
+                Some(Some(Ordering::Equal)) => Some(Error::Duplicated),
+            };
+            if let Some(err) = err {
+                let err = Error::AtIndex {
+                    index: ks.len(),
+                    source: Box::new(err),
+                };
+                let msg = err.to_string();
+                *self.failure = Some(err);
+                return Err(serde::de::Error::custom(msg));
+            }
+            ks.push(key);
+            vs.push(value);
+        }
+        Ok(FlatDict { ks, vs })
+    }
+}
+
 //
 // methods
 //
@@ -184,6 +326,24 @@ impl<K, V> FlatDict<K, V> {
         self.ks.is_empty()
     }
 
+    /// Get the pair with the smallest key.
+    ///
+    /// This returns [None] if the dictionary is empty.
+    /// To access it without [Option], make the dictionary [`crate::size_ensured::NonEmpty`]
+    /// via [`crate::size_ensured::RequireMinSize`].
+    #[inline]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.ks.first().zip(self.vs.first())
+    }
+
+    /// Get the pair with the largest key.
+    ///
+    /// This returns [None] if the dictionary is empty.
+    #[inline]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.ks.last().zip(self.vs.last())
+    }
+
     #[inline]
     pub fn at(&self, idx: usize) -> Option<(&K, &V)> {
         self.ks
@@ -251,16 +411,330 @@ impl<K, V> FlatDict<K, V> {
         }
     }
 
+    /// Get the pair with the largest key which is less than or equal to the given key.
+    ///
+    /// This returns [None] if all keys are greater than the given key or they are not comparable.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1., 2., 4.], vec!["a", "b", "c"]).unwrap();
+    ///
+    /// assert_eq!(dict.floor(&2.), Some((&2., &"b")));
+    /// assert_eq!(dict.floor(&3.), Some((&2., &"b")));
+    /// assert_eq!(dict.floor(&0.), None);
+    /// ```
+    #[inline]
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: PartialOrd,
+    {
+        let idx = self.ks.partition_point(|k| k.borrow() <= key);
+        self.at(idx.checked_sub(1)?)
+    }
+
+    /// Get the pair with the smallest key which is greater than or equal to the given key.
+    ///
+    /// This returns [None] if all keys are less than the given key or they are not comparable.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1., 2., 4.], vec!["a", "b", "c"]).unwrap();
+    ///
+    /// assert_eq!(dict.ceil(&2.), Some((&2., &"b")));
+    /// assert_eq!(dict.ceil(&3.), Some((&4., &"c")));
+    /// assert_eq!(dict.ceil(&5.), None);
+    /// ```
+    #[inline]
+    pub fn ceil<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: PartialOrd,
+    {
+        let idx = self.ks.partition_point(|k| k.borrow() < key);
+        self.at(idx).filter(|(k, _)| key <= (*k).borrow())
+    }
+
+    /// Get the pair with the key nearest to the given key.
+    ///
+    /// Distance between keys is measured by subtraction.
+    /// If the given key is just in the middle of two keys, the smaller one is returned.
+    /// This returns [None] if the dictionary is empty or keys are not comparable with the given key.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1., 2., 4.], vec!["a", "b", "c"]).unwrap();
+    ///
+    /// assert_eq!(dict.nearest(&0.), Some((&1., &"a")));
+    /// assert_eq!(dict.nearest(&3.5), Some((&4., &"c")));
+    /// assert_eq!(dict.nearest(&3.), Some((&2., &"b")));
+    /// ```
+    pub fn nearest<Q, D>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: PartialOrd + Clone + Sub<Output = D>,
+        D: PartialOrd,
+    {
+        match (self.floor(key), self.ceil(key)) {
+            (Some(lo), Some(hi)) => {
+                let to_lo = key.clone() - lo.0.borrow().clone();
+                let to_hi = hi.0.borrow().clone() - key.clone();
+                if to_lo <= to_hi {
+                    Some(lo)
+                } else {
+                    Some(hi)
+                }
+            }
+            (lo, hi) => lo.or(hi),
+        }
+    }
+
+    /// Clone pairs whose keys are in the given range into a new dictionary.
+    ///
+    /// Inclusivity of each end follows the range, e.g. `a..b` keeps keys `k` with `a <= k < b`
+    /// and `a..=b` keeps keys with `a <= k <= b`. Order of keys is preserved.
+    /// The result is empty if no key is in the range.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1, 2, 3, 4], vec!["a", "b", "c", "d"]).unwrap();
+    ///
+    /// assert_eq!(dict.slice(2..4).keys(), &[2, 3]);
+    /// assert_eq!(dict.slice(2..=4).keys(), &[2, 3, 4]);
+    /// assert_eq!(dict.slice(..2).values(), &["a"]);
+    /// ```
+    pub fn slice<Q, R>(&self, range: R) -> Self
+    where
+        K: Borrow<Q> + Clone,
+        V: Clone,
+        Q: PartialOrd + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let lo = match range.start_bound() {
+            Bound::Included(s) => self.ks.partition_point(|k| k.borrow() < s),
+            Bound::Excluded(s) => self.ks.partition_point(|k| k.borrow() <= s),
+            Bound::Unbounded => 0,
+        };
+        let hi = match range.end_bound() {
+            Bound::Included(e) => self.ks.partition_point(|k| k.borrow() <= e),
+            Bound::Excluded(e) => self.ks.partition_point(|k| k.borrow() < e),
+            Bound::Unbounded => self.len(),
+        }
+        .max(lo);
+        FlatDict {
+            ks: self.ks[lo..hi].to_vec(),
+            vs: self.vs[lo..hi].to_vec(),
+        }
+    }
+
+    /// Split into two dictionaries at the given key.
+    ///
+    /// The first one has keys less than `at` and the second one has keys greater than or equal to `at`.
+    /// Hence a pair whose key is exactly `at` goes to the second one.
+    /// This is equivalent to `(self.slice(..at), self.slice(at..))`.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1, 2, 3, 4], vec!["a", "b", "c", "d"]).unwrap();
+    ///
+    /// let (left, right) = dict.split_at(&3);
+    /// assert_eq!(left.keys(), &[1, 2]);
+    /// assert_eq!(right.keys(), &[3, 4]);
+    /// ```
+    pub fn split_at<Q>(&self, at: &Q) -> (Self, Self)
+    where
+        K: Borrow<Q> + Clone,
+        V: Clone,
+        Q: PartialOrd + ?Sized,
+    {
+        let idx = self.ks.partition_point(|k| k.borrow() < at);
+        let (lks, rks) = self.ks.split_at(idx);
+        let (lvs, rvs) = self.vs.split_at(idx);
+        (
+            FlatDict {
+                ks: lks.to_vec(),
+                vs: lvs.to_vec(),
+            },
+            FlatDict {
+                ks: rks.to_vec(),
+                vs: rvs.to_vec(),
+            },
+        )
+    }
+
+    /// Combine values of two dictionaries on the union of their keys.
+    ///
+    /// How keys which exist only in one side are handled is controlled by `on_missing`.
+    /// See [`MissingPolicy`] for details.
+    ///
+    /// # Errors
+    /// - [`Error::Unsortable`] if keys of two dictionaries are not comparable.
+    /// - [`Error::KeyMismatch`] if key sets differ and `on_missing` is [`MissingPolicy::Error`].
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::{FlatDict, MissingPolicy};
+    ///
+    /// let lhs = FlatDict::with_data(vec![1, 2, 4], vec![10, 20, 40]).unwrap();
+    /// let rhs = FlatDict::with_data(vec![2, 3, 4], vec![2, 3, 4]).unwrap();
+    ///
+    /// let inner = lhs.zip_with(&rhs, |l, r| l - r, MissingPolicy::Inner).unwrap();
+    /// assert_eq!(inner.keys(), &[2, 4]);
+    /// assert_eq!(inner.values(), &[18, 36]);
+    ///
+    /// let ffill = lhs.zip_with(&rhs, |l, r| l - r, MissingPolicy::ForwardFill).unwrap();
+    /// assert_eq!(ffill.keys(), &[2, 3, 4]);
+    /// assert_eq!(ffill.values(), &[18, 17, 36]);
+    ///
+    /// assert!(lhs.zip_with(&rhs, |l, r| l - r, MissingPolicy::Error).is_err());
+    /// ```
+    pub fn zip_with<U, W, F>(
+        &self,
+        other: &FlatDict<K, U>,
+        mut f: F,
+        on_missing: MissingPolicy,
+    ) -> Result<FlatDict<K, W>, Error>
+    where
+        K: PartialOrd + Clone,
+        F: FnMut(&V, &U) -> W,
+    {
+        let (mut i, mut j) = (0, 0);
+        let mut ks = Vec::with_capacity(self.len().max(other.len()));
+        let mut vs = Vec::with_capacity(self.len().max(other.len()));
+        while i < self.len() || j < other.len() {
+            let ord = match (self.ks.get(i), other.ks.get(j)) {
+                (Some(l), Some(r)) => l.partial_cmp(r).ok_or(Error::Unsortable)?,
+                (Some(_), None) => Ordering::Less,
+                (None, _) => Ordering::Greater,
+            };
+            let (key, li, rj) = match ord {
+                Ordering::Less => (&self.ks[i], Some(i), None),
+                Ordering::Greater => (&other.ks[j], None, Some(j)),
+                Ordering::Equal => (&self.ks[i], Some(i), Some(j)),
+            };
+            if li.is_some() {
+                i += 1;
+            }
+            if rj.is_some() {
+                j += 1;
+            }
+            let (li, rj) = match (on_missing, li, rj) {
+                (_, Some(li), Some(rj)) => (li, rj),
+                (MissingPolicy::Inner, _, _) => continue,
+                (MissingPolicy::Error, _, _) => return Err(Error::KeyMismatch),
+                (MissingPolicy::ForwardFill, li, rj) => {
+                    // 'i' and 'j' already point to the next of the last consumed keys
+                    match (li.or(i.checked_sub(1)), rj.or(j.checked_sub(1))) {
+                        (Some(li), Some(rj)) => (li, rj),
+                        _ => continue,
+                    }
+                }
+            };
+            ks.push(key.clone());
+            vs.push(f(&self.vs[li], &other.vs[rj]));
+        }
+        Ok(FlatDict { ks, vs })
+    }
+
+    /// Iterate over pairs of consecutive entries in ascending order of keys.
+    ///
+    /// This yields `len() - 1` items, hence nothing if the dictionary has less than two entries.
+    #[inline]
+    pub fn windows(&self) -> impl Iterator<Item = ((&K, &V), (&K, &V))> {
+        self.into_iter().tuple_windows()
+    }
+
+    /// Create a dictionary from consecutive entries.
+    ///
+    /// Each value is `f(prev, next)` for a pair from [`FlatDict::windows`]
+    /// and it is keyed on the key of `next`. Hence the first key is not contained in the result.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::flat_dict::FlatDict;
+    ///
+    /// let dict = FlatDict::with_data(vec![1, 2, 4], vec![10, 20, 40]).unwrap();
+    ///
+    /// let diffs = dict.deltas(|(_, prev), (_, next)| next - prev);
+    /// assert_eq!(diffs.keys(), &[2, 4]);
+    /// assert_eq!(diffs.values(), &[10, 20]);
+    /// ```
+    pub fn deltas<W, F>(&self, mut f: F) -> FlatDict<K, W>
+    where
+        K: Clone,
+        F: FnMut((&K, &V), (&K, &V)) -> W,
+    {
+        let (ks, vs) = self
+            .windows()
+            .map(|(prev, next)| (next.0.clone(), f(prev, next)))
+            .unzip();
+        FlatDict { ks, vs }
+    }
+
+    /// Map each value keeping keys.
+    #[inline]
+    pub fn map_values<U, F>(self, f: F) -> FlatDict<K, U>
+    where
+        F: FnMut(V) -> U,
+    {
+        FlatDict {
+            ks: self.ks,
+            vs: self.vs.into_iter().map(f).collect(),
+        }
+    }
+
     #[inline]
     pub fn destruct(self) -> (Vec<K>, Vec<V>) {
         (self.ks, self.vs)
     }
 }
 
+//
+// operators
+//
+/// Element-wise addition on common keys, i.e. [`MissingPolicy::Inner`] is used.
+impl<K, V> Add for &FlatDict<K, V>
+where
+    K: PartialOrd + Clone,
+    V: Clone + Add<Output = V>,
+{
+    type Output = Result<FlatDict<K, V>, Error>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |l, r| l.clone() + r.clone(), MissingPolicy::Inner)
+    }
+}
+
+/// Element-wise subtraction on common keys, i.e. [`MissingPolicy::Inner`] is used.
+impl<K, V> Sub for &FlatDict<K, V>
+where
+    K: PartialOrd + Clone,
+    V: Clone + Sub<Output = V>,
+{
+    type Output = Result<FlatDict<K, V>, Error>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |l, r| l.clone() - r.clone(), MissingPolicy::Inner)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::f64;
 
+    use chrono::NaiveDate;
     use rstest::rstest;
 
     use super::*;
@@ -439,6 +913,101 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_from_json_reader() {
+        let json = r#"[
+            {"key": "2021-01-04", "value": 0.1},
+            {"key": "2021-01-05", "value": 0.2},
+            {"key": "2021-01-06", "value": 0.3}
+        ]"#;
+
+        let dict = FlatDict::<NaiveDate, f64>::from_json_reader(json.as_bytes()).unwrap();
+
+        assert_eq!(
+            dict.keys(),
+            &[
+                NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 1, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 1, 6).unwrap(),
+            ]
+        );
+        assert_eq!(dict.values(), &[0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn test_from_json_reader_empty() {
+        let dict = FlatDict::<NaiveDate, f64>::from_json_reader(" [] ".as_bytes()).unwrap();
+
+        assert!(dict.is_empty());
+    }
+
+    #[rstest]
+    #[case("2021-01-04", 2, Error::Unordered)]
+    #[case("2021-01-05", 2, Error::Duplicated)]
+    fn test_from_json_reader_err_order(
+        #[case] third: &str,
+        #[case] index: usize,
+        #[case] expected: Error,
+    ) {
+        let json = format!(
+            r#"[
+                {{"key": "2021-01-01", "value": 0.1}},
+                {{"key": "2021-01-05", "value": 0.2}},
+                {{"key": "{third}", "value": 0.3}},
+                {{"key": "2021-01-07", "value": 0.4}}
+            ]"#
+        );
+
+        let err = FlatDict::<NaiveDate, f64>::from_json_reader(json.as_bytes()).unwrap_err();
+
+        let Error::AtIndex { index: idx, source } = &err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(*idx, index);
+        assert_eq!(source.to_string(), expected.to_string());
+        assert_eq!(err.to_string(), format!("{expected} at index {index}"));
+    }
+
+    #[rstest]
+    #[case(r#"{"key": "2021-01-01", "value": 0.1}"#)]
+    #[case(r#"[{"key": "2021-01-01", "value": 0.1}"#)]
+    #[case(r#"[{"key": "2021-01-01"}]"#)]
+    #[case(r#"[{"key": "2021-13-01", "value": 0.1}]"#)]
+    #[case(r#"[] []"#)]
+    fn test_from_json_reader_err_json(#[case] json: &str) {
+        let err = FlatDict::<NaiveDate, f64>::from_json_reader(json.as_bytes()).unwrap_err();
+
+        assert!(matches!(err, Error::Json(_)), "unexpected error: {err:?}");
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(1, Some((0, "0")))]
+    #[case(3, Some((0, "0")))]
+    fn test_first(#[case] size: usize, #[case] expected: Option<(usize, &str)>) {
+        let ks = (0..size).collect::<Vec<_>>();
+        let vs = (0..size).map(|i| i.to_string()).collect::<Vec<_>>();
+        let map = FlatDict::with_data(ks, vs).unwrap();
+
+        let res = map.first();
+
+        assert_eq!(res.map(|(k, v)| (*k, v.as_str())), expected);
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(1, Some((0, "0")))]
+    #[case(3, Some((2, "2")))]
+    fn test_last(#[case] size: usize, #[case] expected: Option<(usize, &str)>) {
+        let ks = (0..size).collect::<Vec<_>>();
+        let vs = (0..size).map(|i| i.to_string()).collect::<Vec<_>>();
+        let map = FlatDict::with_data(ks, vs).unwrap();
+
+        let res = map.last();
+
+        assert_eq!(res.map(|(k, v)| (*k, v.as_str())), expected);
+    }
+
     #[rstest]
     #[case(0, 0)]
     #[case(0, 1)]
@@ -576,4 +1145,303 @@ mod tests {
         assert_eq!(empty.interval_index(&x), None);
         assert_eq!(single.interval_index(&x), None);
     }
+
+    #[rstest]
+    #[case::same(vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3], vec![11, 22, 33])]
+    #[case::partial(vec![1, 2, 4], vec![2, 3, 4], vec![2, 4], vec![22, 44])]
+    #[case::disjoint(vec![1, 3], vec![2, 4], vec![], vec![])]
+    #[case::empty(vec![1, 3], vec![], vec![], vec![])]
+    fn test_zip_with_inner(
+        #[case] lks: Vec<i32>,
+        #[case] rks: Vec<i32>,
+        #[case] exp_ks: Vec<i32>,
+        #[case] exp_vs: Vec<i32>,
+    ) {
+        let lhs = FlatDict::with_data(lks.clone(), lks.iter().map(|k| 10 * k).collect()).unwrap();
+        let rhs = FlatDict::with_data(rks.clone(), rks).unwrap();
+
+        let tested = lhs
+            .zip_with(&rhs, |l, r| l + r, MissingPolicy::Inner)
+            .unwrap();
+
+        assert_eq!(tested.keys(), exp_ks.as_slice());
+        assert_eq!(tested.values(), exp_vs.as_slice());
+    }
+
+    #[rstest]
+    #[case::same(vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3], vec![11, 22, 33])]
+    #[case::partial(vec![1, 2, 4], vec![2, 3, 4], vec![2, 3, 4], vec![22, 23, 44])]
+    #[case::disjoint(vec![1, 3], vec![2, 4], vec![2, 3, 4], vec![12, 32, 34])]
+    #[case::rhs_first(vec![2, 4], vec![1, 3], vec![2, 3, 4], vec![21, 23, 43])]
+    #[case::empty(vec![1, 3], vec![], vec![], vec![])]
+    fn test_zip_with_ffill(
+        #[case] lks: Vec<i32>,
+        #[case] rks: Vec<i32>,
+        #[case] exp_ks: Vec<i32>,
+        #[case] exp_vs: Vec<i32>,
+    ) {
+        let lhs = FlatDict::with_data(lks.clone(), lks.iter().map(|k| 10 * k).collect()).unwrap();
+        let rhs = FlatDict::with_data(rks.clone(), rks).unwrap();
+
+        let tested = lhs
+            .zip_with(&rhs, |l, r| l + r, MissingPolicy::ForwardFill)
+            .unwrap();
+
+        assert_eq!(tested.keys(), exp_ks.as_slice());
+        assert_eq!(tested.values(), exp_vs.as_slice());
+    }
+
+    #[rstest]
+    #[case::same(vec![1, 2, 3], vec![1, 2, 3], true)]
+    #[case::both_empty(vec![], vec![], true)]
+    #[case::partial(vec![1, 2, 4], vec![2, 3, 4], false)]
+    #[case::subset(vec![1, 2], vec![1, 2, 3], false)]
+    #[case::empty(vec![1, 3], vec![], false)]
+    fn test_zip_with_error(#[case] lks: Vec<i32>, #[case] rks: Vec<i32>, #[case] ok: bool) {
+        let lhs = FlatDict::with_data(lks.clone(), lks).unwrap();
+        let rhs = FlatDict::with_data(rks.clone(), rks).unwrap();
+
+        let tested = lhs.zip_with(&rhs, |l, r| l + r, MissingPolicy::Error);
+
+        assert_eq!(tested.is_ok(), ok);
+        if let Err(e) = tested {
+            assert!(matches!(e, Error::KeyMismatch));
+        }
+    }
+
+    #[test]
+    fn test_zip_with_unsortable() {
+        let lhs = FlatDict::with_data(vec![1.0, 2.0], vec![1, 2]).unwrap();
+        let rhs = FlatDict::with_data(vec![f64::NAN], vec![1]).unwrap();
+
+        let tested = lhs.zip_with(&rhs, |l, r| l + r, MissingPolicy::Inner);
+
+        assert!(matches!(tested, Err(Error::Unsortable)));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let lhs = FlatDict::with_data(vec![1, 2, 4], vec![1.0, 2.0, 4.0]).unwrap();
+        let rhs = FlatDict::with_data(vec![2, 3, 4], vec![0.5, 0.25, 0.125]).unwrap();
+
+        let added = (&lhs + &rhs).unwrap();
+        let subtracted = (&lhs - &rhs).unwrap();
+
+        assert_eq!(added.keys(), &[2, 4]);
+        assert_eq!(added.values(), &[2.5, 4.125]);
+        assert_eq!(subtracted.keys(), &[2, 4]);
+        assert_eq!(subtracted.values(), &[1.5, 3.875]);
+    }
+
+    #[test]
+    fn test_map_values() {
+        let map = FlatDict::with_data(vec![2, 1], vec!["bb", "a"]).unwrap();
+
+        let tested = map.map_values(|v| v.to_uppercase());
+
+        assert_eq!(tested.keys(), &[1, 2]);
+        assert_eq!(tested.values(), &["A".to_owned(), "BB".to_owned()]);
+    }
+
+    fn datetime_dict() -> FlatDict<chrono::DateTime<chrono::Utc>, i32> {
+        let ks = vec![
+            "2021-01-01T00:00:00Z".parse().unwrap(),
+            "2021-01-02T00:00:00Z".parse().unwrap(),
+            "2021-01-04T00:00:00Z".parse().unwrap(),
+        ];
+        FlatDict::with_sorted(ks, vec![1, 2, 4]).unwrap()
+    }
+
+    #[rstest]
+    #[case("2020-12-31T00:00:00Z", None)]
+    #[case("2021-01-01T00:00:00Z", Some(1))]
+    #[case("2021-01-01T12:00:00Z", Some(1))]
+    #[case("2021-01-02T00:00:00Z", Some(2))]
+    #[case("2021-01-03T23:59:59Z", Some(2))]
+    #[case("2021-01-04T00:00:00Z", Some(4))]
+    #[case("2021-01-05T00:00:00Z", Some(4))]
+    fn test_floor(#[case] key: &str, #[case] expected: Option<i32>) {
+        let dict = datetime_dict();
+        let key: chrono::DateTime<chrono::Utc> = key.parse().unwrap();
+
+        let tested = dict.floor(&key);
+
+        assert_eq!(tested.map(|(_, v)| *v), expected);
+        assert!(tested.map_or(true, |(k, _)| k <= &key));
+    }
+
+    #[rstest]
+    #[case("2020-12-31T00:00:00Z", Some(1))]
+    #[case("2021-01-01T00:00:00Z", Some(1))]
+    #[case("2021-01-01T12:00:00Z", Some(2))]
+    #[case("2021-01-02T00:00:00Z", Some(2))]
+    #[case("2021-01-03T23:59:59Z", Some(4))]
+    #[case("2021-01-04T00:00:00Z", Some(4))]
+    #[case("2021-01-05T00:00:00Z", None)]
+    fn test_ceil(#[case] key: &str, #[case] expected: Option<i32>) {
+        let dict = datetime_dict();
+        let key: chrono::DateTime<chrono::Utc> = key.parse().unwrap();
+
+        let tested = dict.ceil(&key);
+
+        assert_eq!(tested.map(|(_, v)| *v), expected);
+        assert!(tested.map_or(true, |(k, _)| &key <= k));
+    }
+
+    #[rstest]
+    #[case("2020-12-31T00:00:00Z", Some(1))]
+    #[case("2021-01-01T00:00:00Z", Some(1))]
+    #[case("2021-01-01T11:59:59Z", Some(1))]
+    #[case("2021-01-01T12:00:00Z", Some(1))]
+    #[case("2021-01-01T12:00:01Z", Some(2))]
+    #[case("2021-01-02T00:00:00Z", Some(2))]
+    #[case("2021-01-03T00:00:00Z", Some(2))]
+    #[case("2021-01-03T00:00:01Z", Some(4))]
+    #[case("2021-01-04T00:00:00Z", Some(4))]
+    #[case("2021-01-05T00:00:00Z", Some(4))]
+    fn test_nearest(#[case] key: &str, #[case] expected: Option<i32>) {
+        let dict = datetime_dict();
+        let key: chrono::DateTime<chrono::Utc> = key.parse().unwrap();
+
+        let tested = dict.nearest(&key);
+
+        assert_eq!(tested.map(|(_, v)| *v), expected);
+    }
+
+    #[test]
+    fn test_floor_ceil_nearest_empty() {
+        let dict = FlatDict::<f64, i32>::with_sorted(vec![], vec![]).unwrap();
+
+        assert_eq!(dict.floor(&0.), None);
+        assert_eq!(dict.ceil(&0.), None);
+        assert_eq!(dict.nearest(&0.), None);
+    }
+
+    #[test]
+    fn test_floor_ceil_nearest_nan() {
+        let dict = FlatDict::with_sorted(vec![1., 2.], vec![1, 2]).unwrap();
+
+        assert_eq!(dict.floor(&f64::NAN), None);
+        assert_eq!(dict.ceil(&f64::NAN), None);
+        assert_eq!(dict.nearest(&f64::NAN), None);
+    }
+
+    #[rstest]
+    #[case((Bound::Included(2.), Bound::Excluded(4.)), vec![2., 3.])]
+    #[case((Bound::Included(2.), Bound::Included(4.)), vec![2., 3., 4.])]
+    #[case((Bound::Excluded(2.), Bound::Excluded(4.)), vec![3.])]
+    #[case((Bound::Excluded(2.), Bound::Included(4.)), vec![3., 4.])]
+    #[case((Bound::Included(1.5), Bound::Excluded(3.5)), vec![2., 3.])]
+    #[case((Bound::Unbounded, Bound::Excluded(3.)), vec![1., 2.])]
+    #[case((Bound::Included(3.), Bound::Unbounded), vec![3., 4., 5.])]
+    #[case((Bound::Unbounded, Bound::Unbounded), vec![1., 2., 3., 4., 5.])]
+    #[case((Bound::Included(0.), Bound::Excluded(1.)), vec![])]
+    #[case((Bound::Included(6.), Bound::Unbounded), vec![])]
+    #[case((Bound::Included(4.), Bound::Excluded(2.)), vec![])]
+    #[case((Bound::Included(3.), Bound::Excluded(3.)), vec![])]
+    fn test_slice(#[case] range: (Bound<f64>, Bound<f64>), #[case] expected: Vec<f64>) {
+        let dict =
+            FlatDict::with_sorted(vec![1., 2., 3., 4., 5.], vec![10, 20, 30, 40, 50]).unwrap();
+
+        let tested = dict.slice(range);
+
+        assert_eq!(tested.keys(), expected.as_slice());
+        assert_eq!(
+            tested.values(),
+            expected
+                .iter()
+                .map(|k| (k * 10.) as i32)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+    }
+
+    #[test]
+    fn test_slice_datetime() {
+        let dict = datetime_dict();
+        let (from, to): (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>) = (
+            "2021-01-02T00:00:00Z".parse().unwrap(),
+            "2021-01-04T00:00:00Z".parse().unwrap(),
+        );
+
+        let excl = dict.slice(from..to);
+        let incl = dict.slice(from..=to);
+
+        assert_eq!(excl.keys(), &[from]);
+        assert_eq!(excl.values(), &[2]);
+        assert_eq!(incl.keys(), &[from, to]);
+        assert_eq!(incl.values(), &[2, 4]);
+    }
+
+    #[rstest]
+    // on knots
+    #[case(1., vec![], vec![1., 2., 4.])]
+    #[case(2., vec![1.], vec![2., 4.])]
+    #[case(4., vec![1., 2.], vec![4.])]
+    // off knots
+    #[case(0., vec![], vec![1., 2., 4.])]
+    #[case(3., vec![1., 2.], vec![4.])]
+    #[case(5., vec![1., 2., 4.], vec![])]
+    fn test_split_at(#[case] at: f64, #[case] left: Vec<f64>, #[case] right: Vec<f64>) {
+        let dict = FlatDict::with_sorted(vec![1., 2., 4.], vec!["a", "b", "c"]).unwrap();
+
+        let (l, r) = dict.split_at(&at);
+
+        assert_eq!(l.keys(), left.as_slice());
+        assert_eq!(r.keys(), right.as_slice());
+        assert_eq!(
+            l.values().iter().chain(r.values()).collect::<Vec<_>>(),
+            vec![&"a", &"b", &"c"]
+        );
+        assert_eq!((l, r), (dict.slice(..at), dict.slice(at..)));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(2)]
+    #[case(5)]
+    fn test_windows(#[case] size: usize) {
+        let ks: Vec<_> = (0..size).collect();
+        let vs: Vec<_> = (0..size).map(|i| i * 10).collect();
+        let dict = FlatDict::with_sorted(ks, vs).unwrap();
+
+        let tested: Vec<_> = dict.windows().collect();
+
+        assert_eq!(tested.len(), size.saturating_sub(1));
+        for (i, ((k0, v0), (k1, v1))) in tested.into_iter().enumerate() {
+            assert_eq!((*k0, *v0), (i, i * 10));
+            assert_eq!((*k1, *v1), (i + 1, (i + 1) * 10));
+        }
+    }
+
+    #[test]
+    fn test_deltas_forward_rates() {
+        let ts = vec![0.5, 1., 2.];
+        let rates = [0.01f64, 0.02, 0.03];
+        let dfs: Vec<f64> = ts.iter().zip(rates).map(|(t, r)| (-r * t).exp()).collect();
+        let dict = FlatDict::with_sorted(ts, dfs).unwrap();
+
+        let fwds = dict.deltas(|(t0, df0), (t1, df1)| (df0 / df1 - 1.) / (t1 - t0));
+
+        // simple forward rate: (exp(r1 * t1 - r0 * t0) - 1) / (t1 - t0)
+        let expected = [
+            ((0.02f64 * 1. - 0.01 * 0.5).exp() - 1.) / 0.5,
+            ((0.03f64 * 2. - 0.02 * 1.).exp() - 1.) / 1.,
+        ];
+        assert_eq!(fwds.keys(), &[1., 2.]);
+        assert_eq!(fwds.len(), 2);
+        for (tested, expected) in fwds.values().iter().zip(expected) {
+            assert!((tested - expected).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_deltas_short() {
+        let dict = FlatDict::with_sorted(vec![1], vec![10]).unwrap();
+
+        let tested = dict.deltas(|(_, prev), (_, next)| next - prev);
+
+        assert!(tested.is_empty());
+    }
 }
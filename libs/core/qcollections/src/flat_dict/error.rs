@@ -9,6 +9,16 @@ pub enum Error {
     Unordered,
     #[error("Duplicated keys found")]
     Duplicated,
+    #[error("Keys of dictionaries do not match")]
+    KeyMismatch,
     #[error("Size mismatch. keys: {}, values: {}", .keys, .values)]
     SizeMismatch { keys: usize, values: usize },
+    #[error("{source} at index {index}")]
+    AtIndex {
+        index: usize,
+        #[source]
+        source: Box<Error>,
+    },
+    #[error("Failed to parse JSON: {0}")]
+    Json(String),
 }
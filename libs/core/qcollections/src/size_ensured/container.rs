@@ -4,6 +4,8 @@ use std::{
     ops::{Deref, Index, IndexMut},
 };
 
+use crate::flat_dict::FlatDict;
+
 use super::{impls::sealed, Error, SizedContainer, SplitFirst};
 
 // -----------------------------------------------------------------------------
@@ -334,6 +336,109 @@ impl<C, const N: usize> SizeEnsured<C, N> {
     }
 }
 
+impl<T, const N: usize> SizeEnsured<Vec<T>, N> {
+    /// Map each element keeping the size guarantee.
+    ///
+    /// Because mapping does not change the length, the result is ensured to have at least N elements
+    /// without any fallible check.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::size_ensured::{NonEmpty, RequireMinSize};
+    ///
+    /// let data: NonEmpty<Vec<i32>> = vec![1, 2, 3].require_min_size().unwrap();
+    ///
+    /// let mapped: NonEmpty<Vec<String>> = data.map(|x| (x * 10).to_string());
+    ///
+    /// assert_eq!(mapped.first(), "10");
+    /// assert_eq!(mapped.last(), "30");
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> SizeEnsured<Vec<U>, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        SizeEnsured(self.0.into_iter().map(f).collect())
+    }
+}
+
+impl<T, const N: usize> SizeEnsured<Vec<T>, N>
+where
+    Self: sealed::Has<1>,
+{
+    /// Get the first element.
+    ///
+    /// Because the vector is ensured to be non-empty, this does not return [Option].
+    #[inline]
+    pub fn first(&self) -> &T {
+        self.0.first().expect("Must have enough elements")
+    }
+
+    /// Get the last element.
+    ///
+    /// Because the vector is ensured to be non-empty, this does not return [Option].
+    #[inline]
+    pub fn last(&self) -> &T {
+        self.0.last().expect("Must have enough elements")
+    }
+}
+
+impl<K, V, const N: usize> SizeEnsured<FlatDict<K, V>, N> {
+    /// Map each value keeping keys and the size guarantee.
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::{flat_dict::FlatDict, size_ensured::{NonEmpty, RequireMinSize}};
+    ///
+    /// let data = FlatDict::with_data(vec![1, 2], vec![0.5, 0.25]).unwrap();
+    /// let data: NonEmpty<_> = data.require_min_size().unwrap();
+    ///
+    /// let mapped = data.map_values(|v| 1.0 / v);
+    ///
+    /// assert_eq!(mapped.first(), (&1, &2.0));
+    /// assert_eq!(mapped.last(), (&2, &4.0));
+    /// ```
+    #[inline]
+    pub fn map_values<U, F>(self, f: F) -> SizeEnsured<FlatDict<K, U>, N>
+    where
+        F: FnMut(V) -> U,
+    {
+        SizeEnsured(self.0.map_values(f))
+    }
+}
+
+impl<K, V, const N: usize> SizeEnsured<FlatDict<K, V>, N>
+where
+    Self: sealed::Has<1>,
+{
+    /// Get the pair with the smallest key.
+    ///
+    /// Because the dictionary is ensured to be non-empty, this does not return [Option].
+    ///
+    /// # Example
+    /// ```
+    /// use qcollections::{flat_dict::FlatDict, size_ensured::{NonEmpty, RequireMinSize}};
+    ///
+    /// let data = FlatDict::with_data(vec![2, 1, 3], vec!["b", "a", "c"]).unwrap();
+    /// let data: NonEmpty<_> = data.require_min_size().unwrap();
+    ///
+    /// assert_eq!(data.first(), (&1, &"a"));
+    /// assert_eq!(data.last(), (&3, &"c"));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> (&K, &V) {
+        self.0.first().expect("Must have enough elements")
+    }
+
+    /// Get the pair with the largest key.
+    ///
+    /// Because the dictionary is ensured to be non-empty, this does not return [Option].
+    #[inline]
+    pub fn last(&self) -> (&K, &V) {
+        self.0.last().expect("Must have enough elements")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,4 +545,65 @@ mod tests {
         assert_eq!(fst2, &6);
         assert_eq!(&rest.map(add_ret).collect::<Vec<_>>(), &[8, 9]);
     }
+
+    #[test]
+    fn test_flat_dict_first_last() {
+        let data = FlatDict::with_data(vec![2, 1, 3], vec!["b", "a", "c"]).unwrap();
+        let data: SizeEnsured<_, 2> = data.require_min_size().unwrap();
+
+        assert_eq!(data.first(), (&1, &"a"));
+        assert_eq!(data.last(), (&3, &"c"));
+    }
+
+    #[test]
+    fn test_flat_dict_first_last_single() {
+        let data = FlatDict::with_data(vec![1], vec!["a"]).unwrap();
+        let data: NonEmpty<_> = data.require_min_size().unwrap();
+
+        assert_eq!(data.first(), (&1, &"a"));
+        assert_eq!(data.last(), (&1, &"a"));
+    }
+
+    #[test]
+    fn test_flat_dict_require_non_empty_err() {
+        let data = FlatDict::<i32, &str>::with_data(vec![], vec![]).unwrap();
+
+        let tested: Result<NonEmpty<_>, _> = data.require_min_size();
+
+        assert!(tested.is_err());
+        let err = tested.unwrap_err();
+        assert_eq!(err.required, 1);
+        assert_eq!(err.actual, 0);
+    }
+
+    #[test]
+    fn test_map() {
+        let data: SizeEnsured<Vec<i32>, 2> = vec![1, 2, 3].require_min_size().unwrap();
+
+        let tested: SizeEnsured<Vec<f64>, 2> = data.map(|x| x as f64 / 2.0);
+
+        assert_eq!(tested.inner(), &vec![0.5, 1.0, 1.5]);
+        assert_eq!(tested.first(), &0.5);
+        assert_eq!(tested.last(), &1.5);
+    }
+
+    #[test]
+    fn test_vec_first_last() {
+        let data: NonEmpty<Vec<i32>> = vec![3].require_min_size().unwrap();
+
+        assert_eq!(data.first(), &3);
+        assert_eq!(data.last(), &3);
+    }
+
+    #[test]
+    fn test_map_values() {
+        let data = FlatDict::with_data(vec![2, 1], vec!["bb", "a"]).unwrap();
+        let data: SizeEnsured<_, 2> = data.require_min_size().unwrap();
+
+        let tested = data.map_values(str::len);
+
+        assert_eq!(tested.keys(), &[1, 2]);
+        assert_eq!(tested.values(), &[1, 2]);
+        assert_eq!(tested.first(), (&1, &1));
+    }
 }
@@ -1,7 +1,13 @@
+mod cubic_spline;
 mod lerp;
+mod loglerp;
 mod pwconst;
+mod resample;
 mod traits;
 
+pub use cubic_spline::{CubicSpline1d, CubicSpline1dBuilder, SplineBoundary};
 pub use lerp::{Lerp1d, Lerp1dBuilder};
+pub use loglerp::{LogLerp1d, LogLerp1dBuilder};
 pub use pwconst::{Pwconst1d, Pwconst1dBuilder};
+pub use resample::{resample, FillPolicy};
 pub use traits::{Interp1d, Interp1dBuilder, RebuildableInterp1d};
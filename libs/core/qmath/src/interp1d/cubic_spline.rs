@@ -0,0 +1,414 @@
+use anyhow::{anyhow, ensure, Context};
+use qcollections::{
+    flat_dict::FlatDict,
+    size_ensured::{RequireMinSize, SizeEnsured},
+};
+
+use crate::num::{DerX1d, DerXX1d, Real, Vector};
+
+use super::{Interp1d, Interp1dBuilder, RebuildableInterp1d};
+
+// -----------------------------------------------------------------------------
+// SplineBoundary
+// -----------------------------------------------------------------------------
+/// Boundary condition of cubic spline.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SplineBoundary<V> {
+    /// Second derivatives at both ends are zero.
+    Natural,
+    /// First derivatives at both ends are given.
+    Clamped { left: V, right: V },
+}
+
+// -----------------------------------------------------------------------------
+// CubicSpline1d
+// -----------------------------------------------------------------------------
+/// Cubic spline interpolation.
+///
+/// The interpolant is a piecewise cubic polynomial which is twice continuously differentiable.
+/// Outside of the knots, the cubic polynomial of the nearest interval is used.
+///
+/// # Example
+/// ```
+/// use qcollections::flat_dict::FlatDict;
+/// use qmath::interp1d::{CubicSpline1dBuilder, Interp1d, Interp1dBuilder, SplineBoundary};
+///
+/// let data = FlatDict::with_data(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0]).unwrap();
+/// let spline = CubicSpline1dBuilder::new(SplineBoundary::Natural)
+///     .build(data)
+///     .unwrap();
+///
+/// assert_eq!(spline.interp(&1.0).unwrap(), 1.0);
+/// approx::assert_abs_diff_eq!(spline.interp(&0.5).unwrap(), 0.6875, epsilon = 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline1d<X, V> {
+    data: SizeEnsured<FlatDict<X, V>, 2>,
+    boundary: SplineBoundary<V>,
+    // second derivatives at knots
+    der_xx: Vec<V>,
+}
+
+impl<X, V> CubicSpline1d<X, V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    /// Create a cubic spline which passes through all points in `data`.
+    ///
+    /// # Errors
+    /// Returns an error if the linear system for the spline is singular,
+    /// which does not happen as long as knots are strictly increasing.
+    pub fn new(
+        data: SizeEnsured<FlatDict<X, V>, 2>,
+        boundary: SplineBoundary<V>,
+    ) -> anyhow::Result<Self> {
+        let der_xx = _second_derivatives(&data, &boundary)?;
+        Ok(CubicSpline1d {
+            data,
+            boundary,
+            der_xx,
+        })
+    }
+
+    #[inline]
+    pub fn boundary(&self) -> &SplineBoundary<V> {
+        &self.boundary
+    }
+
+    /// Returns `(index, h, a, b)` where `a` and `b` are relative positions
+    /// measured from right and left knots respectively.
+    #[inline]
+    fn _locate(&self, x: &X) -> anyhow::Result<(usize, X, X, X)> {
+        let index = self.data.interval_index(x);
+        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let (xl, _) = self.data.at(index).unwrap();
+        let (xr, _) = self.data.at(index + 1).unwrap();
+
+        let h = xr.clone() - xl;
+        let a = (xr.clone() - x) / &h;
+        let b = (x.clone() - xl) / &h;
+        Ok((index, h, a, b))
+    }
+}
+
+/// Solve the tridiagonal system for second derivatives at knots with Thomas algorithm.
+fn _second_derivatives<X, V>(
+    data: &FlatDict<X, V>,
+    boundary: &SplineBoundary<V>,
+) -> anyhow::Result<Vec<V>>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    let xs = data.keys();
+    let ys = data.values();
+    let n = xs.len();
+    let two = X::nearest_value_of_f64(2.);
+    let six = X::nearest_value_of_f64(6.);
+
+    let hs: Vec<X> = xs.windows(2).map(|w| w[1].clone() - &w[0]).collect();
+    let slopes: Vec<V> = ys
+        .windows(2)
+        .zip(&hs)
+        .map(|(w, h)| (w[1].clone() - &w[0]) / h)
+        .collect();
+
+    // i-th row: sub[i] * m[i-1] + diag[i] * m[i] + sup[i] * m[i+1] = rhs[i]
+    let mut sub = vec![X::zero(); n];
+    let mut diag = vec![X::zero(); n];
+    let mut sup = vec![X::zero(); n];
+    let mut rhs = vec![V::zero(); n];
+    for i in 1..(n - 1) {
+        sub[i] = hs[i - 1].clone();
+        diag[i] = (hs[i - 1].clone() + &hs[i]) * &two;
+        sup[i] = hs[i].clone();
+        rhs[i] = (slopes[i].clone() - &slopes[i - 1]) * &six;
+    }
+    match boundary {
+        SplineBoundary::Natural => {
+            diag[0] = X::one();
+            diag[n - 1] = X::one();
+        }
+        SplineBoundary::Clamped { left, right } => {
+            diag[0] = hs[0].clone() * &two;
+            sup[0] = hs[0].clone();
+            rhs[0] = (slopes[0].clone() - left) * &six;
+            sub[n - 1] = hs[n - 2].clone();
+            diag[n - 1] = hs[n - 2].clone() * &two;
+            rhs[n - 1] = (right.clone() - &slopes[n - 2]) * &six;
+        }
+    }
+
+    // forward elimination
+    for i in 1..n {
+        ensure!(!diag[i - 1].is_zero(), "Spline system is singular");
+        let w = sub[i].clone() / &diag[i - 1];
+        diag[i] = diag[i].clone() - &(w.clone() * &sup[i - 1]);
+        rhs[i] = rhs[i].clone() - &(rhs[i - 1].clone() * &w);
+    }
+    ensure!(!diag[n - 1].is_zero(), "Spline system is singular");
+
+    // back substitution
+    let mut res = vec![V::zero(); n];
+    res[n - 1] = rhs[n - 1].clone() / &diag[n - 1];
+    for i in (0..(n - 1)).rev() {
+        res[i] = (rhs[i].clone() - &(res[i + 1].clone() * &sup[i])) / &diag[i];
+    }
+    Ok(res)
+}
+
+impl<X, V> Interp1d for CubicSpline1d<X, V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    type X = X;
+    type Value = V;
+
+    #[inline]
+    fn interpolatee(&self) -> &FlatDict<Self::X, Self::Value> {
+        &self.data
+    }
+
+    fn interp(&self, x: &X) -> anyhow::Result<Self::Value> {
+        let (index, h, a, b) = self._locate(x)?;
+        let yl = &self.data.values()[index];
+        let yr = &self.data.values()[index + 1];
+        let ml = &self.der_xx[index];
+        let mr = &self.der_xx[index + 1];
+
+        let coeff = h.clone() * &h / &X::nearest_value_of_f64(6.);
+        let cl = (a.clone() * &a * &a - &a) * &coeff;
+        let cr = (b.clone() * &b * &b - &b) * &coeff;
+        Ok(yl.clone() * &a + &(yr.clone() * &b) + &(ml.clone() * &cl) + &(mr.clone() * &cr))
+    }
+}
+
+impl<X, V> DerX1d<X> for CubicSpline1d<X, V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    type DerX = V;
+
+    fn der_x(&self, x: &X) -> anyhow::Result<Self::DerX> {
+        let (index, h, a, b) = self._locate(x)?;
+        let yl = &self.data.values()[index];
+        let yr = &self.data.values()[index + 1];
+        let ml = &self.der_xx[index];
+        let mr = &self.der_xx[index + 1];
+
+        let three = X::nearest_value_of_f64(3.);
+        let coeff = h.clone() / &X::nearest_value_of_f64(6.);
+        let cl = (a.clone() * &a * &three - &X::one()) * &coeff;
+        let cr = (b.clone() * &b * &three - &X::one()) * &coeff;
+        Ok((yr.clone() - yl) / &h - &(ml.clone() * &cl) + &(mr.clone() * &cr))
+    }
+}
+
+impl<X, V> DerXX1d<X> for CubicSpline1d<X, V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    type DerXX = V;
+
+    fn der_xx(&self, x: &X) -> anyhow::Result<Self::DerXX> {
+        let (index, _, a, b) = self._locate(x)?;
+        let ml = &self.der_xx[index];
+        let mr = &self.der_xx[index + 1];
+        Ok(ml.clone() * &a + &(mr.clone() * &b))
+    }
+}
+
+impl<X, V> RebuildableInterp1d for CubicSpline1d<X, V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    type Builder = CubicSpline1dBuilder<V>;
+
+    #[inline]
+    fn destruct(self) -> (Self::Builder, FlatDict<Self::X, Self::Value>) {
+        (
+            CubicSpline1dBuilder::new(self.boundary),
+            self.data.into_inner(),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// CubicSpline1dBuilder
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline1dBuilder<V> {
+    boundary: SplineBoundary<V>,
+}
+
+impl<V> CubicSpline1dBuilder<V> {
+    #[inline]
+    pub fn new(boundary: SplineBoundary<V>) -> Self {
+        CubicSpline1dBuilder { boundary }
+    }
+}
+
+impl<X, V> Interp1dBuilder<X, V> for CubicSpline1dBuilder<V>
+where
+    X: Real,
+    V: Vector<X>,
+{
+    type Output = CubicSpline1d<X, V>;
+
+    #[inline]
+    fn build(self, data: FlatDict<X, V>) -> anyhow::Result<Self::Output> {
+        let data = data.require_min_size().context("Building cubic spline")?;
+        CubicSpline1d::new(data, self.boundary).context("Building cubic spline")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::num::Func1d;
+
+    use super::*;
+
+    fn knots() -> FlatDict<f64, f64> {
+        FlatDict::with_data(
+            vec![0.0, 0.5, 1.0, 2.0, 3.5, 5.0],
+            vec![1.0, 0.8, 1.1, 0.4, 0.9, 0.2],
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(SplineBoundary::Natural)]
+    #[case(SplineBoundary::Clamped { left: 0.3, right: -1.0 })]
+    fn test_interpolates_knots(#[case] boundary: SplineBoundary<f64>) {
+        let data = knots();
+        let spline = CubicSpline1dBuilder::new(boundary)
+            .build(data.clone())
+            .unwrap();
+
+        for (x, y) in data.keys().iter().zip(data.values()) {
+            approx::assert_abs_diff_eq!(spline.interp(x).unwrap(), *y, epsilon = 1e-14);
+            approx::assert_abs_diff_eq!(spline.eval(x).unwrap(), *y, epsilon = 1e-14);
+        }
+    }
+
+    #[rstest]
+    #[case(SplineBoundary::Natural)]
+    #[case(SplineBoundary::Clamped { left: 0.3, right: -1.0 })]
+    fn test_smoothness(#[case] boundary: SplineBoundary<f64>) {
+        let data = knots();
+        let spline = CubicSpline1dBuilder::new(boundary)
+            .build(data.clone())
+            .unwrap();
+        let eps = 1e-9;
+
+        // first and second derivatives are continuous at interior knots
+        for x in &data.keys()[1..data.len() - 1] {
+            let (l, r) = (x - eps, x + eps);
+            approx::assert_abs_diff_eq!(
+                spline.der_x(&l).unwrap(),
+                spline.der_x(&r).unwrap(),
+                epsilon = 1e-7
+            );
+            approx::assert_abs_diff_eq!(
+                spline.der_xx(&l).unwrap(),
+                spline.der_xx(&r).unwrap(),
+                epsilon = 1e-7
+            );
+        }
+    }
+
+    #[test]
+    fn test_natural_boundary() {
+        let data = knots();
+        let spline = CubicSpline1dBuilder::new(SplineBoundary::Natural)
+            .build(data.clone())
+            .unwrap();
+
+        let (first, _) = data.first().unwrap();
+        let (last, _) = data.last().unwrap();
+        approx::assert_abs_diff_eq!(spline.der_xx(first).unwrap(), 0.0, epsilon = 1e-14);
+        approx::assert_abs_diff_eq!(spline.der_xx(last).unwrap(), 0.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_clamped_boundary() {
+        let data = knots();
+        let spline = CubicSpline1dBuilder::new(SplineBoundary::Clamped {
+            left: 0.3,
+            right: -1.0,
+        })
+        .build(data.clone())
+        .unwrap();
+
+        let (first, _) = data.first().unwrap();
+        let (last, _) = data.last().unwrap();
+        approx::assert_abs_diff_eq!(spline.der_x(first).unwrap(), 0.3, epsilon = 1e-14);
+        approx::assert_abs_diff_eq!(spline.der_x(last).unwrap(), -1.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_clamped_reproduces_cubic() {
+        let f = |x: f64| 0.5 * x * x * x - 2.0 * x * x + x - 3.0;
+        let df = |x: f64| 1.5 * x * x - 4.0 * x + 1.0;
+        let xs = vec![0.0, 0.7, 1.5, 2.0, 4.0];
+        let ys = xs.iter().map(|x| f(*x)).collect();
+        let data = FlatDict::with_data(xs, ys).unwrap();
+        let spline = CubicSpline1dBuilder::new(SplineBoundary::Clamped {
+            left: df(0.0),
+            right: df(4.0),
+        })
+        .build(data)
+        .unwrap();
+
+        for x in (0..=40).map(|i| i as f64 * 0.1) {
+            let (y, dy, _) = spline.der_0_x_xx(&x).unwrap();
+            approx::assert_abs_diff_eq!(y, f(x), epsilon = 1e-12);
+            approx::assert_abs_diff_eq!(dy, df(x), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_two_points_natural_is_linear() {
+        let data = FlatDict::with_data(vec![1.0, 3.0], vec![2.0, 6.0]).unwrap();
+        let spline = CubicSpline1dBuilder::new(SplineBoundary::Natural)
+            .build(data)
+            .unwrap();
+
+        assert_eq!(spline.interp(&2.0).unwrap(), 4.0);
+        assert_eq!(spline.der_x(&2.0).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_builder_err() {
+        let data = FlatDict::with_data(vec![1.0], vec![1.0]).unwrap();
+
+        let res = CubicSpline1dBuilder::new(SplineBoundary::Natural).build(data);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_destruct() {
+        let boundary = SplineBoundary::Clamped {
+            left: 0.3,
+            right: -1.0,
+        };
+        let data = knots();
+        let spline = CubicSpline1dBuilder::new(boundary.clone())
+            .build(data.clone())
+            .unwrap();
+
+        let (builder, destructed) = spline.destruct();
+
+        assert_eq!(builder, CubicSpline1dBuilder::new(boundary));
+        assert_eq!(destructed, data);
+    }
+}
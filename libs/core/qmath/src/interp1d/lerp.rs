@@ -1,6 +1,6 @@
 use std::ops::{Div, Sub};
 
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 use num::{One, Zero};
 use qcollections::{
     flat_dict::FlatDict,
@@ -14,6 +14,26 @@ use super::{Interp1d, Interp1dBuilder, RebuildableInterp1d};
 // -----------------------------------------------------------------------------
 // Lerp1d
 // -----------------------------------------------------------------------------
+/// Linear interpolation.
+///
+/// Arguments out of the range of knots are errors by default.
+/// If `extrapolate` is enabled, e.g. via [`Lerp1dBuilder::with_extrapolate`],
+/// they are extrapolated with the first or the last interval.
+///
+/// # Example
+/// ```
+/// use qcollections::flat_dict::FlatDict;
+/// use qmath::interp1d::{Interp1d, Interp1dBuilder, Lerp1dBuilder};
+///
+/// let data = FlatDict::with_data(vec![0.0, 2.0], vec![1.0, 3.0]).unwrap();
+/// let interp = Lerp1dBuilder::new().build(data.clone()).unwrap();
+///
+/// approx::assert_abs_diff_eq!(interp.interp(&1.0).unwrap(), 2.0, epsilon = 1e-12);
+/// assert!(interp.interp(&3.0).is_err());
+///
+/// let interp = Lerp1dBuilder::new().with_extrapolate(true).build(data).unwrap();
+/// approx::assert_abs_diff_eq!(interp.interp(&3.0).unwrap(), 4.0, epsilon = 1e-12);
+/// ```
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(bound(
     deserialize = "X: PartialOrd + serde::Deserialize<'de>, V: serde::Deserialize<'de>"
@@ -21,12 +41,29 @@ use super::{Interp1d, Interp1dBuilder, RebuildableInterp1d};
 pub struct Lerp1d<X, V> {
     #[serde(rename = "interpolatee")]
     data: SizeEnsured<FlatDict<X, V>, 2>,
+    #[serde(default)]
+    extrapolate: bool,
 }
 
 impl<X, V> Lerp1d<X, V> {
     #[inline]
-    pub fn new(data: SizeEnsured<FlatDict<X, V>, 2>) -> Self {
-        Lerp1d { data }
+    pub fn new(data: SizeEnsured<FlatDict<X, V>, 2>, extrapolate: bool) -> Self {
+        Lerp1d { data, extrapolate }
+    }
+
+    fn _interval_index(&self, x: &X) -> anyhow::Result<usize>
+    where
+        X: PartialOrd,
+    {
+        if !self.extrapolate {
+            let (xfirst, _) = self.data.first();
+            let (xlast, _) = self.data.last();
+            if x < xfirst || xlast < x {
+                bail!("Linear interpolation: argument is out of range of knots");
+            }
+        }
+        let index = self.data.interval_index(x);
+        index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))
     }
 }
 
@@ -44,8 +81,7 @@ where
     }
 
     fn interp(&self, x: &X) -> anyhow::Result<Self::Value> {
-        let index = self.data.interval_index(x);
-        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let index = self._interval_index(x)?;
         let (xl, yl) = self.data.at(index).unwrap();
         let (xr, yr) = self.data.at(index + 1).unwrap();
 
@@ -64,8 +100,7 @@ where
     type DerX = <V as Div<<X as Sub>::Output>>::Output;
 
     fn der_x(&self, x: &X) -> anyhow::Result<Self::DerX> {
-        let index = self.data.interval_index(x);
-        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let index = self._interval_index(x)?;
         let (xl, yl) = self.data.at(index).unwrap();
         let (xr, yr) = self.data.at(index + 1).unwrap();
 
@@ -76,8 +111,7 @@ where
     }
 
     fn der_0_x(&self, arg: &X) -> anyhow::Result<(Self::Output, Self::DerX)> {
-        let index = self.data.interval_index(arg);
-        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let index = self._interval_index(arg)?;
         let (xl, yl) = self.data.at(index).unwrap();
         let (xr, yr) = self.data.at(index + 1).unwrap();
 
@@ -105,8 +139,7 @@ where
 
     #[inline]
     fn der_xx(&self, x: &X) -> anyhow::Result<Self::DerXX> {
-        let index = self.data.interval_index(x);
-        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let index = self._interval_index(x)?;
 
         let (xl, _) = self.data.at(index).unwrap();
         let (xr, _) = self.data.at(index + 1).unwrap();
@@ -118,8 +151,7 @@ where
     }
 
     fn der_0_x_xx(&self, arg: &X) -> anyhow::Result<(Self::Output, Self::DerX, Self::DerXX)> {
-        let index = self.data.interval_index(arg);
-        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let index = self._interval_index(arg)?;
         let (xl, yl) = self.data.at(index).unwrap();
         let (xr, yr) = self.data.at(index + 1).unwrap();
 
@@ -146,7 +178,10 @@ where
 
     #[inline]
     fn destruct(self) -> (Self::Builder, FlatDict<Self::X, Self::Value>) {
-        (Lerp1dBuilder, self.data.into_inner())
+        let builder = Lerp1dBuilder {
+            extrapolate: self.extrapolate,
+        };
+        (builder, self.data.into_inner())
     }
 }
 
@@ -154,7 +189,25 @@ where
 // Lerp1dBuilder
 // -----------------------------------------------------------------------------
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub struct Lerp1dBuilder;
+pub struct Lerp1dBuilder {
+    extrapolate: bool,
+}
+
+impl Lerp1dBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether arguments out of the range of knots are extrapolated.
+    ///
+    /// Extrapolation is disabled by default.
+    #[inline]
+    pub fn with_extrapolate(mut self, extrapolate: bool) -> Self {
+        self.extrapolate = extrapolate;
+        self
+    }
+}
 
 impl<X, V> Interp1dBuilder<X, V> for Lerp1dBuilder
 where
@@ -166,7 +219,7 @@ where
     #[inline]
     fn build(self, data: FlatDict<X, V>) -> anyhow::Result<Self::Output> {
         let data = data.require_min_size().context("Building lerp")?;
-        Ok(Lerp1d::new(data))
+        Ok(Lerp1d::new(data, self.extrapolate))
     }
 }
 
@@ -174,6 +227,8 @@ where
 mod tests {
     use std::{path::PathBuf, vec};
 
+    use rstest::rstest;
+
     use crate::num::Func1d;
 
     use super::*;
@@ -230,7 +285,7 @@ mod tests {
         let ys = vec![0.0, 1.0, 2.0];
         let data = FlatDict::with_data(xs, ys).unwrap();
 
-        let interp = Lerp1dBuilder.build(data.clone()).unwrap();
+        let interp = Lerp1dBuilder::new().build(data.clone()).unwrap();
 
         assert_eq!(interp.interpolatee(), &data);
     }
@@ -241,7 +296,7 @@ mod tests {
         let ys = vec![1.0];
         let data = FlatDict::with_data(xs, ys).unwrap();
 
-        let res = Lerp1dBuilder.build(data);
+        let res = Lerp1dBuilder::new().build(data);
 
         assert!(res.is_err());
     }
@@ -252,10 +307,64 @@ mod tests {
         let ys = vec![0.0, 1.0, 2.0];
         let data = FlatDict::with_data(xs, ys).unwrap();
 
-        let interp = Lerp1dBuilder.build(data.clone()).unwrap();
+        let interp = Lerp1dBuilder::new().build(data.clone()).unwrap();
         let (builder, data) = interp.destruct();
 
-        assert_eq!(builder, Lerp1dBuilder);
+        assert_eq!(builder, Lerp1dBuilder::new());
         assert_eq!(data, data);
     }
+
+    #[rstest]
+    #[case(0.0, Some(1.0))]
+    #[case(1.0, Some(2.0))]
+    #[case(2.0, Some(3.0))]
+    #[case(-1e-9, None)]
+    #[case(2.0 + 1e-9, None)]
+    #[case(-1.0, None)]
+    #[case(3.0, None)]
+    fn test_lerp1d_no_extrapolation(#[case] x: f64, #[case] expected: Option<f64>) {
+        let data = FlatDict::with_data(vec![0.0, 2.0], vec![1.0, 3.0]).unwrap();
+        let interp = Lerp1dBuilder::new().build(data).unwrap();
+
+        let tested = interp.interp(&x);
+        let der_x = interp.der_x(&x);
+        let der_xx = interp.der_xx(&x);
+
+        match expected {
+            Some(expected) => {
+                approx::assert_abs_diff_eq!(tested.unwrap(), expected, epsilon = 1e-14);
+                approx::assert_abs_diff_eq!(der_x.unwrap(), 1.0, epsilon = 1e-14);
+                approx::assert_abs_diff_eq!(der_xx.unwrap(), 0.0, epsilon = 1e-14);
+            }
+            None => {
+                assert!(tested.is_err());
+                assert!(der_x.is_err());
+                assert!(der_xx.is_err());
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 3.0]] }), false)]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 3.0]], "extrapolate": false }), false)]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 3.0]], "extrapolate": true }), true)]
+    fn test_serde_extrapolate(#[case] json: serde_json::Value, #[case] extrapolate: bool) {
+        let interp: Lerp1d<f64, f64> = serde_json::from_value(json).unwrap();
+        let reserialized = serde_json::to_value(&interp).unwrap();
+        let deserialized: Lerp1d<f64, f64> = serde_json::from_value(reserialized).unwrap();
+
+        assert_eq!(interp.interp(&3.0).is_ok(), extrapolate);
+        assert_eq!(deserialized, interp);
+    }
+
+    #[test]
+    fn test_destruct_extrapolate() {
+        let data = FlatDict::with_data(vec![0.0, 2.0], vec![1.0, 3.0]).unwrap();
+        let builder = Lerp1dBuilder::new().with_extrapolate(true);
+
+        let interp = builder.build(data).unwrap();
+        let (tested, _) = interp.destruct();
+
+        assert_eq!(tested, builder);
+    }
 }
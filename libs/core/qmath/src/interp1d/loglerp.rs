@@ -0,0 +1,297 @@
+use anyhow::{anyhow, bail, ensure, Context};
+use num::One;
+use qcollections::{
+    flat_dict::FlatDict,
+    size_ensured::{RequireMinSize, SizeEnsured},
+};
+
+use crate::num::{Exp, Log, RelPos, Vector};
+
+use super::{Interp1d, Interp1dBuilder, RebuildableInterp1d};
+
+// -----------------------------------------------------------------------------
+// LogLerp1d
+// -----------------------------------------------------------------------------
+/// Log-linear interpolation, that is, linear interpolation of logarithm of values.
+///
+/// This is typically used for discount factors because constant forward rate between knots is implied.
+/// All values must be positive.
+///
+/// At knots, the given values are reproduced.
+/// Arguments out of the range of knots are errors by default.
+/// If `extrapolate` is enabled, e.g. via [`LogLerp1dBuilder::with_extrapolate`],
+/// they are extrapolated with the first or the last interval as well as [`super::Lerp1d`].
+///
+/// # Example
+/// ```
+/// use qcollections::flat_dict::FlatDict;
+/// use qmath::interp1d::{Interp1d, Interp1dBuilder, LogLerp1dBuilder};
+///
+/// let data = FlatDict::with_data(vec![0.0, 2.0], vec![1.0, 0.25]).unwrap();
+/// let interp = LogLerp1dBuilder::new().build(data).unwrap();
+///
+/// approx::assert_abs_diff_eq!(interp.interp(&1.0).unwrap(), 0.5, epsilon = 1e-12);
+/// assert!(interp.interp(&3.0).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(bound(
+    deserialize = "X: PartialOrd + serde::Deserialize<'de>, V: serde::Deserialize<'de>"
+))]
+pub struct LogLerp1d<X, V> {
+    #[serde(rename = "interpolatee")]
+    data: SizeEnsured<FlatDict<X, V>, 2>,
+    #[serde(default)]
+    extrapolate: bool,
+}
+
+impl<X, V> LogLerp1d<X, V> {
+    #[inline]
+    pub fn new(data: SizeEnsured<FlatDict<X, V>, 2>, extrapolate: bool) -> Self {
+        LogLerp1d { data, extrapolate }
+    }
+}
+
+impl<X, V> Interp1d for LogLerp1d<X, V>
+where
+    X: RelPos,
+    V: Vector<X::Output> + PartialOrd + Log<Output = V> + Exp<Output = V>,
+{
+    type X = X;
+    type Value = V;
+
+    #[inline]
+    fn interpolatee(&self) -> &FlatDict<Self::X, Self::Value> {
+        &self.data
+    }
+
+    fn interp(&self, x: &X) -> anyhow::Result<Self::Value> {
+        if !self.extrapolate {
+            let (xfirst, _) = self.data.first();
+            let (xlast, _) = self.data.last();
+            if x < xfirst || xlast < x {
+                bail!("Log-linear interpolation: argument is out of range of knots");
+            }
+        }
+        let index = self.data.interval_index(x);
+        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let (xl, yl) = self.data.at(index).unwrap();
+        let (xr, yr) = self.data.at(index + 1).unwrap();
+        ensure!(
+            &V::zero() < yl && &V::zero() < yr,
+            "Log-linear interpolation requires positive values"
+        );
+
+        let wr = x.relpos_between(xl, xr).unwrap();
+        let wl = <X::Output as One>::one() - &wr;
+        Ok((yl.clone().log() * &wl + yr.clone().log() * &wr).exp())
+    }
+}
+
+impl<X, V> RebuildableInterp1d for LogLerp1d<X, V>
+where
+    X: RelPos,
+    V: Vector<X::Output> + PartialOrd + Log<Output = V> + Exp<Output = V>,
+{
+    type Builder = LogLerp1dBuilder;
+
+    #[inline]
+    fn destruct(self) -> (Self::Builder, FlatDict<Self::X, Self::Value>) {
+        let builder = LogLerp1dBuilder {
+            extrapolate: self.extrapolate,
+        };
+        (builder, self.data.into_inner())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// LogLerp1dBuilder
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LogLerp1dBuilder {
+    extrapolate: bool,
+}
+
+impl LogLerp1dBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether arguments out of the range of knots are extrapolated.
+    ///
+    /// Extrapolation is disabled by default.
+    #[inline]
+    pub fn with_extrapolate(mut self, extrapolate: bool) -> Self {
+        self.extrapolate = extrapolate;
+        self
+    }
+}
+
+impl<X, V> Interp1dBuilder<X, V> for LogLerp1dBuilder
+where
+    X: RelPos,
+    V: Vector<X::Output> + PartialOrd + Log<Output = V> + Exp<Output = V>,
+{
+    type Output = LogLerp1d<X, V>;
+
+    #[inline]
+    fn build(self, data: FlatDict<X, V>) -> anyhow::Result<Self::Output> {
+        ensure!(
+            data.values().iter().all(|v| &V::zero() < v),
+            "Building log-lerp: all values must be positive"
+        );
+        let data = data.require_min_size().context("Building log-lerp")?;
+        Ok(LogLerp1d::new(data, self.extrapolate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::num::Func1d;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.3)]
+    #[case(1.0)]
+    #[case(1.7)]
+    #[case(2.0)]
+    #[case(2.5)]
+    #[case(4.0)]
+    #[case(-1.0)]
+    #[case(5.0)]
+    fn test_loglerp1d_interp(#[case] x: f64) {
+        // piecewise constant forward rates, 1% on [0, 2) and 3% on [2, 4)
+        let df = |t: f64| {
+            if t < 2.0 {
+                (-0.01 * t).exp()
+            } else {
+                (-0.02 - 0.03 * (t - 2.0)).exp()
+            }
+        };
+        let xs = vec![0.0, 2.0, 4.0];
+        let ys = xs.iter().copied().map(df).collect();
+        let interp = LogLerp1dBuilder::new()
+            .with_extrapolate(true)
+            .build(FlatDict::with_data(xs, ys).unwrap())
+            .unwrap();
+
+        let tested = interp.interp(&x).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, df(x), epsilon = 1e-14);
+        approx::assert_abs_diff_eq!(interp.eval(&x).unwrap(), df(x), epsilon = 1e-14);
+    }
+
+    #[rstest]
+    #[case("2021-01-01T00:00:00Z", 1.0)]
+    #[case("2021-01-02T00:00:00Z", 0.5)]
+    #[case("2021-01-03T00:00:00Z", 0.25)]
+    #[case("2021-01-02T12:00:00Z", 0.3535533905932738)]
+    #[case("2021-01-04T00:00:00Z", 0.125)]
+    fn test_loglerp1d_datetime(#[case] x: &str, #[case] expected: f64) {
+        let xs = vec![
+            "2021-01-01T00:00:00Z".parse().unwrap(),
+            "2021-01-03T00:00:00Z".parse().unwrap(),
+        ];
+        let interp = LogLerp1dBuilder::new()
+            .with_extrapolate(true)
+            .build(FlatDict::with_data(xs, vec![1.0, 0.25]).unwrap())
+            .unwrap();
+        let x: chrono::DateTime<chrono::Utc> = x.parse().unwrap();
+
+        let tested = interp.interp(&x).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-14);
+    }
+
+    #[rstest]
+    #[case(0.0, Some(1.0))]
+    #[case(1.0, Some(0.5))]
+    #[case(2.0, Some(0.25))]
+    #[case(-1e-9, None)]
+    #[case(2.0 + 1e-9, None)]
+    #[case(-1.0, None)]
+    #[case(3.0, None)]
+    fn test_loglerp1d_no_extrapolation(#[case] x: f64, #[case] expected: Option<f64>) {
+        let data = FlatDict::with_data(vec![0.0, 2.0], vec![1.0, 0.25]).unwrap();
+        let interp = LogLerp1dBuilder::new().build(data).unwrap();
+
+        let tested = interp.interp(&x);
+
+        match expected {
+            Some(expected) => {
+                approx::assert_abs_diff_eq!(tested.unwrap(), expected, epsilon = 1e-14)
+            }
+            None => assert!(tested.is_err()),
+        }
+    }
+
+    #[rstest]
+    #[case("2020-12-31T23:59:59Z")]
+    #[case("2021-01-03T00:00:01Z")]
+    fn test_loglerp1d_datetime_no_extrapolation(#[case] x: &str) {
+        let xs = vec![
+            "2021-01-01T00:00:00Z".parse().unwrap(),
+            "2021-01-03T00:00:00Z".parse().unwrap(),
+        ];
+        let interp = LogLerp1dBuilder::new()
+            .build(FlatDict::with_data(xs, vec![1.0, 0.25]).unwrap())
+            .unwrap();
+        let x: chrono::DateTime<chrono::Utc> = x.parse().unwrap();
+
+        let tested = interp.interp(&x);
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 0.25]] }), false)]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 0.25]], "extrapolate": false }), false)]
+    #[case(serde_json::json!({ "interpolatee": [[0.0, 1.0], [2.0, 0.25]], "extrapolate": true }), true)]
+    fn test_serde_extrapolate(#[case] json: serde_json::Value, #[case] extrapolate: bool) {
+        let interp: LogLerp1d<f64, f64> = serde_json::from_value(json).unwrap();
+        let reserialized = serde_json::to_value(&interp).unwrap();
+        let deserialized: LogLerp1d<f64, f64> = serde_json::from_value(reserialized).unwrap();
+
+        assert_eq!(interp.interp(&3.0).is_ok(), extrapolate);
+        assert_eq!(deserialized, interp);
+    }
+
+    #[rstest]
+    #[case(vec![1.0], vec![1.0])]
+    #[case(vec![0.0, 1.0], vec![1.0, 0.0])]
+    #[case(vec![0.0, 1.0], vec![1.0, -0.5])]
+    fn test_builder_err(#[case] xs: Vec<f64>, #[case] ys: Vec<f64>) {
+        let data = FlatDict::with_data(xs, ys).unwrap();
+
+        let res = LogLerp1dBuilder::new().build(data);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_interp_err_non_positive() {
+        let json = serde_json::json!({ "interpolatee": [[0.0, 1.0], [1.0, -1.0]] });
+        let interp: LogLerp1d<f64, f64> = serde_json::from_value(json).unwrap();
+
+        let res = interp.interp(&0.5);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_destruct() {
+        let data = FlatDict::with_data(vec![0.0, 1.0, 2.0], vec![1.0, 0.5, 0.25]).unwrap();
+
+        let builder = LogLerp1dBuilder::new().with_extrapolate(true);
+
+        let interp = builder.build(data.clone()).unwrap();
+        let (tested_builder, tested) = interp.destruct();
+
+        assert_eq!(tested_builder, builder);
+        assert_eq!(tested, data);
+    }
+}
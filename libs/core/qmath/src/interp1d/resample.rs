@@ -0,0 +1,238 @@
+use anyhow::{anyhow, bail, Context};
+use num::One;
+use qcollections::flat_dict::FlatDict;
+
+use crate::num::{RelPos, Vector};
+
+// -----------------------------------------------------------------------------
+// FillPolicy
+// -----------------------------------------------------------------------------
+/// Policy to fill a value at a point which is not a knot of the source data.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FillPolicy {
+    /// Value at the nearest knot on the left.
+    Previous,
+    /// Value at the nearest knot on the right.
+    Next,
+    /// Value at the nearest knot. When a point is just in the middle of two knots, the left one is taken.
+    Nearest,
+    /// Linear interpolation between the bracketing knots.
+    Linear,
+}
+
+// -----------------------------------------------------------------------------
+// resample
+// -----------------------------------------------------------------------------
+/// Resample `data` onto `targets` with the given fill policy.
+///
+/// Values at knots of `data` are taken as is regardless of the policy.
+/// Targets out of the range of knots are filled with the value at the nearest end knot
+/// if `extrapolate` is `true`. Otherwise, they are treated as errors.
+/// Note that this flat extrapolation is applied also for [`FillPolicy::Linear`].
+///
+/// `targets` need not be sorted. The result is sorted by its keys as usual [`FlatDict`].
+///
+/// # Errors
+/// - When `data` is empty.
+/// - When some target is out of range and `extrapolate` is `false`.
+/// - When some target is not comparable with knots, e.g. `NaN`.
+/// - When `targets` contains duplicated points.
+///
+/// # Example
+/// ```
+/// use qcollections::flat_dict::FlatDict;
+/// use qmath::interp1d::{resample, FillPolicy};
+///
+/// let data = FlatDict::with_data(vec![0.0, 1.0, 2.0], vec![0.0, 10.0, 20.0]).unwrap();
+///
+/// let res = resample(&data, &[0.25, 1.5], FillPolicy::Linear, false).unwrap();
+///
+/// assert_eq!(res.values(), &[2.5, 15.0]);
+/// ```
+pub fn resample<X, V>(
+    data: &FlatDict<X, V>,
+    targets: &[X],
+    policy: FillPolicy,
+    extrapolate: bool,
+) -> anyhow::Result<FlatDict<X, V>>
+where
+    X: RelPos + Clone,
+    V: Vector<X::Output>,
+{
+    let (xfirst, yfirst) = data.first().context("Resampling empty data")?;
+    let (xlast, ylast) = data.last().unwrap();
+
+    let mut vs = Vec::with_capacity(targets.len());
+    for x in targets {
+        if x < xfirst || xlast < x {
+            if !extrapolate {
+                bail!("Resampling target is out of range of knots");
+            }
+            vs.push(if x < xfirst { yfirst } else { ylast }.clone());
+            continue;
+        }
+        if data.len() == 1 {
+            vs.push(yfirst.clone());
+            continue;
+        }
+        let index = data.interval_index(x);
+        let index = index.ok_or_else(|| anyhow!("Given argument maybe uncomparable."))?;
+        let (xl, yl) = data.at(index).unwrap();
+        let (xr, yr) = data.at(index + 1).unwrap();
+        let wr = x.relpos_between(xl, xr).unwrap();
+
+        let y = if x <= xl {
+            yl.clone()
+        } else if xr <= x {
+            yr.clone()
+        } else {
+            match policy {
+                FillPolicy::Previous => yl.clone(),
+                FillPolicy::Next => yr.clone(),
+                FillPolicy::Nearest => {
+                    let half = <X::Output as One>::one() / &(X::Output::one() + &X::Output::one());
+                    if wr <= half {
+                        yl.clone()
+                    } else {
+                        yr.clone()
+                    }
+                }
+                FillPolicy::Linear => {
+                    let wl = <X::Output as One>::one() - &wr;
+                    yl.clone() * &wl + yr.clone() * &wr
+                }
+            }
+        };
+        vs.push(y);
+    }
+    FlatDict::with_data(targets.to_vec(), vs).context("Resampling")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn data() -> FlatDict<f64, f64> {
+        FlatDict::with_data(vec![0.0, 1.0, 3.0], vec![1.0, 3.0, -1.0]).unwrap()
+    }
+
+    #[rstest]
+    #[case(FillPolicy::Previous, vec![1.0, 1.0, 3.0, 3.0, 3.0, -1.0])]
+    #[case(FillPolicy::Next, vec![1.0, 3.0, 3.0, -1.0, -1.0, -1.0])]
+    #[case(FillPolicy::Nearest, vec![1.0, 1.0, 3.0, 3.0, -1.0, -1.0])]
+    #[case(FillPolicy::Linear, vec![1.0, 2.0, 3.0, 1.0, 0.0, -1.0])]
+    fn test_resample(#[case] policy: FillPolicy, #[case] expected: Vec<f64>) {
+        let targets = [0.0, 0.5, 1.0, 2.0, 2.5, 3.0];
+
+        let tested = resample(&data(), &targets, policy, false).unwrap();
+
+        assert_eq!(tested.keys(), &targets);
+        assert_eq!(tested.values(), expected.as_slice());
+    }
+
+    #[rstest]
+    fn test_resample_unsorted(
+        #[values(
+            FillPolicy::Previous,
+            FillPolicy::Next,
+            FillPolicy::Nearest,
+            FillPolicy::Linear
+        )]
+        policy: FillPolicy,
+    ) {
+        let tested = resample(&data(), &[2.5, 0.5], policy, false).unwrap();
+
+        let expected = resample(&data(), &[0.5, 2.5], policy, false).unwrap();
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    fn test_resample_extrapolate(
+        #[values(
+            FillPolicy::Previous,
+            FillPolicy::Next,
+            FillPolicy::Nearest,
+            FillPolicy::Linear
+        )]
+        policy: FillPolicy,
+    ) {
+        let targets = [-1.0, 4.0];
+
+        let tested = resample(&data(), &targets, policy, true).unwrap();
+
+        assert_eq!(tested.values(), &[1.0, -1.0]);
+    }
+
+    #[rstest]
+    #[case(vec![-1.0])]
+    #[case(vec![4.0])]
+    #[case(vec![0.5, 4.0])]
+    #[case(vec![f64::NAN])]
+    fn test_resample_out_of_range(#[case] targets: Vec<f64>) {
+        let tested = resample(&data(), &targets, FillPolicy::Linear, false);
+
+        assert!(tested.is_err());
+    }
+
+    #[test]
+    fn test_resample_single_knot() {
+        let data = FlatDict::with_data(vec![1.0], vec![2.0]).unwrap();
+
+        let tested = resample(&data, &[0.0, 1.0, 2.0], FillPolicy::Linear, true).unwrap();
+
+        assert_eq!(tested.values(), &[2.0, 2.0, 2.0]);
+    }
+
+    #[rstest]
+    #[case(FlatDict::with_data(vec![], vec![]).unwrap(), vec![0.0])]
+    #[case(data(), vec![0.5, 0.5])]
+    fn test_resample_err(#[case] data: FlatDict<f64, f64>, #[case] targets: Vec<f64>) {
+        let tested = resample(&data, &targets, FillPolicy::Linear, true);
+
+        assert!(tested.is_err());
+    }
+
+    #[test]
+    fn test_resample_date() {
+        let ymd = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let data =
+            FlatDict::with_data(vec![ymd(2021, 1, 1), ymd(2021, 1, 11)], vec![1.0, 2.0]).unwrap();
+
+        let tested = resample(
+            &data,
+            &[ymd(2021, 1, 3), ymd(2021, 1, 7)],
+            FillPolicy::Linear,
+            false,
+        )
+        .unwrap();
+
+        approx::assert_abs_diff_eq!(tested.values()[0], 1.2, epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(tested.values()[1], 1.6, epsilon = 1e-12);
+    }
+
+    #[rstest]
+    #[case(FillPolicy::Previous, "\"previous\"")]
+    #[case(FillPolicy::Next, "\"next\"")]
+    #[case(FillPolicy::Nearest, "\"nearest\"")]
+    #[case(FillPolicy::Linear, "\"linear\"")]
+    fn test_fill_policy_serde(#[case] policy: FillPolicy, #[case] expected: &str) {
+        let serialized = serde_json::to_string(&policy).unwrap();
+        let deserialized: FillPolicy = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(serialized, expected);
+        assert_eq!(deserialized, policy);
+    }
+}
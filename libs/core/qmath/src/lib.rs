@@ -1,3 +1,5 @@
 pub mod ext;
 pub mod interp1d;
 pub mod num;
+pub mod solve;
+pub mod stats;
@@ -2,12 +2,14 @@ mod algebra;
 mod bounded;
 mod elementary_fn;
 mod func1d;
+mod interval;
 mod relpos;
 mod weak_minmax;
 
 pub use algebra::{Arithmetic, FloatBased, Real, Scalar, Vector};
 pub use bounded::Positive;
-pub use elementary_fn::{Erf, Exp, Log, Powi, Sqrt};
+pub use elementary_fn::{Abs, Atan2, Erf, Exp, Expm1, Hypot, Log, Log1p, Max, Min, Powi, Sqrt};
 pub use func1d::{DerX1d, DerXX1d, Func1d, Integrable1d};
+pub use interval::Interval;
 pub use relpos::RelPos;
 pub use weak_minmax::WeakMinMax;
@@ -5,7 +5,7 @@ use std::{
 
 use num::{One, Zero};
 
-use super::{Erf, Exp, Log, Powi, Sqrt};
+use super::{Abs, Atan2, Erf, Exp, Expm1, Hypot, Log, Log1p, Max, Min, Powi, Sqrt};
 
 // -----------------------------------------------------------------------------
 // FloatBased
@@ -164,6 +164,8 @@ pub trait Scalar:
     + Powi<Output = Self>
     + Exp<Output = Self>
     + Log<Output = Self>
+    + Expm1<Output = Self>
+    + Log1p<Output = Self>
 {
     #[inline]
     fn nearest_value_of_f64(v: f64) -> Self {
@@ -181,12 +183,36 @@ impl<T> Scalar for T where
         + Sqrt<Output = Self>
         + Exp<Output = Self>
         + Log<Output = Self>
+        + Expm1<Output = Self>
+        + Log1p<Output = Self>
 {
 }
 
 /// Trait for real numbers.
 /// We consider a type `T` as a real number if it is a scalar on a 1-dim line.
 /// Hence, this trait requires total ordering in addition to scalar requirements.
-pub trait Real: Scalar + PartialOrd + Erf<Output = Self> + Display {}
+pub trait Real:
+    Scalar
+    + PartialOrd
+    + Abs<Output = Self>
+    + Erf<Output = Self>
+    + Atan2<Output = Self>
+    + Hypot<Output = Self>
+    + Max<Output = Self>
+    + Min<Output = Self>
+    + Display
+{
+}
 
-impl<T> Real for T where T: Scalar + PartialOrd + Erf<Output = Self> + Display {}
+impl<T> Real for T where
+    T: Scalar
+        + PartialOrd
+        + Abs<Output = Self>
+        + Erf<Output = Self>
+        + Atan2<Output = Self>
+        + Hypot<Output = Self>
+        + Max<Output = Self>
+        + Min<Output = Self>
+        + Display
+{
+}
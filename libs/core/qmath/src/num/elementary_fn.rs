@@ -34,6 +34,43 @@ impl<T: Sqrt<Output = T>> Sqrt for ordered_float::OrderedFloat<T> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Abs
+// -----------------------------------------------------------------------------
+/// Trait to generalize absolute value function interface.
+pub trait Abs: Sized {
+    type Output: Into<Self>;
+
+    fn abs(self) -> Self::Output;
+}
+
+impl Abs for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn abs(self) -> Self::Output {
+        f64::abs(self)
+    }
+}
+
+impl Abs for f32 {
+    type Output = Self;
+
+    #[inline]
+    fn abs(self) -> Self::Output {
+        f32::abs(self)
+    }
+}
+
+impl<T: Abs<Output = T>> Abs for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn abs(self) -> Self::Output {
+        ordered_float::OrderedFloat(Abs::abs(self.0))
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Powi
 // -----------------------------------------------------------------------------
@@ -108,6 +145,43 @@ impl<T: Exp<Output = T>> Exp for ordered_float::OrderedFloat<T> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Expm1
+// -----------------------------------------------------------------------------
+/// Trait to provide `exp(x) - 1` interface which is accurate even when `x` is close to zero.
+pub trait Expm1: Sized {
+    type Output: Into<Self>;
+
+    fn expm1(self) -> Self::Output;
+}
+
+impl Expm1 for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn expm1(self) -> Self::Output {
+        f64::exp_m1(self)
+    }
+}
+
+impl Expm1 for f32 {
+    type Output = Self;
+
+    #[inline]
+    fn expm1(self) -> Self::Output {
+        f32::exp_m1(self)
+    }
+}
+
+impl<T: Expm1<Output = T>> Expm1 for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn expm1(self) -> Self::Output {
+        ordered_float::OrderedFloat(self.0.expm1())
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Log
 // -----------------------------------------------------------------------------
@@ -145,6 +219,43 @@ impl<T: Log<Output = T>> Log for ordered_float::OrderedFloat<T> {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Log1p
+// -----------------------------------------------------------------------------
+/// Trait to provide `log(1 + x)` interface which is accurate even when `x` is close to zero.
+pub trait Log1p: Sized {
+    type Output: Into<Self>;
+
+    fn log1p(self) -> Self::Output;
+}
+
+impl Log1p for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn log1p(self) -> Self::Output {
+        f64::ln_1p(self)
+    }
+}
+
+impl Log1p for f32 {
+    type Output = Self;
+
+    #[inline]
+    fn log1p(self) -> Self::Output {
+        f32::ln_1p(self)
+    }
+}
+
+impl<T: Log1p<Output = T>> Log1p for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn log1p(self) -> Self::Output {
+        ordered_float::OrderedFloat(self.0.log1p())
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Erf
 // -----------------------------------------------------------------------------
@@ -181,3 +292,199 @@ impl<T: Erf<Output = T>> Erf for ordered_float::OrderedFloat<T> {
         ordered_float::OrderedFloat(self.0.erf())
     }
 }
+
+// -----------------------------------------------------------------------------
+// Atan2
+// -----------------------------------------------------------------------------
+/// Trait to generalize four-quadrant arctangent function interface.
+///
+/// `y.atan2(&x)` is the angle of the point `(x, y)` in `[-pi, pi]`, as [`f64::atan2`].
+pub trait Atan2: Sized {
+    type Output: Into<Self>;
+
+    fn atan2(self, other: &Self) -> Self::Output;
+}
+
+impl Atan2 for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn atan2(self, other: &Self) -> Self::Output {
+        f64::atan2(self, *other)
+    }
+}
+
+impl Atan2 for f32 {
+    type Output = Self;
+
+    #[inline]
+    fn atan2(self, other: &Self) -> Self::Output {
+        f32::atan2(self, *other)
+    }
+}
+
+impl<T: Atan2<Output = T>> Atan2 for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn atan2(self, other: &Self) -> Self::Output {
+        ordered_float::OrderedFloat(self.0.atan2(&other.0))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Hypot
+// -----------------------------------------------------------------------------
+/// Trait to generalize hypotenuse function interface, `sqrt(x^2 + y^2)` without overflow.
+pub trait Hypot: Sized {
+    type Output: Into<Self>;
+
+    fn hypot(self, other: &Self) -> Self::Output;
+}
+
+impl Hypot for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn hypot(self, other: &Self) -> Self::Output {
+        f64::hypot(self, *other)
+    }
+}
+
+impl Hypot for f32 {
+    type Output = Self;
+
+    #[inline]
+    fn hypot(self, other: &Self) -> Self::Output {
+        f32::hypot(self, *other)
+    }
+}
+
+impl<T: Hypot<Output = T>> Hypot for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn hypot(self, other: &Self) -> Self::Output {
+        ordered_float::OrderedFloat(self.0.hypot(&other.0))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Max
+// Min
+// -----------------------------------------------------------------------------
+/// Trait to generalize maximum function interface.
+///
+/// For floating point numbers, this follows [`f64::max`], that is, NaN is ignored if the other is a number.
+/// Since this is also implemented for types which are not totally ordered, e.g. [`super::Interval`],
+/// the result is not necessarily one of arguments.
+pub trait Max: Sized {
+    type Output: Into<Self>;
+
+    fn max(self, other: &Self) -> Self::Output;
+}
+
+/// Trait to generalize minimum function interface.
+///
+/// For floating point numbers, this follows [`f64::min`], that is, NaN is ignored if the other is a number.
+/// Since this is also implemented for types which are not totally ordered, e.g. [`super::Interval`],
+/// the result is not necessarily one of arguments.
+pub trait Min: Sized {
+    type Output: Into<Self>;
+
+    fn min(self, other: &Self) -> Self::Output;
+}
+
+macro_rules! _impl_minmax_for_float {
+    ($t:ty) => {
+        impl Max for $t {
+            type Output = Self;
+
+            #[inline]
+            fn max(self, other: &Self) -> Self::Output {
+                <$t>::max(self, *other)
+            }
+        }
+
+        impl Min for $t {
+            type Output = Self;
+
+            #[inline]
+            fn min(self, other: &Self) -> Self::Output {
+                <$t>::min(self, *other)
+            }
+        }
+    };
+}
+
+_impl_minmax_for_float!(f64);
+_impl_minmax_for_float!(f32);
+
+impl<T: Max<Output = T>> Max for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn max(self, other: &Self) -> Self::Output {
+        ordered_float::OrderedFloat(Max::max(self.0, &other.0))
+    }
+}
+
+impl<T: Min<Output = T>> Min for ordered_float::OrderedFloat<T> {
+    type Output = ordered_float::OrderedFloat<T::Output>;
+
+    #[inline]
+    fn min(self, other: &Self) -> Self::Output {
+        ordered_float::OrderedFloat(Min::min(self.0, &other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1e-5, 9.99995000033333e-06)]
+    #[case(-1e-5, -1.0000050000333335e-05)]
+    #[case(1e-10, 9.9999999995e-11)]
+    #[case(1e-15, 9.999999999999995e-16)]
+    #[case(0.25, 0.22314355131420976)]
+    fn test_log1p(#[case] x: f64, #[case] expected: f64) {
+        let tested = Log1p::log1p(x);
+
+        approx::assert_relative_eq!(tested, expected, max_relative = 1e-15);
+    }
+
+    #[rstest]
+    #[case(1e-5, 1.0000050000166667e-05)]
+    #[case(-1e-5, -9.999950000166666e-06)]
+    #[case(1e-10, 1.00000000005e-10)]
+    #[case(1e-15, 1.0000000000000005e-15)]
+    #[case(0.25, 0.2840254166877415)]
+    fn test_expm1(#[case] x: f64, #[case] expected: f64) {
+        let tested = Expm1::expm1(x);
+
+        approx::assert_relative_eq!(tested, expected, max_relative = 1e-15);
+    }
+
+    #[rstest]
+    #[case(1., 2., 2., 1.)]
+    #[case(2., 1., 2., 1.)]
+    #[case(-0.5, -0.5, -0.5, -0.5)]
+    #[case(f64::NAN, 1., 1., 1.)]
+    #[case(1., f64::NAN, 1., 1.)]
+    #[case(f64::NEG_INFINITY, 1., 1., f64::NEG_INFINITY)]
+    fn test_minmax(#[case] x: f64, #[case] y: f64, #[case] max: f64, #[case] min: f64) {
+        let tested_max = Max::max(x, &y);
+        let tested_min = Min::min(x, &y);
+        let tested_max_of = Max::max(
+            ordered_float::OrderedFloat(x),
+            &ordered_float::OrderedFloat(y),
+        );
+
+        assert_eq!(tested_max, max);
+        assert_eq!(tested_min, min);
+        assert_eq!(tested_max_of.0, max);
+    }
+}
@@ -0,0 +1,973 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use num::{One, Zero};
+
+use super::{Abs, Atan2, Erf, Exp, Expm1, FloatBased, Hypot, Log, Log1p, Max, Min, Powi, Sqrt};
+
+// -----------------------------------------------------------------------------
+// Interval
+// -----------------------------------------------------------------------------
+/// Closed interval `[lo, hi]` which rigorously encloses the result of calculations.
+///
+/// Every operation rounds its lower bound toward negative infinity and upper bound toward positive infinity,
+/// so that the true result on real numbers is always contained in the resulting interval.
+/// Since this implements [`super::Real`], generic calculations can produce guaranteed bounds
+/// by replacing `f64` with this type.
+///
+/// Arithmetic operations and `sqrt` are correctly rounded by `f64`,
+/// so that their directed rounding is recovered exactly from error-free transformations.
+/// Other elementary functions, `exp` and `log` of the standard library and `erf` of [libm],
+/// are not correctly rounded, so their results are widened by one ulp
+/// on the assumption that these implementations have errors less than one ulp.
+///
+/// An interval with NaN bounds represents an undefined result, e.g. `sqrt` of a negative interval.
+/// Division by an interval containing zero results in the entire real line.
+///
+/// # Example
+/// ```
+/// use qmath::num::Interval;
+///
+/// let x = Interval::new(1.0, 2.0).unwrap();
+/// let y = Interval::from(0.1);
+///
+/// let z = x * &y;
+///
+/// assert!(z.contains(0.1));
+/// assert!(z.contains(0.2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<F> {
+    lo: F,
+    hi: F,
+}
+
+impl Interval<f64> {
+    /// Create an interval `[lo, hi]`.
+    ///
+    /// This returns [None] if `lo > hi` or either of them is NaN.
+    #[inline]
+    pub fn new(lo: f64, hi: f64) -> Option<Self> {
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// The entire real line `[-inf, inf]`.
+    #[inline]
+    pub fn entire() -> Self {
+        Self {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+
+    #[inline]
+    fn nan() -> Self {
+        Self {
+            lo: f64::NAN,
+            hi: f64::NAN,
+        }
+    }
+
+    #[inline]
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    #[inline]
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Width of the interval rounded upward.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        let w = self.hi - self.lo;
+        _round_up(w, _two_diff_err(self.hi, self.lo, w))
+    }
+
+    /// Whether the interval consists of a single point.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    #[inline]
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Apply monotonically increasing function computed with an error less than one ulp.
+    #[inline]
+    fn _map_increasing(self, f: impl Fn(f64) -> f64) -> Self {
+        if self.is_nan() {
+            return Self::nan();
+        }
+        Self {
+            lo: _next_down(f(self.lo)),
+            hi: _next_up(f(self.hi)),
+        }
+    }
+}
+
+//
+// ser/de
+//
+impl Display for Interval<f64> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+//
+// construction
+//
+impl From<f64> for Interval<f64> {
+    #[inline]
+    fn from(v: f64) -> Self {
+        Self { lo: v, hi: v }
+    }
+}
+
+//
+// comp
+//
+/// Intervals are ordered only when they are disjoint or identical.
+impl PartialOrd for Interval<f64> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if other.hi < self.lo {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+//
+// numeric
+//
+impl FloatBased for Interval<f64> {
+    type BaseFloat = f64;
+}
+
+impl Zero for Interval<f64> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from(0.)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.lo == 0. && self.hi == 0.
+    }
+}
+
+impl One for Interval<f64> {
+    #[inline]
+    fn one() -> Self {
+        Self::from(1.)
+    }
+}
+
+impl Neg for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add<&Self> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: &Self) -> Self::Output {
+        let lo = self.lo + rhs.lo;
+        let hi = self.hi + rhs.hi;
+        Self {
+            lo: _round_down(lo, _two_sum_err(self.lo, rhs.lo, lo)),
+            hi: _round_up(hi, _two_sum_err(self.hi, rhs.hi, hi)),
+        }
+    }
+}
+
+impl Sub<&Self> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self::Output {
+        let lo = self.lo - rhs.hi;
+        let hi = self.hi - rhs.lo;
+        Self {
+            lo: _round_down(lo, _two_diff_err(self.lo, rhs.hi, lo)),
+            hi: _round_up(hi, _two_diff_err(self.hi, rhs.lo, hi)),
+        }
+    }
+}
+
+impl Mul<&Self> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: &Self) -> Self::Output {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        for a in [self.lo, self.hi] {
+            for b in [rhs.lo, rhs.hi] {
+                let p = a * b;
+                // 0 * inf is regarded as 0 because bounds are limits of finite values
+                let (l, h) = if p.is_nan() {
+                    (0., 0.)
+                } else {
+                    let err = a.mul_add(b, -p);
+                    (_round_down(p, err), _round_up(p, err))
+                };
+                lo = lo.min(l);
+                hi = hi.max(h);
+            }
+        }
+        Self { lo, hi }
+    }
+}
+
+impl Div<&Self> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: &Self) -> Self::Output {
+        if self.is_nan() || rhs.is_nan() {
+            return Self::nan();
+        }
+        if rhs.contains(0.) {
+            return Self::entire();
+        }
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        for a in [self.lo, self.hi] {
+            for b in [rhs.lo, rhs.hi] {
+                let q = a / b;
+                let (l, h) = if q.is_nan() {
+                    // inf / inf can be any value with the sign
+                    if 0. < a.signum() * b.signum() {
+                        (0., f64::INFINITY)
+                    } else {
+                        (f64::NEG_INFINITY, 0.)
+                    }
+                } else {
+                    // a / b = q + r / b
+                    let err = (-q).mul_add(b, a) * b.signum();
+                    (_round_down(q, err), _round_up(q, err))
+                };
+                lo = lo.min(l);
+                hi = hi.max(h);
+            }
+        }
+        Self { lo, hi }
+    }
+}
+
+impl Mul<&f64> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: &f64) -> Self::Output {
+        self * Self::from(*rhs)
+    }
+}
+
+impl Div<&f64> for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: &f64) -> Self::Output {
+        self / Self::from(*rhs)
+    }
+}
+
+macro_rules! _define_by_ref {
+    ($tr:ident, $method:ident, $assign_tr:ident, $assign_method:ident, $rhs:ty) => {
+        impl $assign_tr<&$rhs> for Interval<f64> {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &$rhs) {
+                *self = $tr::$method(*self, rhs);
+            }
+        }
+    };
+    ($tr:ident, $method:ident, $assign_tr:ident, $assign_method:ident) => {
+        _define_by_ref!($tr, $method, $assign_tr, $assign_method, Self);
+
+        impl $tr for Interval<f64> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                $tr::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+_define_by_ref!(Add, add, AddAssign, add_assign);
+_define_by_ref!(Sub, sub, SubAssign, sub_assign);
+_define_by_ref!(Mul, mul, MulAssign, mul_assign);
+_define_by_ref!(Div, div, DivAssign, div_assign);
+_define_by_ref!(Mul, mul, MulAssign, mul_assign, f64);
+_define_by_ref!(Div, div, DivAssign, div_assign, f64);
+
+//
+// elementary functions
+//
+impl Sqrt for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn sqrt(self) -> Self::Output {
+        if self.is_nan() || self.hi < 0. {
+            return Self::nan();
+        }
+        let sqrt = |x: f64, up: bool| {
+            let s = x.sqrt();
+            let err = (-s).mul_add(s, x);
+            if up {
+                _round_up(s, err)
+            } else {
+                _round_down(s, err).max(0.)
+            }
+        };
+        Self {
+            lo: sqrt(self.lo.max(0.), false),
+            hi: sqrt(self.hi, true),
+        }
+    }
+}
+
+impl Powi for Interval<f64> {
+    type Output = Self;
+
+    fn powi(self, n: i32) -> Self::Output {
+        // unsigned_abs avoids overflow of `-n` for `i32::MIN`
+        let m = n.unsigned_abs();
+        let res = self._powu(m);
+        if n < 0 {
+            Self::one() / res
+        } else {
+            res
+        }
+    }
+}
+
+impl Interval<f64> {
+    fn _powu(self, n: u32) -> Self {
+        // powers of points are calculated with interval multiplication to keep the rounding direction
+        let pow = |x: f64| {
+            let mut base = Self::from(x);
+            let mut res = Self::one();
+            let mut n = n;
+            while 0 < n {
+                if n & 1 == 1 {
+                    res *= &base;
+                }
+                let b = base;
+                base *= &b;
+                n >>= 1;
+            }
+            res
+        };
+        if n % 2 == 1 {
+            return Self {
+                lo: pow(self.lo).lo,
+                hi: pow(self.hi).hi,
+            };
+        }
+        // even power is decreasing on negative side and increasing on positive side
+        let (abs_lo, abs_hi) = if self.contains(0.) {
+            (0., self.hi.max(-self.lo))
+        } else if 0. < self.lo {
+            (self.lo, self.hi)
+        } else {
+            (-self.hi, -self.lo)
+        };
+        Self {
+            lo: pow(abs_lo).lo,
+            hi: pow(abs_hi).hi,
+        }
+    }
+}
+
+impl Exp for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn exp(self) -> Self::Output {
+        let mut res = self._map_increasing(f64::exp);
+        res.lo = res.lo.max(0.);
+        res
+    }
+}
+
+impl Log for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn log(self) -> Self::Output {
+        if self.is_nan() || self.hi < 0. {
+            return Self::nan();
+        }
+        let res = Self {
+            lo: self.lo.max(0.),
+            hi: self.hi,
+        };
+        res._map_increasing(f64::ln)
+    }
+}
+
+impl Expm1 for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn expm1(self) -> Self::Output {
+        let mut res = self._map_increasing(f64::exp_m1);
+        res.lo = res.lo.max(-1.);
+        res
+    }
+}
+
+impl Log1p for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn log1p(self) -> Self::Output {
+        if self.is_nan() || self.hi < -1. {
+            return Self::nan();
+        }
+        let res = Self {
+            lo: self.lo.max(-1.),
+            hi: self.hi,
+        };
+        res._map_increasing(f64::ln_1p)
+    }
+}
+
+impl Erf for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn erf(self) -> Self::Output {
+        let res = self._map_increasing(libm::erf);
+        Self {
+            lo: res.lo.max(-1.),
+            hi: res.hi.min(1.),
+        }
+    }
+}
+
+impl Atan2 for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn atan2(self, other: &Self) -> Self::Output {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        // f64 pi is below the true value, so the next float is an upper bound
+        let pi = _next_up(std::f64::consts::PI);
+        // normalize -0 so that a rectangle touching the branch cut from above stays continuous
+        let (ylo, yhi) = (if self.lo == 0. { 0. } else { self.lo }, self.hi);
+        let (xlo, xhi) = (other.lo, other.hi);
+        let crosses_cut = ylo < 0. && 0. <= yhi && xlo <= 0.;
+        if crosses_cut || (self.contains(0.) && other.contains(0.)) {
+            return Self { lo: -pi, hi: pi };
+        }
+        // atan2 is continuous on the rectangle and has no interior extremum,
+        // so the range is attained at the corners
+        let corners = [
+            ylo.atan2(xlo),
+            ylo.atan2(xhi),
+            yhi.atan2(xlo),
+            yhi.atan2(xhi),
+        ];
+        let lo = corners.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = corners.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Self {
+            lo: _next_down(lo).max(-pi),
+            hi: _next_up(hi).min(pi),
+        }
+    }
+}
+
+impl Hypot for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn hypot(self, other: &Self) -> Self::Output {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        // hypot is increasing w.r.t. absolute value of each argument
+        let abs = |x: &Self| {
+            if x.contains(0.) {
+                (0., x.hi.max(-x.lo))
+            } else if 0. < x.lo {
+                (x.lo, x.hi)
+            } else {
+                (-x.hi, -x.lo)
+            }
+        };
+        let (xlo, xhi) = abs(&self);
+        let (ylo, yhi) = abs(other);
+        Self {
+            lo: _next_down(xlo.hypot(ylo)).max(0.),
+            hi: _next_up(xhi.hypot(yhi)),
+        }
+    }
+}
+
+impl Abs for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn abs(self) -> Self::Output {
+        if self.is_nan() {
+            return Self::nan();
+        }
+        // abs is exact
+        if 0. <= self.lo {
+            self
+        } else if self.hi <= 0. {
+            Self {
+                lo: -self.hi,
+                hi: -self.lo,
+            }
+        } else {
+            Self {
+                lo: 0.,
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+}
+
+impl Max for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn max(self, other: &Self) -> Self::Output {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        // max is increasing w.r.t. each argument and exact
+        Self {
+            lo: self.lo.max(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+}
+
+impl Min for Interval<f64> {
+    type Output = Self;
+
+    #[inline]
+    fn min(self, other: &Self) -> Self::Output {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        // min is increasing w.r.t. each argument and exact
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.min(other.hi),
+        }
+    }
+}
+
+//
+// rounding helpers
+//
+/// Error of `a + b` rounded to `s`, i.e. `a + b = s + err` exactly. NaN if `s` is not finite.
+#[inline]
+fn _two_sum_err(a: f64, b: f64, s: f64) -> f64 {
+    if !s.is_finite() {
+        return f64::NAN;
+    }
+    let bb = s - a;
+    (a - (s - bb)) + (b - bb)
+}
+
+#[inline]
+fn _two_diff_err(a: f64, b: f64, s: f64) -> f64 {
+    _two_sum_err(a, -b, s)
+}
+
+/// Round `v` downward given the exact error `err` of the rounded value. NaN error means unknown.
+#[inline]
+fn _round_down(v: f64, err: f64) -> f64 {
+    if err.is_nan() || err < 0. {
+        _next_down(v)
+    } else {
+        v
+    }
+}
+
+/// Round `v` upward given the exact error `err` of the rounded value. NaN error means unknown.
+#[inline]
+fn _round_up(v: f64, err: f64) -> f64 {
+    if err.is_nan() || 0. < err {
+        _next_up(v)
+    } else {
+        v
+    }
+}
+
+#[inline]
+fn _next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x == 0. {
+        return f64::from_bits(1);
+    }
+    let bits = x.to_bits();
+    if 0. < x {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+#[inline]
+fn _next_down(x: f64) -> f64 {
+    -_next_up(-x)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::num::Real;
+
+    use super::*;
+
+    fn iv(lo: f64, hi: f64) -> Interval<f64> {
+        Interval::new(lo, hi).unwrap()
+    }
+
+    fn samples(x: &Interval<f64>) -> impl Iterator<Item = f64> + '_ {
+        (0..=100).map(|i| x.lo + (x.hi - x.lo) * i as f64 / 100.)
+    }
+
+    #[test]
+    fn test_impl_real() {
+        static_assertions::assert_impl_all!(Interval<f64>: Real);
+    }
+
+    #[rstest]
+    #[case(1., 2.)]
+    #[case(1., f64::NAN)]
+    #[case(f64::NAN, 1.)]
+    #[case(2., 1.)]
+    fn test_new(#[case] lo: f64, #[case] hi: f64) {
+        let tested = Interval::new(lo, hi);
+
+        assert_eq!(tested.is_some(), lo <= hi);
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(_next_up(1.), 1. + f64::EPSILON);
+        assert_eq!(_next_down(1.), 1. - f64::EPSILON / 2.);
+        assert_eq!(_next_up(-1.), -1. + f64::EPSILON / 2.);
+        assert_eq!(_next_up(0.), f64::from_bits(1));
+        assert_eq!(_next_down(0.), -f64::from_bits(1));
+        assert_eq!(_next_down(f64::INFINITY), f64::MAX);
+        assert_eq!(_next_up(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[rstest]
+    #[case(iv(-3., -1.))]
+    #[case(iv(-1., 1.))]
+    #[case(iv(0., 0.1))]
+    #[case(iv(0.3, 0.7))]
+    #[case(iv(1., 50.))]
+    #[case(iv(-700., -600.))]
+    fn test_exp_contains(#[case] x: Interval<f64>) {
+        let tested = x.exp();
+
+        for v in samples(&x) {
+            assert!(tested.contains(v.exp()), "exp({v}) not in {tested}");
+        }
+        assert!(0. <= tested.lo());
+    }
+
+    #[rstest]
+    #[case(iv(-1e-5, 1e-5))]
+    #[case(iv(-0.5, 0.25))]
+    #[case(iv(1., 50.))]
+    fn test_expm1_log1p_contains(#[case] x: Interval<f64>) {
+        let expm1 = x.expm1();
+        let log1p = x.log1p();
+
+        for v in samples(&x) {
+            assert!(expm1.contains(v.exp_m1()), "expm1({v}) not in {expm1}");
+            assert!(log1p.contains(v.ln_1p()), "log1p({v}) not in {log1p}");
+        }
+        assert!(-1. <= expm1.lo());
+    }
+
+    #[rstest]
+    #[case(iv(0., 0.1))]
+    #[case(iv(0.3, 0.7))]
+    #[case(iv(1., 50.))]
+    #[case(iv(2., 2.))]
+    #[case(iv(1e-300, 1e300))]
+    #[case(iv(-1., 4.))]
+    fn test_sqrt_contains(#[case] x: Interval<f64>) {
+        let tested = x.sqrt();
+
+        for v in samples(&x).filter(|v| 0. <= *v) {
+            assert!(tested.contains(v.sqrt()), "sqrt({v}) not in {tested}");
+        }
+        assert!(0. <= tested.lo());
+    }
+
+    #[test]
+    fn test_sqrt_negative() {
+        let tested = iv(-2., -1.).sqrt();
+
+        assert!(tested.is_nan());
+    }
+
+    #[rstest]
+    #[case(iv(1., 2.))]
+    #[case(iv(-3., -1.))]
+    #[case(iv(-1., 4.))]
+    #[case(iv(-4., 1.))]
+    #[case(iv(0., 0.))]
+    fn test_abs_contains(#[case] x: Interval<f64>) {
+        let tested = x.abs();
+
+        for v in samples(&x) {
+            assert!(tested.contains(v.abs()), "abs({v}) not in {tested}");
+        }
+        assert!(0. <= tested.lo());
+    }
+
+    #[rstest]
+    fn test_degenerate_matches_f64(
+        #[values(-2.5, -0.1, 0., 0.3, 1., 7., 1e10)] a: f64,
+        #[values(-3., 0.1, 0.7, 2., 1e-5)] b: f64,
+    ) {
+        let x = Interval::from(a);
+        let y = Interval::from(b);
+        let checks = [
+            (x + y, a + b),
+            (x - y, a - b),
+            (x * y, a * b),
+            (x / y, a / b),
+            (x.exp(), a.exp()),
+            (x.erf(), libm::erf(a)),
+            (Interval::from(b.abs()).sqrt(), b.abs().sqrt()),
+            (Interval::from(b.abs()).log(), b.abs().ln()),
+        ];
+
+        for (tested, expected) in checks {
+            assert!(tested.contains(expected), "{expected} not in {tested}");
+            // at most one ulp on each side of the f64 result
+            assert!(
+                _next_down(expected) <= tested.lo(),
+                "{tested} vs {expected}"
+            );
+            assert!(tested.hi() <= _next_up(expected), "{tested} vs {expected}");
+        }
+    }
+
+    #[rstest]
+    #[case(1., 2.)]
+    #[case(3., 4.)]
+    #[case(1.5, 0.5)]
+    fn test_exact_arithmetic_is_degenerate(#[case] a: f64, #[case] b: f64) {
+        let x = Interval::from(a);
+        let y = Interval::from(b);
+
+        assert_eq!(x + y, Interval::from(a + b));
+        assert_eq!(x - y, Interval::from(a - b));
+        assert_eq!(x * y, Interval::from(a * b));
+        assert_eq!(x / y, Interval::from(a / b));
+    }
+
+    #[test]
+    fn test_inexact_arithmetic_encloses() {
+        let x = Interval::from(1.);
+        let y = Interval::from(3.);
+
+        let tested = x / y;
+
+        assert!(!tested.is_degenerate());
+        assert!(tested.contains(1. / 3.));
+        // 1 / 3 * 3 must contain 1 although f64 gives 1 by chance
+        assert!((tested * y).contains(1.));
+    }
+
+    #[rstest]
+    #[case(iv(-1., 2.), iv(3., 4.), iv(-4., 8.))]
+    #[case(iv(-2., -1.), iv(-4., 3.), iv(-6., 8.))]
+    #[case(iv(0., 0.), iv(f64::NEG_INFINITY, 1.), iv(0., 0.))]
+    fn test_mul(
+        #[case] x: Interval<f64>,
+        #[case] y: Interval<f64>,
+        #[case] expected: Interval<f64>,
+    ) {
+        let tested = x * y;
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(iv(1., 2.), iv(2., 4.), iv(0.25, 1.))]
+    #[case(iv(-1., 2.), iv(-4., -2.), iv(-1., 0.5))]
+    fn test_div(
+        #[case] x: Interval<f64>,
+        #[case] y: Interval<f64>,
+        #[case] expected: Interval<f64>,
+    ) {
+        let tested = x / y;
+
+        assert_eq!(tested, expected);
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let tested = iv(1., 2.) / iv(-1., 1.);
+
+        assert_eq!(tested, Interval::entire());
+    }
+
+    #[rstest]
+    #[case(iv(-2., 3.), 2, iv(0., 9.))]
+    #[case(iv(-3., -2.), 2, iv(4., 9.))]
+    #[case(iv(-2., 3.), 3, iv(-8., 27.))]
+    #[case(iv(2., 4.), -1, iv(0.25, 0.5))]
+    #[case(iv(-2., 3.), 0, iv(1., 1.))]
+    #[case(iv(1., 1.), i32::MIN, iv(1., 1.))]
+    #[case(iv(-1., -1.), i32::MIN, iv(1., 1.))]
+    #[case(iv(1., 1.), i32::MAX, iv(1., 1.))]
+    #[case(iv(-1., -1.), i32::MAX, iv(-1., -1.))]
+    fn test_powi(#[case] x: Interval<f64>, #[case] n: i32, #[case] expected: Interval<f64>) {
+        let tested = x.powi(n);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(iv(-1., 2.), iv(3., 4.), Some(Ordering::Less))]
+    #[case(iv(3., 4.), iv(-1., 2.), Some(Ordering::Greater))]
+    #[case(iv(1., 2.), iv(1., 2.), Some(Ordering::Equal))]
+    #[case(iv(1., 3.), iv(2., 4.), None)]
+    fn test_partial_cmp(
+        #[case] x: Interval<f64>,
+        #[case] y: Interval<f64>,
+        #[case] expected: Option<Ordering>,
+    ) {
+        assert_eq!(x.partial_cmp(&y), expected);
+    }
+
+    #[rstest]
+    #[case(iv(1., 2.), iv(1., 3.))]
+    #[case(iv(1., 2.), iv(-3., -1.))]
+    #[case(iv(-2., -1.), iv(-3., -1.))]
+    #[case(iv(-2., 1.), iv(0.5, 3.))]
+    #[case(iv(0., 1.), iv(-3., -1.))]
+    #[case(iv(-0., 1.), iv(-3., -1.))]
+    fn test_atan2_contains(#[case] y: Interval<f64>, #[case] x: Interval<f64>) {
+        let tested = y.atan2(&x);
+
+        for yv in samples(&y) {
+            for xv in samples(&x) {
+                let v = yv.atan2(xv);
+                assert!(tested.contains(v), "atan2({yv}, {xv}) not in {tested}");
+            }
+        }
+        assert!(tested.width() < 2. * std::f64::consts::PI);
+    }
+
+    #[rstest]
+    #[case(iv(-1., 1.), iv(-1., -0.5))]
+    #[case(iv(-1., 1.), iv(-1., 1.))]
+    fn test_atan2_branch_cut(#[case] y: Interval<f64>, #[case] x: Interval<f64>) {
+        let tested = y.atan2(&x);
+
+        assert!(tested.contains(-std::f64::consts::PI));
+        assert!(tested.contains(std::f64::consts::PI));
+    }
+
+    #[rstest]
+    #[case(iv(3., 4.), iv(4., 5.))]
+    #[case(iv(-2., 1.), iv(0.5, 3.))]
+    #[case(iv(-2., -1.), iv(-1., 0.))]
+    #[case(iv(1e-200, 1e200), iv(1e-200, 1e200))]
+    fn test_hypot_contains(#[case] x: Interval<f64>, #[case] y: Interval<f64>) {
+        let tested = x.hypot(&y);
+
+        for xv in samples(&x) {
+            for yv in samples(&y) {
+                let v = xv.hypot(yv);
+                assert!(tested.contains(v), "hypot({xv}, {yv}) not in {tested}");
+            }
+        }
+        assert!(0. <= tested.lo());
+    }
+
+    #[rstest]
+    #[case(iv(1., 2.), iv(3., 4.))]
+    #[case(iv(-2., 1.), iv(0.5, 3.))]
+    #[case(iv(-1., 1.), iv(-1., 1.))]
+    fn test_minmax_contains(#[case] x: Interval<f64>, #[case] y: Interval<f64>) {
+        let max = x.max(&y);
+        let min = x.min(&y);
+
+        for xv in samples(&x) {
+            for yv in samples(&y) {
+                assert!(max.contains(xv.max(yv)), "max({xv}, {yv}) not in {max}");
+                assert!(min.contains(xv.min(yv)), "min({xv}, {yv}) not in {min}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_generic_real() {
+        fn poly<V: Real>(x: &V) -> V {
+            // (x + 1) * x / 3 - exp(x)
+            let three = V::nearest_value_of_f64(3.);
+            (x.clone() + &V::one()) * x / &three - &x.clone().exp()
+        }
+        let x = iv(0.1, 0.2);
+
+        let tested = poly(&x);
+
+        for v in samples(&x) {
+            assert!(tested.contains(poly(&v)));
+        }
+    }
+}
@@ -10,6 +10,14 @@ pub trait RelPos<X = Self>: PartialOrd<X> {
     ///
     /// Returns [None] if and only if `left` and `right` are equal.
     fn relpos_between(&self, left: &X, right: &X) -> Option<Self::Output>;
+
+    /// Compute the point at relative position `pos` between `left` and `right`.
+    ///
+    /// This is the inverse of [`RelPos::relpos_between`], that is,
+    /// `Self::at_pos(left, right, pos).relpos_between(left, right)` is `pos`
+    /// up to the resolution of `Self` unless `left` and `right` are equal.
+    /// `pos` out of `[0, 1]` extrapolates linearly.
+    fn at_pos(left: &X, right: &X, pos: &Self::Output) -> Self;
 }
 
 impl<T: Real> RelPos<T> for T {
@@ -24,6 +32,11 @@ impl<T: Real> RelPos<T> for T {
             Some((self.clone() - left) / &den)
         }
     }
+
+    #[inline]
+    fn at_pos(left: &T, right: &T, pos: &T) -> T {
+        left.clone() + &((right.clone() - left) * pos)
+    }
 }
 
 impl<Tz: chrono::TimeZone> RelPos for chrono::DateTime<Tz> {
@@ -44,6 +57,17 @@ impl<Tz: chrono::TimeZone> RelPos for chrono::DateTime<Tz> {
             Some((self_ - left) / (right - left))
         }
     }
+
+    /// The result is rounded to microseconds and has the timezone of `left`.
+    #[inline]
+    fn at_pos(
+        left: &chrono::DateTime<Tz>,
+        right: &chrono::DateTime<Tz>,
+        pos: &f64,
+    ) -> chrono::DateTime<Tz> {
+        let span = (right.timestamp_micros() - left.timestamp_micros()) as f64;
+        left.clone() + chrono::Duration::microseconds((span * pos).round() as i64)
+    }
 }
 
 impl RelPos for chrono::NaiveDate {
@@ -60,6 +84,13 @@ impl RelPos for chrono::NaiveDate {
             Some((self_ - left) / (right - left))
         }
     }
+
+    /// The result is rounded to the nearest date.
+    #[inline]
+    fn at_pos(left: &chrono::NaiveDate, right: &chrono::NaiveDate, pos: &f64) -> chrono::NaiveDate {
+        let span = (right.num_days_from_ce() - left.num_days_from_ce()) as f64;
+        *left + chrono::Duration::days((span * pos).round() as i64)
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +143,64 @@ mod tests {
         assert_eq!(invtested, expected.map(|x| 1.0 - x));
     }
 
+    #[rstest]
+    #[case(0.0, 10.0, 0.0, 0.0)]
+    #[case(0.0, 10.0, 0.5, 5.0)]
+    #[case(0.0, 10.0, 1.0, 10.0)]
+    #[case(0.0, 10.0, 1.5, 15.0)]
+    #[case(0.0, 10.0, -0.5, -5.0)]
+    #[case(1.5, 2.5, 0.25, 1.75)]
+    #[case(2.5, 1.5, 0.25, 2.25)]
+    #[case(0.5, 0.5, 0.3, 0.5)]
+    fn test_at_pos(#[case] left: f64, #[case] right: f64, #[case] pos: f64, #[case] expected: f64) {
+        let tested = f64::at_pos(&left, &right, &pos);
+
+        assert_eq!(tested, expected);
+        if left != right {
+            assert_eq!(tested.relpos_between(&left, &right), Some(pos));
+        }
+    }
+
+    #[rstest]
+    #[case(0.0, chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap())]
+    #[case(0.5, chrono::NaiveDate::from_ymd_opt(2021, 1, 6).unwrap())]
+    #[case(1.0, chrono::NaiveDate::from_ymd_opt(2021, 1, 11).unwrap())]
+    #[case(1.5, chrono::NaiveDate::from_ymd_opt(2021, 1, 16).unwrap())]
+    #[case(-0.5, chrono::NaiveDate::from_ymd_opt(2020, 12, 27).unwrap())]
+    #[case(0.26, chrono::NaiveDate::from_ymd_opt(2021, 1, 4).unwrap())]
+    fn test_at_pos_naive_date(#[case] pos: f64, #[case] expected: chrono::NaiveDate) {
+        let left = chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let right = chrono::NaiveDate::from_ymd_opt(2021, 1, 11).unwrap();
+
+        let tested = chrono::NaiveDate::at_pos(&left, &right, &pos);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(0.0, datetime(2021, 1, 1, 0))]
+    #[case(0.5, datetime(2021, 1, 6, 0))]
+    #[case(1.0, datetime(2021, 1, 11, 0))]
+    #[case(1.5, datetime(2021, 1, 16, 0))]
+    #[case(-0.5, datetime(2020, 12, 27, 0))]
+    #[case(0.0125, datetime(2021, 1, 1, 3))]
+    fn test_at_pos_chrono(
+        #[case] pos: f64,
+        #[case] expected: chrono::DateTime<chrono::FixedOffset>,
+    ) {
+        let left = datetime(2021, 1, 1, 0);
+        let right = datetime(2021, 1, 11, 0);
+
+        let tested = chrono::DateTime::at_pos(&left, &right, &pos);
+
+        assert_eq!(tested, expected);
+        approx::assert_abs_diff_eq!(
+            tested.relpos_between(&left, &right).unwrap(),
+            pos,
+            epsilon = 1e-12
+        );
+    }
+
     #[rstest]
     #[case(
         chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
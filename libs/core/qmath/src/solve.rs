@@ -0,0 +1,7 @@
+mod brent;
+mod error;
+mod newton;
+
+pub use brent::brent;
+pub use error::SolveError;
+pub use newton::newton;
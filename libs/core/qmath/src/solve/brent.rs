@@ -0,0 +1,170 @@
+use super::SolveError;
+
+// -----------------------------------------------------------------------------
+// brent
+// -----------------------------------------------------------------------------
+/// Find a root of `f` in the bracket `[a, b]` with Brent's method.
+///
+/// Brent's method combines bisection, secant and inverse quadratic interpolation.
+/// It keeps the root bracketed, so it converges whenever `f` is continuous
+/// and `f(a)` and `f(b)` have opposite signs.
+///
+/// Iteration stops when the bracket becomes narrower than `tol`
+/// (plus a few ulps relative to the estimate) or an exact root is hit.
+///
+/// # Errors
+/// - [`SolveError::NoBracket`] if `f(a)` and `f(b)` have the same sign.
+/// - [`SolveError::NonFinite`] if `f` returns NaN or infinity.
+/// - [`SolveError::MaxIterations`] if not converged within `max_iter` evaluations.
+///
+/// # Example
+/// ```
+/// use qmath::solve::brent;
+///
+/// let root = brent(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+///
+/// approx::assert_abs_diff_eq!(root, 2f64.sqrt(), epsilon = 1e-12);
+/// ```
+pub fn brent(
+    f: impl Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, SolveError> {
+    let eval = |x: f64| {
+        let value = f(x);
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(SolveError::NonFinite { x, value })
+        }
+    };
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (eval(a)?, eval(b)?);
+    if fa == 0. {
+        return Ok(a);
+    }
+    if fb == 0. {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() {
+        return Err(SolveError::NoBracket { a, fa, b, fb });
+    }
+
+    // `b` is the current estimate, `c` is the counterpart of the bracket
+    // and `a` is the previous estimate.
+    let (mut c, mut fc) = (b, fb);
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..max_iter {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            (a, b, c) = (b, c, b);
+            (fa, fb, fc) = (fb, fc, fb);
+        }
+        let tol1 = 2. * f64::EPSILON * b.abs() + 0.5 * tol;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0. {
+            return Ok(b);
+        }
+
+        if tol1 <= e.abs() && fb.abs() < fa.abs() {
+            // try interpolation
+            let s = fb / fa;
+            let (p, q) = if a == c {
+                // secant
+                (2. * xm * s, 1. - s)
+            } else {
+                // inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2. * xm * q * (q - r) - (b - a) * (r - 1.)),
+                    (q - 1.) * (r - 1.) * (s - 1.),
+                )
+            };
+            let (p, q) = if 0. < p { (p, -q) } else { (-p, q) };
+            if 2. * p < (3. * xm * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                // interpolation does not work well, so fall back to bisection
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if tol1 < d.abs() { d } else { tol1.copysign(xm) };
+        fb = eval(b)?;
+    }
+    Err(SolveError::MaxIterations {
+        iterations: max_iter,
+        last: b,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(|x: f64| x * x - 2.0, 0.0, 2.0, 2f64.sqrt())]
+    #[case(|x: f64| (x - 1.0) * (x + 2.0) * (x - 3.0), 2.0, 5.0, 3.0)]
+    #[case(|x: f64| (x - 1.0) * (x + 2.0) * (x - 3.0), -5.0, 0.0, -2.0)]
+    #[case(|x: f64| x.powi(3) - x - 1.0, 1.0, 2.0, 1.324717957244746)]
+    #[case(|x: f64| x.cos() - x, 0.0, 1.0, 0.7390851332151607)]
+    #[case(|x: f64| (x - 0.5).cbrt(), -1.0, 3.0, 0.5)]
+    fn test_brent(
+        #[case] f: fn(f64) -> f64,
+        #[case] a: f64,
+        #[case] b: f64,
+        #[case] expected: f64,
+    ) {
+        let tested = brent(f, a, b, 1e-14, 100).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_brent_endpoint_root() {
+        let tested = brent(|x| x - 1.0, 1.0, 2.0, 1e-12, 100).unwrap();
+
+        assert_eq!(tested, 1.0);
+    }
+
+    #[test]
+    fn test_brent_no_bracket() {
+        let tested = brent(|x| x * x + 1.0, -1.0, 2.0, 1e-12, 100);
+
+        assert!(matches!(tested, Err(SolveError::NoBracket { .. })));
+    }
+
+    #[test]
+    fn test_brent_non_finite() {
+        let tested = brent(|x| 1.0 / x - 1.0, 0.0, 2.0, 1e-12, 100);
+
+        assert!(matches!(tested, Err(SolveError::NonFinite { x, .. }) if x == 0.0));
+    }
+
+    #[test]
+    fn test_brent_max_iterations() {
+        let tested = brent(|x| (x - 0.5).cbrt(), -1.0, 3.0, 0.0, 3);
+
+        assert!(matches!(
+            tested,
+            Err(SolveError::MaxIterations { iterations: 3, .. })
+        ));
+    }
+}
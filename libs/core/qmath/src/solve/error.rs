@@ -0,0 +1,17 @@
+// -----------------------------------------------------------------------------
+// SolveError
+// -----------------------------------------------------------------------------
+/// Error of one-dimensional root finding.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SolveError {
+    #[error("root is not bracketed: f({a})={fa}, f({b})={fb}")]
+    NoBracket { a: f64, fa: f64, b: f64, fb: f64 },
+    #[error("not converged within {iterations} iterations. last estimate: {last}")]
+    MaxIterations { iterations: usize, last: f64 },
+    #[error("non-finite evaluation: f({x})={value}")]
+    NonFinite { x: f64, value: f64 },
+    #[error("derivative is zero or non-finite without bracket: f'({x})={value}")]
+    DegenerateDerivative { x: f64, value: f64 },
+    #[error("step halving does not reduce residual without bracket: f({x})={value}")]
+    Stalled { x: f64, value: f64 },
+}
@@ -0,0 +1,215 @@
+use super::SolveError;
+
+// -----------------------------------------------------------------------------
+// newton
+// -----------------------------------------------------------------------------
+/// Find a root of `f` with safeguarded Newton's method starting from `x0`.
+///
+/// `df` is the derivative of `f`.
+/// Plain Newton's method may diverge or oscillate far from the root,
+/// so each step is guarded as follows.
+/// - Until a sign change of `f` is observed, a step is halved while it does not reduce `|f|`.
+/// - Once a sign change is observed, the root is kept bracketed and a Newton step
+///   falling outside of the bracket (or with degenerate derivative) is replaced with bisection.
+///
+/// Iteration stops when the bracket becomes smaller than `tol` or an exact root is hit.
+/// Before a bracket is found, a step smaller than `tol` is accepted as convergence
+/// only if the residual `|f|` is also not greater than `tol`,
+/// since small steps may come from step halving rather than from closeness to a root.
+///
+/// # Errors
+/// - [`SolveError::NonFinite`] if `f` returns NaN or infinity and step halving does not recover.
+/// - [`SolveError::DegenerateDerivative`] if `df` is zero or non-finite before any bracket is found.
+/// - [`SolveError::Stalled`] if step halving does not reduce `|f|` before any bracket is found.
+/// - [`SolveError::MaxIterations`] if not converged within `max_iter` iterations.
+///
+/// # Example
+/// ```
+/// use qmath::solve::newton;
+///
+/// let root = newton(|x| x * x - 2.0, |x| 2.0 * x, 1.0, 1e-12, 100).unwrap();
+///
+/// approx::assert_abs_diff_eq!(root, 2f64.sqrt(), epsilon = 1e-12);
+/// ```
+pub fn newton(
+    f: impl Fn(f64) -> f64,
+    df: impl Fn(f64) -> f64,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, SolveError> {
+    const MAX_HALVING: usize = 64;
+
+    let mut x = x0;
+    let mut fx = f(x);
+    if !fx.is_finite() {
+        return Err(SolveError::NonFinite { x, value: fx });
+    }
+    // (point where f is negative, point where f is positive)
+    let mut bracket: Option<(f64, f64)> = None;
+    for _ in 0..max_iter {
+        if fx == 0. {
+            return Ok(x);
+        }
+        let dfx = df(x);
+        let step = -fx / dfx;
+        let mut next = match bracket {
+            Some((neg, pos)) => {
+                let (lo, hi) = (neg.min(pos), neg.max(pos));
+                let cand = x + step;
+                if cand.is_finite() && lo < cand && cand < hi {
+                    cand
+                } else {
+                    0.5 * (neg + pos)
+                }
+            }
+            None if step.is_finite() => x + step,
+            None => return Err(SolveError::DegenerateDerivative { x, value: dfx }),
+        };
+        let mut fnext = f(next);
+        if bracket.is_none() {
+            // backtrack while the step makes things worse without crossing the root
+            let mut n = 0;
+            while !fnext.is_finite() || (fx.abs() <= fnext.abs() && fx.signum() == fnext.signum()) {
+                if n == MAX_HALVING {
+                    if !fnext.is_finite() {
+                        break;
+                    }
+                    // no further improvement is possible, e.g. at machine precision
+                    if fx.abs() <= tol {
+                        return Ok(x);
+                    }
+                    return Err(SolveError::Stalled { x, value: fx });
+                }
+                next = x + 0.5 * (next - x);
+                fnext = f(next);
+                n += 1;
+            }
+        }
+        if !fnext.is_finite() {
+            return Err(SolveError::NonFinite {
+                x: next,
+                value: fnext,
+            });
+        }
+
+        // update bracket
+        bracket = match bracket {
+            _ if fnext.signum() != fx.signum() => {
+                Some(if fnext < 0. { (next, x) } else { (x, next) })
+            }
+            Some((_, pos)) if fnext < 0. => Some((next, pos)),
+            Some((neg, _)) => Some((neg, next)),
+            None => None,
+        };
+
+        let converged = match bracket {
+            Some((neg, pos)) => (pos - neg).abs() <= tol || (next - x).abs() <= tol,
+            None => (next - x).abs() <= tol && fnext.abs() <= tol,
+        };
+        x = next;
+        fx = fnext;
+        if converged {
+            return Ok(x);
+        }
+    }
+    Err(SolveError::MaxIterations {
+        iterations: max_iter,
+        last: x,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, 1.0, 2f64.sqrt())]
+    #[case(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, -0.5, -(2f64.sqrt()))]
+    #[case(
+        |x: f64| (x - 1.0) * (x + 2.0) * (x - 3.0),
+        |x: f64| 3.0 * x * x - 4.0 * x - 5.0,
+        4.0,
+        3.0
+    )]
+    #[case(|x: f64| x.powi(3) - x - 1.0, |x: f64| 3.0 * x * x - 1.0, 1.5, 1.324717957244746)]
+    #[case(|x: f64| x.cos() - x, |x: f64| -x.sin() - 1.0, 0.0, 0.7390851332151607)]
+    fn test_newton(
+        #[case] f: fn(f64) -> f64,
+        #[case] df: fn(f64) -> f64,
+        #[case] x0: f64,
+        #[case] expected: f64,
+    ) {
+        let tested = newton(f, df, x0, 1e-14, 100).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-12);
+    }
+
+    #[rstest]
+    // plain newton step for cbrt is x -> -2x, which diverges from any non-root point
+    #[case(|x: f64| (x - 0.5).cbrt(), |x: f64| (x - 0.5).cbrt().powi(-2) / 3.0, 1.5, 0.5)]
+    // plain newton overshoots and diverges for atan when the initial point is far from the root
+    #[case(|x: f64| x.atan(), |x: f64| 1.0 / (1.0 + x * x), 3.0, 0.0)]
+    fn test_newton_pathological(
+        #[case] f: fn(f64) -> f64,
+        #[case] df: fn(f64) -> f64,
+        #[case] x0: f64,
+        #[case] expected: f64,
+    ) {
+        let mut x = x0;
+        for _ in 0..10 {
+            x -= f(x) / df(x);
+        }
+        assert!(
+            (expected - x).abs() > 1.0,
+            "plain newton unexpectedly converged"
+        );
+
+        let tested = newton(f, df, x0, 1e-14, 200).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_newton_degenerate_derivative() {
+        let tested = newton(|x| x * x + 1.0, |x| 2.0 * x, 0.0, 1e-12, 100);
+
+        assert!(matches!(
+            tested,
+            Err(SolveError::DegenerateDerivative { x, .. }) if x == 0.0
+        ));
+    }
+
+    #[test]
+    fn test_newton_non_finite() {
+        let tested = newton(|x: f64| x.ln(), |x| 1.0 / x, -1.0, 1e-12, 100);
+
+        assert!(matches!(tested, Err(SolveError::NonFinite { .. })));
+    }
+
+    #[rstest]
+    #[case(1e-6)]
+    #[case(1e-8)]
+    fn test_newton_no_root_small_step(#[case] tol: f64) {
+        // step halving shrinks steps around the minimum of x^2 + 1, which is not a root
+        let tested = newton(|x| x * x + 1.0, |x| 2.0 * x, 0.3, tol, 100);
+
+        assert!(
+            matches!(tested, Err(SolveError::Stalled { .. })),
+            "unexpected result: {tested:?}"
+        );
+    }
+
+    #[test]
+    fn test_newton_max_iterations() {
+        // newton steps only halve the distance to the root from such a far point
+        let tested = newton(|x| x * x - 2.0, |x| 2.0 * x, 1e6, 1e-12, 20);
+
+        assert!(matches!(
+            tested,
+            Err(SolveError::MaxIterations { iterations: 20, .. })
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+mod normal;
+
+pub use normal::{lognorm_cdf, lognorm_inv_cdf, lognorm_pdf, norm_cdf, norm_inv_cdf, norm_pdf};
@@ -0,0 +1,275 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+// -----------------------------------------------------------------------------
+// Normal distribution
+// -----------------------------------------------------------------------------
+/// Probability density function of the standard normal distribution.
+///
+/// # Example
+/// ```
+/// use qmath::stats::norm_pdf;
+///
+/// approx::assert_abs_diff_eq!(norm_pdf(0.0), 0.3989422804014327, epsilon = 1e-15);
+/// ```
+#[inline]
+pub fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Cumulative distribution function of the standard normal distribution.
+///
+/// This is calculated with the complementary error function to keep accuracy in the lower tail.
+///
+/// # Example
+/// ```
+/// use qmath::stats::norm_cdf;
+///
+/// assert_eq!(norm_cdf(0.0), 0.5);
+/// approx::assert_abs_diff_eq!(norm_cdf(1.96), 0.9750021048517795, epsilon = 1e-15);
+/// ```
+#[inline]
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * libm::erfc(-x * FRAC_1_SQRT_2)
+}
+
+/// Inverse of the cumulative distribution function of the standard normal distribution.
+///
+/// This uses Acklam's rational approximation followed by one step of Halley's refinement,
+/// which gives almost full double precision.
+///
+/// # Domain
+/// - `p` in `(0, 1)` always gives a finite value.
+///   Positive subnormal `p` is clamped to [`f64::MIN_POSITIVE`],
+///   so the result is bounded below by `norm_inv_cdf(f64::MIN_POSITIVE)`, about `-37.5`.
+///   Because `1 - p` can not be smaller than `f64::EPSILON / 2` in double precision,
+///   the result is bounded above by about `8.3`.
+/// - `p == 0` and `p == 1` give negative and positive infinity respectively.
+/// - Otherwise, including NaN, this returns NaN.
+///
+/// # Example
+/// ```
+/// use qmath::stats::norm_inv_cdf;
+///
+/// assert_eq!(norm_inv_cdf(0.5), 0.0);
+/// approx::assert_abs_diff_eq!(norm_inv_cdf(0.975), 1.959963984540054, epsilon = 1e-12);
+/// ```
+pub fn norm_inv_cdf(p: f64) -> f64 {
+    // coefficients of Acklam's algorithm
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+    const P_HIGH: f64 = 1.0 - P_LOW;
+
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    if p == 0.5 {
+        return 0.0;
+    }
+    let p = p.max(f64::MIN_POSITIVE);
+
+    let x = if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= P_HIGH {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    // Halley's refinement. This is skipped in the far tail where the density underflows.
+    let e = norm_cdf(x) - p;
+    let u = e * (2.0 * PI).sqrt() * (0.5 * x * x).exp();
+    if u.is_finite() {
+        x - u / (1.0 + 0.5 * x * u)
+    } else {
+        x
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Lognormal distribution
+// -----------------------------------------------------------------------------
+/// Probability density function of the lognormal distribution,
+/// that is, the distribution of `exp(mu + sigma * Z)` with a standard normal `Z`.
+///
+/// This returns `0` for non-positive `x`. `sigma` must be positive.
+#[inline]
+pub fn lognorm_pdf(x: f64, mu: f64, sigma: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    norm_pdf((x.ln() - mu) / sigma) / (x * sigma)
+}
+
+/// Cumulative distribution function of the lognormal distribution.
+///
+/// This returns `0` for non-positive `x`. `sigma` must be positive.
+#[inline]
+pub fn lognorm_cdf(x: f64, mu: f64, sigma: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    norm_cdf((x.ln() - mu) / sigma)
+}
+
+/// Inverse of the cumulative distribution function of the lognormal distribution.
+///
+/// The domain of `p` follows [`norm_inv_cdf`].
+#[inline]
+pub fn lognorm_inv_cdf(p: f64, mu: f64, sigma: f64) -> f64 {
+    (mu + sigma * norm_inv_cdf(p)).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0.0, 0.3989422804014327)]
+    #[case(1.0, 0.24197072451914337)]
+    #[case(-1.0, 0.24197072451914337)]
+    #[case(3.0, 0.0044318484119380075)]
+    fn test_norm_pdf(#[case] x: f64, #[case] expected: f64) {
+        let tested = norm_pdf(x);
+
+        assert_abs_diff_eq!(tested, expected, epsilon = 1e-15);
+    }
+
+    #[rstest]
+    #[case(0.0, 0.5)]
+    #[case(1.0, 0.8413447460685429)]
+    #[case(-1.0, 0.15865525393145707)]
+    #[case(1.959963984540054, 0.975)]
+    #[case(-5.0, 2.866515718791939e-7)]
+    #[case(-10.0, 7.619853024160527e-24)]
+    fn test_norm_cdf(#[case] x: f64, #[case] expected: f64) {
+        let tested = norm_cdf(x);
+
+        assert_abs_diff_eq!(tested, expected, epsilon = expected * 1e-12);
+    }
+
+    #[rstest]
+    #[case(0.5, 0.0)]
+    #[case(0.975, 1.959963984540054)]
+    #[case(0.025, -1.959963984540054)]
+    #[case(0.8413447460685429, 1.0)]
+    #[case(0.01, -2.3263478740408408)]
+    #[case(0.99, 2.3263478740408408)]
+    #[case(1e-10, -6.361340902404056)]
+    fn test_norm_inv_cdf(#[case] p: f64, #[case] expected: f64) {
+        let tested = norm_inv_cdf(p);
+
+        assert_abs_diff_eq!(tested, expected, epsilon = 1e-9);
+    }
+
+    #[rstest]
+    fn test_norm_inv_cdf_roundtrip(
+        #[values(1e-300, 1e-100, 1e-20, 1e-5, 0.02, 0.3, 0.5, 0.7, 0.98, 0.99999)] p: f64,
+    ) {
+        let x = norm_inv_cdf(p);
+
+        let tested = norm_cdf(x);
+
+        assert_abs_diff_eq!(tested, p, epsilon = p * 1e-12);
+    }
+
+    #[rstest]
+    #[case(f64::MIN_POSITIVE)]
+    #[case(f64::MIN_POSITIVE / 1e10)]
+    #[case(1.0 - f64::EPSILON / 2.0)]
+    fn test_norm_inv_cdf_finite_near_bounds(#[case] p: f64) {
+        let tested = norm_inv_cdf(p);
+
+        assert!(tested.is_finite(), "{tested}");
+        assert!((-38.0..9.0).contains(&tested), "{tested}");
+    }
+
+    #[rstest]
+    #[case(0.0, f64::NEG_INFINITY)]
+    #[case(1.0, f64::INFINITY)]
+    fn test_norm_inv_cdf_bounds(#[case] p: f64, #[case] expected: f64) {
+        let tested = norm_inv_cdf(p);
+
+        assert_eq!(tested, expected);
+    }
+
+    #[rstest]
+    #[case(-0.1)]
+    #[case(1.1)]
+    #[case(f64::NAN)]
+    fn test_norm_inv_cdf_nan(#[case] p: f64) {
+        let tested = norm_inv_cdf(p);
+
+        assert!(tested.is_nan());
+    }
+
+    #[rstest]
+    #[case(1.0, 0.0, 1.0)]
+    #[case(2.0, 0.1, 0.3)]
+    #[case(0.5, -0.2, 0.5)]
+    fn test_lognorm(#[case] x: f64, #[case] mu: f64, #[case] sigma: f64) {
+        let z = (x.ln() - mu) / sigma;
+
+        let pdf = lognorm_pdf(x, mu, sigma);
+        let cdf = lognorm_cdf(x, mu, sigma);
+        let inv = lognorm_inv_cdf(cdf, mu, sigma);
+
+        assert_abs_diff_eq!(pdf, norm_pdf(z) / (x * sigma), epsilon = 1e-15);
+        assert_abs_diff_eq!(cdf, norm_cdf(z), epsilon = 1e-15);
+        assert_abs_diff_eq!(inv, x, epsilon = 1e-12);
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-1.0)]
+    fn test_lognorm_non_positive(#[case] x: f64) {
+        let pdf = lognorm_pdf(x, 0.0, 1.0);
+        let cdf = lognorm_cdf(x, 0.0, 1.0);
+
+        assert_eq!(pdf, 0.0);
+        assert_eq!(cdf, 0.0);
+    }
+}
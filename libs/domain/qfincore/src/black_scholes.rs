@@ -0,0 +1,198 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use anyhow::ensure;
+use qmath::num::Real;
+
+use crate::quantity::Volatility;
+
+// -----------------------------------------------------------------------------
+// price
+// -----------------------------------------------------------------------------
+/// Undiscounted Black-Scholes price of an European option on a forward.
+///
+/// `time` is the year fraction to the expiry measured with the day count of `vol`.
+/// Normal CDF is calculated with [`qmath::num::Erf`], so that gradients with respect to
+/// forward, strike and volatility flow through automatic differentiation types.
+/// When the total variance is zero, the intrinsic value is returned.
+///
+/// # Errors
+/// Returns an error if `forward` or `strike` is not positive,
+/// or `time` or the volatility is negative.
+///
+/// # Example
+/// ```
+/// use qfincore::{black_scholes, daycount::Act365f, quantity::Volatility};
+///
+/// let vol = Volatility {
+///     day_count: Act365f,
+///     value: 0.2,
+/// };
+///
+/// let call = black_scholes::price(&100., &100., &vol, 1., true).unwrap();
+///
+/// approx::assert_abs_diff_eq!(call, 7.965567455405804, epsilon = 1e-12);
+/// ```
+pub fn price<Dcf, V: Real>(
+    forward: &V,
+    strike: &V,
+    vol: &Volatility<Dcf, V>,
+    time: f64,
+    is_call: bool,
+) -> anyhow::Result<V> {
+    ensure!(
+        V::zero() < *forward,
+        "forward must be positive, but got {forward}"
+    );
+    ensure!(
+        V::zero() < *strike,
+        "strike must be positive, but got {strike}"
+    );
+    ensure!(
+        0. <= time,
+        "time to expiry must be non-negative, but got {time}"
+    );
+    ensure!(
+        V::zero() <= vol.value,
+        "volatility must be non-negative, but got {}",
+        vol.value
+    );
+    let sign = V::nearest_value_of_f64(if is_call { 1. } else { -1. });
+    let stdev = vol.value.clone() * &V::nearest_value_of_f64(time.sqrt());
+    if stdev <= V::zero() {
+        let intrinsic = (forward.clone() - strike) * &sign;
+        return Ok(if V::zero() < intrinsic {
+            intrinsic
+        } else {
+            V::zero()
+        });
+    }
+    let half = V::nearest_value_of_f64(0.5);
+    let d1 = (forward.clone() / strike).log() / &stdev + &(stdev.clone() * &half);
+    let d2 = d1.clone() - &stdev;
+    let fwd_leg = forward.clone() * &_norm_cdf(d1 * &sign);
+    let strike_leg = strike.clone() * &_norm_cdf(d2 * &sign);
+    Ok((fwd_leg - &strike_leg) * &sign)
+}
+
+#[inline]
+fn _norm_cdf<V: Real>(x: V) -> V {
+    let half = V::nearest_value_of_f64(0.5);
+    let erf = (x * &V::nearest_value_of_f64(FRAC_1_SQRT_2)).erf();
+    (V::one() + &erf) * &half
+}
+
+#[cfg(test)]
+mod tests {
+    use qmath::stats::norm_cdf;
+    use rstest::rstest;
+
+    use crate::daycount::Act365f;
+
+    use super::*;
+
+    fn vol<V>(value: V) -> Volatility<Act365f, V> {
+        Volatility {
+            day_count: Act365f,
+            value,
+        }
+    }
+
+    #[rstest]
+    #[case(100., 100., 0.2, 1.)]
+    #[case(100., 120., 0.3, 0.5)]
+    #[case(100., 80., 0.15, 2.)]
+    #[case(0.03, 0.035, 0.01, 5.)]
+    fn test_put_call_parity(
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] sigma: f64,
+        #[case] time: f64,
+    ) {
+        let call = price(&forward, &strike, &vol(sigma), time, true).unwrap();
+        let put = price(&forward, &strike, &vol(sigma), time, false).unwrap();
+
+        approx::assert_abs_diff_eq!(call - put, forward - strike, epsilon = 1e-12 * forward);
+    }
+
+    #[rstest]
+    #[case(120., 100., true, 20.)]
+    #[case(80., 100., true, 0.)]
+    #[case(120., 100., false, 0.)]
+    #[case(80., 100., false, 20.)]
+    fn test_intrinsic(
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] is_call: bool,
+        #[case] expected: f64,
+    ) {
+        let zero_vol = price(&forward, &strike, &vol(0.), 1., is_call).unwrap();
+        let zero_time = price(&forward, &strike, &vol(0.2), 0., is_call).unwrap();
+
+        assert_eq!(zero_vol, expected);
+        assert_eq!(zero_time, expected);
+    }
+
+    #[rstest]
+    #[case(100., 100., -0.1, 1.)]
+    #[case(100., 100., 0.2, -1.)]
+    #[case(0., 100., 0.2, 1.)]
+    #[case(-100., 100., 0.2, 1.)]
+    #[case(100., 0., 0.2, 1.)]
+    #[case(100., -100., 0.2, 1.)]
+    #[case(f64::NAN, 100., 0.2, 1.)]
+    #[case(100., f64::NAN, 0.2, 1.)]
+    fn test_price_err(
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] sigma: f64,
+        #[case] time: f64,
+    ) {
+        let res = price(&forward, &strike, &vol(sigma), time, true);
+
+        assert!(res.is_err());
+    }
+
+    #[rstest]
+    #[case(100., 100., 0.2, 1., true)]
+    #[case(100., 100., 0.2, 1., false)]
+    #[case(100., 120., 0.3, 0.5, true)]
+    #[case(100., 80., 0.15, 2., false)]
+    fn test_greeks_autodiff(
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] sigma: f64,
+        #[case] time: f64,
+        #[case] is_call: bool,
+    ) {
+        let graph = qautodiff::Graph::new();
+        let f: qautodiff::Expr<&str, f64> = graph.create_var("fwd", forward).unwrap().into();
+        let s: qautodiff::Expr<&str, f64> = graph.create_var("vol", sigma).unwrap().into();
+        let k = qautodiff::Expr::from(strike);
+
+        let tested = price(&f, &k, &vol(s), time, is_call).unwrap();
+
+        let grads: std::collections::HashMap<_, _> = tested.grads().unwrap().collect();
+        let h = 1e-4;
+        let fd_delta = (price(&(forward + h), &strike, &vol(sigma), time, is_call).unwrap()
+            - price(&(forward - h), &strike, &vol(sigma), time, is_call).unwrap())
+            / (2. * h);
+        let fd_vega = (price(&forward, &strike, &vol(sigma + h), time, is_call).unwrap()
+            - price(&forward, &strike, &vol(sigma - h), time, is_call).unwrap())
+            / (2. * h);
+        let stdev = sigma * time.sqrt();
+        let d1 = (forward / strike).ln() / stdev + 0.5 * stdev;
+        let delta = if is_call {
+            norm_cdf(d1)
+        } else {
+            norm_cdf(d1) - 1.
+        };
+        approx::assert_abs_diff_eq!(
+            tested.value(),
+            price(&forward, &strike, &vol(sigma), time, is_call).unwrap(),
+            epsilon = 1e-12
+        );
+        approx::assert_abs_diff_eq!(grads[&"fwd"], fd_delta, epsilon = 1e-6);
+        approx::assert_abs_diff_eq!(grads[&"fwd"], delta, epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(grads[&"vol"], fd_vega, epsilon = 1e-5);
+    }
+}
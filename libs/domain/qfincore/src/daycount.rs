@@ -1,12 +1,22 @@
 mod act360;
 mod act365f;
+mod actacticma;
+mod actactisda;
 mod bd252;
+mod business_time;
+mod thirty360;
+mod thirty360e;
 mod traits;
 mod variant;
 
+pub use business_time::business_time_between;
 pub use traits::{StateLessYearFrac, YearFrac};
 pub use variant::{DayCount, DayCountSrc, DayCountSym};
 
 pub use act360::Act360;
 pub use act365f::Act365f;
+pub use actacticma::ActActIcma;
+pub use actactisda::ActActIsda;
 pub use bd252::Bd252;
+pub use thirty360::Thirty360;
+pub use thirty360e::Thirty360E;
@@ -0,0 +1,259 @@
+use std::convert::Infallible;
+
+use anyhow::ensure;
+use qchrono::{
+    ext::chrono::Datelike,
+    timepoint::{Date, DateExtensions, DateTime},
+};
+
+use super::YearFrac;
+
+// -----------------------------------------------------------------------------
+// ActActIcma
+// -----------------------------------------------------------------------------
+/// Actual/Actual (ICMA) day count fraction in ISDA 2006 definitions.
+///
+/// Year fraction of a period is the sum over regular coupon periods overlapping with it of
+/// `days / (frequency * days_in_coupon_period)`, where `days` is the number of days in the overlap.
+/// Hence a regular coupon period is always `1 / frequency`, and irregular (short or long) periods
+/// are measured against notional regular periods.
+///
+/// Regular coupon dates are generated by shifting an anchor, a regular coupon date of the schedule,
+/// by multiples of `12 / frequency` months. If the anchor is the end of a month,
+/// so are generated dates.
+/// [`YearFrac::year_frac`] uses the end date of the period as the anchor, that is, it assumes that
+/// the period ends on a regular coupon date. Use [`ActActIcma::year_frac_with_anchor`] otherwise.
+///
+/// # Example
+/// ```
+/// use qchrono::timepoint::Date;
+/// use qfincore::daycount::{ActActIcma, YearFrac};
+///
+/// let dcf = ActActIcma::new(2).unwrap();
+/// let start: Date = "2003-11-01".parse().unwrap();
+/// let end: Date = "2004-05-01".parse().unwrap();
+///
+/// assert_eq!(dcf.year_frac(&start, &end).unwrap(), 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActActIcma {
+    frequency: u8,
+}
+
+//
+// ctor
+//
+impl ActActIcma {
+    /// Create a day count fraction with the number of coupons per year.
+    ///
+    /// # Errors
+    /// - If `frequency` is not a divisor of 12, i.e. none of 1, 2, 3, 4, 6 and 12
+    #[inline]
+    pub fn new(frequency: u8) -> anyhow::Result<Self> {
+        ensure!(
+            frequency != 0 && 12 % frequency == 0,
+            "Coupon frequency must be a divisor of 12: {frequency}"
+        );
+        Ok(Self { frequency })
+    }
+}
+
+//
+// methods
+//
+impl ActActIcma {
+    /// Number of coupons per year.
+    #[inline]
+    pub fn frequency(&self) -> u8 {
+        self.frequency
+    }
+
+    /// Year fraction with regular coupon dates generated from the given anchor.
+    ///
+    /// The anchor does not need to be in the period.
+    /// Coupon dates which are not representable are clamped to [`Date::MIN`] or [`Date::MAX`],
+    /// so periods near these bounds are measured against the clamped coupon periods.
+    ///
+    /// # Example
+    /// ```
+    /// use qchrono::timepoint::Date;
+    /// use qfincore::daycount::ActActIcma;
+    ///
+    /// // long first period of a quarterly bond paying on the end of Feb, May, Aug and Nov
+    /// let dcf = ActActIcma::new(4).unwrap();
+    /// let start: Date = "1999-11-30".parse().unwrap();
+    /// let end: Date = "2000-04-30".parse().unwrap();
+    /// let anchor: Date = "2000-05-31".parse().unwrap();
+    ///
+    /// let expected = 91. / (4. * 91.) + 61. / (4. * 92.);
+    /// approx::assert_abs_diff_eq!(dcf.year_frac_with_anchor(&start, &end, &anchor), expected, epsilon = 1e-15);
+    /// ```
+    pub fn year_frac_with_anchor(&self, start: &Date, end: &Date, anchor: &Date) -> f64 {
+        if end < start {
+            return -self.year_frac_with_anchor(end, start, anchor);
+        }
+        let months = 12 / self.frequency as i32;
+        let eom = anchor.is_end_of_month();
+        let coupon_date = |k: i32| {
+            let d = anchor.add_months_clamped(k * months);
+            if eom {
+                d.end_of_month()
+            } else {
+                d
+            }
+        };
+
+        // coupon_date(k) <= start < coupon_date(k + 1)
+        let diff =
+            12 * (start.year() - anchor.year()) + start.month() as i32 - anchor.month() as i32;
+        let mut k = diff.div_euclid(months);
+        while *start < coupon_date(k) {
+            k -= 1;
+        }
+        // coupon dates are clamped to `Date::MAX`, so stop when they no longer advance
+        while coupon_date(k) < coupon_date(k + 1) && coupon_date(k + 1) <= *start {
+            k += 1;
+        }
+
+        let freq = self.frequency as f64;
+        let mut res = 0.0;
+        let mut lo = coupon_date(k);
+        while lo < *end {
+            let hi = coupon_date(k + 1);
+            let days = (hi.min(*end) - lo.max(*start)).num_days();
+            res += days as f64 / (freq * (hi - lo).num_days() as f64);
+            k += 1;
+            lo = hi;
+        }
+        res
+    }
+}
+
+//
+// behavior
+//
+impl YearFrac for ActActIcma {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &Date, end: &Date) -> Result<f64, Self::Error> {
+        let anchor = start.max(end);
+        Ok(self.year_frac_with_anchor(start, end, anchor))
+    }
+}
+
+/// Year fraction is calculated with local dates of given datetimes. Time-of-day is ignored.
+impl YearFrac<DateTime> for ActActIcma {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &DateTime, end: &DateTime) -> Result<f64, Self::Error> {
+        self.year_frac(&start.date(), &end.date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qchrono::ext::chrono::Days;
+
+    use super::*;
+    use rstest::rstest;
+
+    fn ymd(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(5)]
+    #[case(24)]
+    fn test_new_err(#[case] frequency: u8) {
+        let res = ActActIcma::new(frequency);
+
+        assert!(res.is_err());
+    }
+
+    #[rstest]
+    // regular periods
+    #[case(1, ymd(2021, 3, 15), ymd(2022, 3, 15), 1.)]
+    #[case(2, ymd(2020, 1, 15), ymd(2020, 7, 15), 0.5)]
+    #[case(2, ymd(2020, 7, 15), ymd(2021, 1, 15), 0.5)]
+    #[case(4, ymd(2020, 11, 30), ymd(2021, 2, 28), 0.25)]
+    #[case(12, ymd(2021, 1, 31), ymd(2021, 2, 28), 1. / 12.)]
+    #[case(2, ymd(2019, 1, 15), ymd(2021, 1, 15), 2.)]
+    // ISDA 2006 Section 4.16 (c), examples of ISDA/ICMA memo
+    #[case(2, ymd(2003, 11, 1), ymd(2004, 5, 1), 0.5)]
+    #[case(1, ymd(1999, 2, 1), ymd(1999, 7, 1), 150. / 365.)]
+    #[case(1, ymd(2002, 8, 15), ymd(2003, 7, 15), 334. / 365.)]
+    #[case(2, ymd(1999, 7, 30), ymd(2000, 1, 30), 0.5)]
+    // long first period anchored at the end
+    #[case(2, ymd(2020, 3, 1), ymd(2021, 1, 15), 136. / (2. * 182.) + 0.5)]
+    fn test_year_fraction(
+        #[case] frequency: u8,
+        #[case] start: Date,
+        #[case] end: Date,
+        #[case] expected: f64,
+    ) {
+        let dcf = ActActIcma::new(frequency).unwrap();
+
+        let tested = dcf.year_frac(&start, &end).unwrap();
+        let rev = dcf.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(tested, -rev, epsilon = 1e-10);
+    }
+
+    #[rstest]
+    // ISDA 2006 Section 4.16 (c), long first period of a quarterly bond
+    #[case(4, ymd(1999, 11, 30), ymd(2000, 4, 30), ymd(2000, 5, 31), 91. / 364. + 61. / 368.)]
+    #[case(4, ymd(1999, 11, 30), ymd(2000, 4, 30), ymd(2010, 8, 31), 91. / 364. + 61. / 368.)]
+    // short final period of an annual bond
+    #[case(1, ymd(2021, 1, 15), ymd(2021, 7, 1), ymd(2020, 1, 15), 167. / 365.)]
+    // short period in the middle of a regular period
+    #[case(2, ymd(2021, 2, 1), ymd(2021, 3, 1), ymd(2021, 1, 15), 28. / (2. * 181.))]
+    fn test_year_frac_with_anchor(
+        #[case] frequency: u8,
+        #[case] start: Date,
+        #[case] end: Date,
+        #[case] anchor: Date,
+        #[case] expected: f64,
+    ) {
+        let dcf = ActActIcma::new(frequency).unwrap();
+
+        let tested = dcf.year_frac_with_anchor(&start, &end, &anchor);
+        let rev = dcf.year_frac_with_anchor(&end, &start, &anchor);
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(tested, -rev, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_year_fraction_datetime() {
+        let dcf = ActActIcma::new(2).unwrap();
+        let start: DateTime = "2003-11-01T23:00:00Z".parse().unwrap();
+        let end: DateTime = "2004-05-01T01:00:00Z".parse().unwrap();
+
+        let tested = dcf.year_frac(&start, &end).unwrap();
+
+        assert_eq!(tested, 0.5);
+    }
+
+    #[rstest]
+    #[case(1, Date::MAX, Date::MAX)]
+    #[case(2, Date::MAX, Date::MAX)]
+    #[case(12, Date::MAX, Date::MAX)]
+    #[case(2, Date::MIN, Date::MIN)]
+    #[case(2, Date::MAX.checked_sub_days(Days::new(10)).unwrap(), Date::MAX)]
+    #[case(2, Date::MIN, Date::MIN.checked_add_days(Days::new(10)).unwrap())]
+    fn test_year_fraction_at_bounds(#[case] frequency: u8, #[case] start: Date, #[case] end: Date) {
+        let dcf = ActActIcma::new(frequency).unwrap();
+
+        let tested = dcf.year_frac(&start, &end).unwrap();
+
+        assert!(tested.is_finite());
+        assert!((0.0..=1.0).contains(&tested));
+        if start == end {
+            assert_eq!(tested, 0.0);
+        }
+    }
+}
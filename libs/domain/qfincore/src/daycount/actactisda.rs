@@ -0,0 +1,188 @@
+use std::convert::Infallible;
+
+use qchrono::{
+    ext::chrono::Datelike,
+    timepoint::{Date, DateTime},
+};
+
+use super::{StateLessYearFrac, YearFrac};
+
+// -----------------------------------------------------------------------------
+// ActActIsda
+// -----------------------------------------------------------------------------
+/// Actual/Actual (ISDA) day count fraction in ISDA 2006 definitions.
+///
+/// The period is split at year boundaries. Days in leap years are divided by 366
+/// and days in non-leap years are divided by 365.
+///
+/// # Example
+/// ```
+/// use qchrono::timepoint::Date;
+/// use qfincore::daycount::{ActActIsda, YearFrac};
+///
+/// let start: Date = "2003-11-01".parse().unwrap();
+/// let end: Date = "2004-05-01".parse().unwrap();
+///
+/// let expected = 61. / 365. + 121. / 366.;
+/// approx::assert_abs_diff_eq!(ActActIsda.year_frac(&start, &end).unwrap(), expected, epsilon = 1e-15);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActActIsda;
+
+//
+// ser/de
+//
+impl serde::Serialize for ActActIsda {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("actact_isda")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ActActIsda {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s: &str = serde::Deserialize::deserialize(deserializer)?;
+        if s == "actact_isda" {
+            Ok(ActActIsda)
+        } else {
+            Err(serde::de::Error::custom(
+                "Day count fraction string must be 'actact_isda'",
+            ))
+        }
+    }
+}
+
+impl schemars::JsonSchema for ActActIsda {
+    fn schema_name() -> String {
+        "ActActIsda".to_string()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        "qfincore::daycount::ActActIsda".into()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            format: Some("actact_isda".to_string()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+//
+// behavior
+//
+impl<D: Datelike> StateLessYearFrac<D> for ActActIsda where ActActIsda: YearFrac<D> {}
+
+impl YearFrac for ActActIsda {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &Date, end: &Date) -> Result<f64, Self::Error> {
+        if end < start {
+            return self.year_frac(end, start).map(std::ops::Neg::neg);
+        }
+        if start.year() == end.year() {
+            let days = (*end - *start).num_days();
+            return Ok(days as f64 / _days_in_year(start.year()));
+        }
+        // start year, whole years in between and end year
+        let next_year = Date::from_ymd_opt(start.year() + 1, 1, 1).unwrap();
+        let this_year = Date::from_ymd_opt(end.year(), 1, 1).unwrap();
+        let head = (next_year - *start).num_days() as f64 / _days_in_year(start.year());
+        let tail = (*end - this_year).num_days() as f64 / _days_in_year(end.year());
+        let body = (end.year() - start.year() - 1) as f64;
+        Ok(head + body + tail)
+    }
+}
+
+/// Year fraction is calculated with local dates of given datetimes. Time-of-day is ignored.
+impl YearFrac<DateTime> for ActActIsda {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &DateTime, end: &DateTime) -> Result<f64, Self::Error> {
+        self.year_frac(&start.date(), &end.date())
+    }
+}
+
+#[inline]
+fn _days_in_year(year: i32) -> f64 {
+    if Date::from_yo_opt(year, 366).is_some() {
+        366.0
+    } else {
+        365.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn ymd(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 1, 1), ymd(2021, 1, 2), 1. / 365.)]
+    #[case(ymd(2020, 1, 1), ymd(2020, 1, 2), 1. / 366.)]
+    #[case(ymd(2021, 1, 1), ymd(2022, 1, 1), 1.)]
+    #[case(ymd(2020, 1, 1), ymd(2021, 1, 1), 1.)]
+    #[case(ymd(2019, 12, 31), ymd(2020, 1, 1), 1. / 365.)]
+    #[case(ymd(2020, 12, 31), ymd(2021, 1, 1), 1. / 366.)]
+    #[case(ymd(2019, 7, 1), ymd(2022, 7, 1), 184. / 365. + 2. + 181. / 365.)]
+    // ISDA 2006 Section 4.16 (b), examples of ISDA/ICMA memo
+    #[case(ymd(2003, 11, 1), ymd(2004, 5, 1), 61. / 365. + 121. / 366.)]
+    #[case(ymd(1999, 2, 1), ymd(1999, 7, 1), 150. / 365.)]
+    #[case(ymd(2002, 8, 15), ymd(2003, 7, 15), 334. / 365.)]
+    #[case(ymd(1999, 11, 30), ymd(2000, 4, 30), 32. / 365. + 120. / 366.)]
+    #[case(ymd(1999, 7, 30), ymd(2000, 1, 30), 155. / 365. + 29. / 366.)]
+    fn test_year_fraction(#[case] start: Date, #[case] end: Date, #[case] expected: f64) {
+        let dcf = ActActIsda.year_frac(&start, &end).unwrap();
+        let rev = ActActIsda.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(dcf, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
+    }
+
+    #[rstest]
+    #[case("2019-12-31T23:00:00Z".parse().unwrap(), "2020-01-01T01:00:00Z".parse().unwrap(), 1. / 365.)]
+    #[case("2020-12-31T09:00:00+09:00".parse().unwrap(), "2021-01-01T08:00:00+09:00".parse().unwrap(), 1. / 366.)]
+    fn test_year_fraction_datetime(
+        #[case] start: DateTime,
+        #[case] end: DateTime,
+        #[case] expected: f64,
+    ) {
+        let dcf = ActActIsda.year_frac(&start, &end).unwrap();
+        let rev = ActActIsda.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(dcf, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_ser() {
+        let ser = serde_json::to_string(&ActActIsda).unwrap();
+
+        assert_eq!(ser, "\"actact_isda\"");
+    }
+
+    #[test]
+    fn test_de() {
+        let tested: ActActIsda = serde_json::from_str("\"actact_isda\"").unwrap();
+
+        assert_eq!(tested, ActActIsda);
+    }
+
+    #[rstest]
+    #[case("\"actact_icma\"")]
+    #[case("\"act365f\"")]
+    #[case("\" actact_isda\"")]
+    fn test_de_err(#[case] ser: &str) {
+        let tested: Result<ActActIsda, _> = serde_json::from_str(ser);
+
+        assert!(tested.is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use qchrono::{
     calendar::{Calendar, CalendarError, CalendarSym},
-    timepoint::Date,
+    timepoint::{Date, DateTime},
 };
 
 use super::YearFrac;
@@ -52,6 +52,16 @@ impl YearFrac for Bd252 {
     }
 }
 
+/// Business days are counted between local dates of given datetimes. Time-of-day is ignored.
+impl YearFrac<DateTime> for Bd252 {
+    type Error = CalendarError;
+
+    #[inline]
+    fn year_frac(&self, start: &DateTime, end: &DateTime) -> Result<f64, Self::Error> {
+        self.year_frac(&start.date(), &end.date())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -93,4 +103,21 @@ mod tests {
         approx::assert_abs_diff_eq!(dcf, expected, epsilon = 1e-10);
         approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
     }
+
+    #[rstest]
+    #[case("2021-01-04T09:00:00+09:00", "2021-01-11T09:00:00+09:00", 5. / 252.)]
+    #[case("2021-01-11T00:00:00+09:00", "2021-01-18T23:59:59+09:00", 4. / 252.)]
+    #[case("2021-01-11T23:00:00+09:00", "2021-01-12T01:00:00+09:00", 1. / 252.)]
+    #[case("2021-01-11T10:00:00+09:00", "2021-01-11T23:00:00+09:00", 0. / 252.)]
+    fn test_year_fraction_datetime(#[case] start: &str, #[case] end: &str, #[case] expected: f64) {
+        let bd252 = instance();
+        let start = DateTime::from_str(start).unwrap();
+        let end = DateTime::from_str(end).unwrap();
+
+        let dcf = bd252.year_frac(&start, &end).unwrap();
+        let rev = bd252.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(dcf, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
+    }
 }
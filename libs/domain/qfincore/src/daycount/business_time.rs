@@ -0,0 +1,194 @@
+use qchrono::{
+    calendar::Calendar,
+    ext::chrono::{self, Timelike},
+    timepoint::DateTime,
+};
+
+use super::YearFrac;
+
+// -----------------------------------------------------------------------------
+// business_time_between
+// -----------------------------------------------------------------------------
+/// Year fraction between two datetimes which accrues only on business days.
+///
+/// Each business day contributes `dcf.year_frac(d, d + 1)` multiplied by the covered part of the day,
+/// and holidays contribute nothing.
+/// Covered parts of the first and the last days are calculated from time-of-day on the wall clock,
+/// i.e. `seconds_from_midnight / 86400`.
+///
+/// Local dates and time-of-day are taken in the timezone of `start`,
+/// so `end` is converted to the timezone of `start` if they are different.
+/// If `end` is before `start`, the result is negative.
+///
+/// # Errors
+/// - If the calendar does not cover the period
+/// - If the day count fails to calculate a year fraction
+///
+/// # Example
+/// ```
+/// use qchrono::{calendar::Calendar, ext::chrono::{NaiveDate, Weekday}, timepoint::DateTime};
+/// use qfincore::daycount::{business_time_between, Act365f};
+///
+/// let cal = Calendar::builder()
+///     .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+///     .with_extra_holidays(vec![])
+///     .with_extra_business_days(vec![])
+///     .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+///     .build()
+///     .unwrap();
+///
+/// // from Friday noon to Monday noon
+/// let start: DateTime = "2021-01-08T12:00:00+09:00".parse().unwrap();
+/// let end: DateTime = "2021-01-11T12:00:00+09:00".parse().unwrap();
+///
+/// let tested = business_time_between(&start, &end, &cal, &Act365f).unwrap();
+/// approx::assert_abs_diff_eq!(tested, 1. / 365., epsilon = 1e-15);
+/// ```
+pub fn business_time_between<Dcf>(
+    start: &DateTime,
+    end: &DateTime,
+    cal: &Calendar,
+    dcf: &Dcf,
+) -> anyhow::Result<f64>
+where
+    Dcf: YearFrac,
+    anyhow::Error: From<Dcf::Error>,
+{
+    if end < start {
+        return business_time_between(end, start, cal, dcf).map(std::ops::Neg::neg);
+    }
+    let tz = start.timezone();
+    let end: DateTime = chrono::DateTime::from(end.clone())
+        .with_timezone(&tz)
+        .into();
+    let (d0, d1) = (start.date(), end.date());
+    let (t0, t1) = (_day_fraction(start), _day_fraction(&end));
+
+    let mut res = 0.0;
+    for d in cal.iter_bizdays_in(d0..=d1)? {
+        let lo = if d == d0 { t0 } else { 0.0 };
+        let hi = if d == d1 { t1 } else { 1.0 };
+        let next = d
+            .succ_opt()
+            .ok_or_else(|| anyhow::anyhow!("Date overflow"))?;
+        res += (hi - lo) * dcf.year_frac(&d, &next)?;
+    }
+    Ok(res)
+}
+
+/// Elapsed part of the local day on the wall clock.
+#[inline]
+fn _day_fraction(dt: &DateTime) -> f64 {
+    let time = dt.time();
+    let secs = time.num_seconds_from_midnight() as f64 + time.nanosecond() as f64 * 1e-9;
+    secs / 86400.0
+}
+
+#[cfg(test)]
+mod tests {
+    use qchrono::{ext::chrono::NaiveDate, timepoint::Weekday};
+    use rstest::rstest;
+
+    use crate::daycount::{Act360, Act365f, Bd252, DayCount};
+
+    use super::*;
+
+    fn cal() -> Calendar {
+        Calendar::builder()
+            .with_valid_period(
+                NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+            )
+            .with_extra_holidays(vec![NaiveDate::from_ymd_opt(2021, 1, 13).unwrap()])
+            .with_extra_business_days(vec![])
+            .with_holiday_weekdays(vec![Weekday::Sat, Weekday::Sun])
+            .build()
+            .unwrap()
+    }
+
+    fn dt(s: &str) -> DateTime {
+        s.parse().unwrap()
+    }
+
+    #[rstest]
+    // Fri to Mon and Mon to Tue
+    #[case(dt("2021-01-08T12:00:00+09:00"), dt("2021-01-11T12:00:00+09:00"), 1.)]
+    #[case(dt("2021-01-11T12:00:00+09:00"), dt("2021-01-12T12:00:00+09:00"), 1.)]
+    #[case(dt("2021-01-08T00:00:00+09:00"), dt("2021-01-11T00:00:00+09:00"), 1.)]
+    #[case(dt("2021-01-04T00:00:00+09:00"), dt("2021-01-11T00:00:00+09:00"), 5.)]
+    // within a day
+    #[case(dt("2021-01-11T06:00:00+09:00"), dt("2021-01-11T18:00:00+09:00"), 0.5)]
+    // weekend and holiday do not accrue
+    #[case(dt("2021-01-09T06:00:00+09:00"), dt("2021-01-10T18:00:00+09:00"), 0.)]
+    #[case(dt("2021-01-12T18:00:00+09:00"), dt("2021-01-14T06:00:00+09:00"), 0.5)]
+    // end is converted to the timezone of start
+    #[case(dt("2021-01-08T12:00:00+09:00"), dt("2021-01-11T03:00:00Z"), 1.)]
+    #[case(dt("2021-01-08T03:00:00Z"), dt("2021-01-11T12:00:00+09:00"), 1.)]
+    fn test_business_time_between(
+        #[case] start: DateTime,
+        #[case] end: DateTime,
+        #[case] days: f64,
+    ) {
+        let cal = cal();
+
+        let tested = business_time_between(&start, &end, &cal, &Act365f).unwrap();
+        let rev = business_time_between(&end, &start, &cal, &Act365f).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, days / 365., epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(rev, -tested, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_business_time_weekend_vs_weekday() {
+        let cal = cal();
+        let dcf: DayCount = Act360.into();
+
+        let fri_to_mon = business_time_between(
+            &dt("2021-01-08T15:00:00+09:00"),
+            &dt("2021-01-11T15:00:00+09:00"),
+            &cal,
+            &dcf,
+        )
+        .unwrap();
+        let mon_to_tue = business_time_between(
+            &dt("2021-01-11T15:00:00+09:00"),
+            &dt("2021-01-12T15:00:00+09:00"),
+            &cal,
+            &dcf,
+        )
+        .unwrap();
+
+        approx::assert_abs_diff_eq!(fri_to_mon, mon_to_tue, epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(fri_to_mon, 1. / 360., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_business_time_bd252() {
+        let cal = cal();
+        let dcf: DayCount = Bd252::new("TKY".parse().unwrap(), cal.clone()).into();
+
+        let tested = business_time_between(
+            &dt("2021-01-08T12:00:00+09:00"),
+            &dt("2021-01-15T12:00:00+09:00"),
+            &cal,
+            &dcf,
+        )
+        .unwrap();
+
+        approx::assert_abs_diff_eq!(tested, 4. / 252., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_business_time_err_out_of_calendar() {
+        let cal = cal();
+
+        let tested = business_time_between(
+            &dt("2021-12-30T12:00:00+09:00"),
+            &dt("2022-01-05T12:00:00+09:00"),
+            &cal,
+            &Act365f,
+        );
+
+        assert!(tested.is_err());
+    }
+}
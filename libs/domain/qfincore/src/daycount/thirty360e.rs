@@ -0,0 +1,187 @@
+use std::convert::Infallible;
+
+use qchrono::{
+    ext::chrono::Datelike,
+    timepoint::{Date, DateTime},
+};
+
+use super::{thirty360::_year_frac, StateLessYearFrac, YearFrac};
+
+// -----------------------------------------------------------------------------
+// Thirty360E
+// -----------------------------------------------------------------------------
+/// 30E/360 day count fraction, a.k.a. Eurobond Basis in ISDA 2006 definitions.
+///
+/// Day of month of start and end dates, `d1` and `d2`, are adjusted as follows:
+/// - If `d1` is 31, then change `d1` to 30.
+/// - If `d2` is 31, then change `d2` to 30.
+///
+/// Different from [`super::Thirty360`], `d2` is adjusted regardless of `d1`.
+/// Note that the last day of February is not adjusted.
+///
+/// # Example
+/// ```
+/// use qchrono::timepoint::Date;
+/// use qfincore::daycount::{Thirty360E, YearFrac};
+///
+/// let start: Date = "2021-01-15".parse().unwrap();
+/// let end: Date = "2021-03-31".parse().unwrap();
+///
+/// assert_eq!(Thirty360E.year_frac(&start, &end).unwrap(), 75. / 360.);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Thirty360E;
+
+//
+// ser/de
+//
+impl serde::Serialize for Thirty360E {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("30E/360")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Thirty360E {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s: &str = serde::Deserialize::deserialize(deserializer)?;
+        if s == "30E/360" {
+            Ok(Thirty360E)
+        } else {
+            Err(serde::de::Error::custom(
+                "Day count fraction string must be '30E/360'",
+            ))
+        }
+    }
+}
+
+impl schemars::JsonSchema for Thirty360E {
+    fn schema_name() -> String {
+        "Thirty360E".to_string()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        "qfincore::daycount::Thirty360E".into()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            format: Some("30E/360".to_string()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+//
+// behavior
+//
+impl<D: Datelike> StateLessYearFrac<D> for Thirty360E where Thirty360E: YearFrac<D> {}
+
+impl YearFrac for Thirty360E {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &Date, end: &Date) -> Result<f64, Self::Error> {
+        if end < start {
+            return self.year_frac(end, start).map(std::ops::Neg::neg);
+        }
+        let d1 = start.day().min(30);
+        let d2 = end.day().min(30);
+        Ok(_year_frac(start, d1, end, d2))
+    }
+}
+
+/// Year fraction is calculated with local dates of given datetimes. Time-of-day is ignored.
+impl YearFrac<DateTime> for Thirty360E {
+    type Error = Infallible;
+
+    #[inline]
+    fn year_frac(&self, start: &DateTime, end: &DateTime) -> Result<f64, Self::Error> {
+        self.year_frac(&start.date(), &end.date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn ymd(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[rstest]
+    #[case(ymd(2021, 1, 1), ymd(2021, 1, 2), 1.)]
+    #[case(ymd(2021, 1, 1), ymd(2022, 1, 1), 360.)]
+    #[case(ymd(2021, 1, 30), ymd(2021, 1, 31), 0.)]
+    #[case(ymd(2021, 1, 31), ymd(2021, 3, 31), 60.)]
+    #[case(ymd(2007, 1, 15), ymd(2007, 1, 30), 15.)]
+    #[case(ymd(2007, 1, 15), ymd(2007, 2, 15), 30.)]
+    #[case(ymd(2007, 1, 15), ymd(2007, 7, 15), 180.)]
+    #[case(ymd(2007, 9, 30), ymd(2008, 3, 31), 180.)]
+    #[case(ymd(2007, 9, 30), ymd(2007, 10, 31), 30.)]
+    #[case(ymd(2007, 9, 30), ymd(2008, 9, 30), 360.)]
+    #[case(ymd(2007, 1, 15), ymd(2007, 1, 31), 15.)]
+    #[case(ymd(2007, 1, 31), ymd(2007, 2, 28), 28.)]
+    #[case(ymd(2007, 2, 28), ymd(2007, 3, 31), 32.)]
+    #[case(ymd(2006, 8, 31), ymd(2007, 2, 28), 178.)]
+    #[case(ymd(2007, 2, 28), ymd(2007, 8, 31), 182.)]
+    #[case(ymd(2007, 2, 14), ymd(2007, 2, 28), 14.)]
+    #[case(ymd(2007, 2, 26), ymd(2008, 2, 29), 363.)]
+    #[case(ymd(2008, 2, 29), ymd(2009, 2, 28), 359.)]
+    #[case(ymd(2008, 2, 29), ymd(2008, 3, 30), 31.)]
+    #[case(ymd(2008, 2, 29), ymd(2008, 3, 31), 31.)]
+    #[case(ymd(2007, 2, 28), ymd(2007, 3, 5), 7.)]
+    #[case(ymd(2007, 10, 31), ymd(2007, 11, 28), 28.)]
+    #[case(ymd(2007, 8, 31), ymd(2008, 2, 29), 179.)]
+    #[case(ymd(2008, 2, 29), ymd(2008, 8, 31), 181.)]
+    #[case(ymd(2008, 8, 31), ymd(2009, 2, 28), 178.)]
+    #[case(ymd(2009, 2, 28), ymd(2009, 8, 31), 182.)]
+    fn test_year_fraction(#[case] start: Date, #[case] end: Date, #[case] days: f64) {
+        let dcf = Thirty360E.year_frac(&start, &end).unwrap();
+        let rev = Thirty360E.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(dcf, days / 360., epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
+    }
+
+    #[rstest]
+    #[case("2021-01-15T23:00:00Z".parse().unwrap(), "2021-03-31T01:00:00Z".parse().unwrap(), 75.)]
+    #[case("2021-01-31T09:00:00+09:00".parse().unwrap(), "2021-02-01T08:00:00+09:00".parse().unwrap(), 1.)]
+    fn test_year_fraction_datetime(
+        #[case] start: DateTime,
+        #[case] end: DateTime,
+        #[case] days: f64,
+    ) {
+        let dcf = Thirty360E.year_frac(&start, &end).unwrap();
+        let rev = Thirty360E.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(dcf, days / 360., epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(dcf, -rev, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_ser() {
+        let ser = serde_json::to_string(&Thirty360E).unwrap();
+
+        assert_eq!(ser, "\"30E/360\"");
+    }
+
+    #[test]
+    fn test_de() {
+        let tested: Thirty360E = serde_json::from_str("\"30E/360\"").unwrap();
+
+        assert_eq!(tested, Thirty360E);
+    }
+
+    #[rstest]
+    #[case("\"30/360\"")]
+    #[case("\"30e/360\"")]
+    #[case("\"act360\"")]
+    fn test_de_err(#[case] ser: &str) {
+        let tested: Result<Thirty360E, _> = serde_json::from_str(ser);
+
+        assert!(tested.is_err());
+    }
+}
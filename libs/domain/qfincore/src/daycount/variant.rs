@@ -1,11 +1,11 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use qchrono::{
     calendar::{CalendarSrc, CalendarSym},
-    timepoint::Date,
+    timepoint::{Date, DateTime},
 };
 
-use super::{Act360, Act365f, Bd252, YearFrac};
+use super::{Act360, Act365f, ActActIcma, ActActIsda, Bd252, Thirty360, Thirty360E, YearFrac};
 
 // -----------------------------------------------------------------------------
 // DayCount
@@ -15,6 +15,10 @@ pub enum DayCount {
     Act365f,
     Act360,
     Bd252(Bd252),
+    Thirty360,
+    Thirty360E,
+    ActActIsda,
+    ActActIcma(ActActIcma),
 }
 
 impl From<Act365f> for DayCount {
@@ -38,6 +42,34 @@ impl From<Bd252> for DayCount {
     }
 }
 
+impl From<Thirty360> for DayCount {
+    #[inline]
+    fn from(_: Thirty360) -> Self {
+        DayCount::Thirty360
+    }
+}
+
+impl From<Thirty360E> for DayCount {
+    #[inline]
+    fn from(_: Thirty360E) -> Self {
+        DayCount::Thirty360E
+    }
+}
+
+impl From<ActActIsda> for DayCount {
+    #[inline]
+    fn from(_: ActActIsda) -> Self {
+        DayCount::ActActIsda
+    }
+}
+
+impl From<ActActIcma> for DayCount {
+    #[inline]
+    fn from(src: ActActIcma) -> Self {
+        DayCount::ActActIcma(src)
+    }
+}
+
 impl Display for DayCount {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -55,6 +87,27 @@ impl YearFrac for DayCount {
             DayCount::Act365f => Act365f.year_frac(start, end).map_err(Into::into),
             DayCount::Act360 => Act360.year_frac(start, end).map_err(Into::into),
             DayCount::Bd252(src) => src.year_frac(start, end).map_err(Into::into),
+            DayCount::Thirty360 => Thirty360.year_frac(start, end).map_err(Into::into),
+            DayCount::Thirty360E => Thirty360E.year_frac(start, end).map_err(Into::into),
+            DayCount::ActActIsda => ActActIsda.year_frac(start, end).map_err(Into::into),
+            DayCount::ActActIcma(src) => src.year_frac(start, end).map_err(Into::into),
+        }
+    }
+}
+
+impl YearFrac<DateTime> for DayCount {
+    type Error = anyhow::Error;
+
+    #[inline]
+    fn year_frac(&self, start: &DateTime, end: &DateTime) -> anyhow::Result<f64> {
+        match self {
+            DayCount::Act365f => Act365f.year_frac(start, end).map_err(Into::into),
+            DayCount::Act360 => Act360.year_frac(start, end).map_err(Into::into),
+            DayCount::Bd252(src) => src.year_frac(start, end).map_err(Into::into),
+            DayCount::Thirty360 => Thirty360.year_frac(start, end).map_err(Into::into),
+            DayCount::Thirty360E => Thirty360E.year_frac(start, end).map_err(Into::into),
+            DayCount::ActActIsda => ActActIsda.year_frac(start, end).map_err(Into::into),
+            DayCount::ActActIcma(src) => src.year_frac(start, end).map_err(Into::into),
         }
     }
 }
@@ -68,6 +121,12 @@ impl DayCount {
             DayCount::Bd252(src) => DayCountSym::Bd252 {
                 calendar: src.calendar_sym().clone(),
             },
+            DayCount::Thirty360 => DayCountSym::Thirty360,
+            DayCount::Thirty360E => DayCountSym::Thirty360E,
+            DayCount::ActActIsda => DayCountSym::ActActIsda,
+            DayCount::ActActIcma(src) => DayCountSym::ActActIcma {
+                frequency: src.frequency(),
+            },
         }
     }
 }
@@ -82,7 +141,19 @@ impl DayCount {
 pub enum DayCountSym {
     Act365f,
     Act360,
-    Bd252 { calendar: CalendarSym },
+    Bd252 {
+        calendar: CalendarSym,
+    },
+    #[serde(rename = "30/360")]
+    Thirty360,
+    #[serde(rename = "30E/360")]
+    Thirty360E,
+    #[serde(rename = "actact_isda")]
+    ActActIsda,
+    #[serde(rename = "actact_icma")]
+    ActActIcma {
+        frequency: u8,
+    },
 }
 
 impl Display for DayCountSym {
@@ -92,6 +163,56 @@ impl Display for DayCountSym {
             DayCountSym::Act365f => write!(f, "act365f"),
             DayCountSym::Act360 => write!(f, "act360"),
             DayCountSym::Bd252 { calendar } => write!(f, "bd252[{}]", calendar),
+            DayCountSym::Thirty360 => write!(f, "30/360"),
+            DayCountSym::Thirty360E => write!(f, "30E/360"),
+            DayCountSym::ActActIsda => write!(f, "actact_isda"),
+            DayCountSym::ActActIcma { frequency } => write!(f, "actact_icma[{}]", frequency),
+        }
+    }
+}
+
+impl FromStr for DayCountSym {
+    type Err = anyhow::Error;
+
+    /// Parse a day count symbol from the string in the same format as [`Display`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::daycount::DayCountSym;
+    ///
+    /// let sym: DayCountSym = "30E/360".parse().unwrap();
+    /// assert_eq!(sym, DayCountSym::Thirty360E);
+    ///
+    /// let sym: DayCountSym = "bd252[TKY]".parse().unwrap();
+    /// assert_eq!(sym.to_string(), "bd252[TKY]");
+    ///
+    /// let sym: DayCountSym = "actact_icma[2]".parse().unwrap();
+    /// assert_eq!(sym, DayCountSym::ActActIcma { frequency: 2 });
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "act365f" => Ok(DayCountSym::Act365f),
+            "act360" => Ok(DayCountSym::Act360),
+            "30/360" => Ok(DayCountSym::Thirty360),
+            "30E/360" => Ok(DayCountSym::Thirty360E),
+            "actact_isda" => Ok(DayCountSym::ActActIsda),
+            _ => {
+                if let Some(frequency) = s
+                    .strip_prefix("actact_icma[")
+                    .and_then(|s| s.strip_suffix(']'))
+                {
+                    return Ok(DayCountSym::ActActIcma {
+                        frequency: frequency.parse()?,
+                    });
+                }
+                let calendar = s
+                    .strip_prefix("bd252[")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .ok_or_else(|| anyhow::anyhow!("Unknown day count symbol: {s}"))?;
+                Ok(DayCountSym::Bd252 {
+                    calendar: calendar.parse()?,
+                })
+            }
         }
     }
 }
@@ -112,6 +233,10 @@ impl<S: CalendarSrc> DayCountSrc for S {
                 let cal = self.get_calendar(calendar)?;
                 Ok(Bd252::new(calendar.clone(), cal).into())
             }
+            DayCountSym::Thirty360 => Ok(Thirty360.into()),
+            DayCountSym::Thirty360E => Ok(Thirty360E.into()),
+            DayCountSym::ActActIsda => Ok(ActActIsda.into()),
+            DayCountSym::ActActIcma { frequency } => Ok(ActActIcma::new(*frequency)?.into()),
         }
     }
 }
@@ -123,6 +248,7 @@ mod tests {
         ext::chrono::NaiveDate,
         timepoint::Weekday,
     };
+    use rstest::rstest;
 
     use super::*;
 
@@ -186,4 +312,142 @@ mod tests {
 
         assert!(res.is_err());
     }
+
+    fn bd252() -> DayCount {
+        let cal = Calendar::builder()
+            .with_extra_business_days(Default::default())
+            .with_extra_holidays(vec![NaiveDate::from_ymd_opt(2021, 1, 13).unwrap()])
+            .with_valid_period(NaiveDate::MIN, NaiveDate::MAX)
+            .with_holiday_weekdays(vec![Weekday::Sun, Weekday::Sat])
+            .build()
+            .unwrap();
+        Bd252::new("TKY".parse().unwrap(), cal).into()
+    }
+
+    #[rstest]
+    #[case(Act365f.into(), 14. / 365.)]
+    #[case(Act360.into(), 14. / 360.)]
+    #[case(bd252(), 9. / 252.)]
+    #[case(Thirty360.into(), 14. / 360.)]
+    #[case(Thirty360E.into(), 14. / 360.)]
+    #[case(ActActIsda.into(), 14. / 365.)]
+    #[case(ActActIcma::new(12).unwrap().into(), 14. / 31.0 / 12.)]
+    fn test_year_frac(#[case] dcf: DayCount, #[case] expected: f64) {
+        let start = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+        let end = NaiveDate::from_ymd_opt(2021, 1, 18).unwrap();
+
+        let tested = dcf.year_frac(&start, &end).unwrap();
+        let rev = dcf.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(rev, -expected, epsilon = 1e-10);
+    }
+
+    #[rstest]
+    #[case(Act365f.into(), 14. / 365.)]
+    #[case(Act360.into(), 14. / 360.)]
+    #[case(bd252(), 9. / 252.)]
+    #[case(Thirty360.into(), 14. / 360.)]
+    #[case(Thirty360E.into(), 14. / 360.)]
+    #[case(ActActIsda.into(), 14. / 365.)]
+    #[case(ActActIcma::new(12).unwrap().into(), 14. / 31.0 / 12.)]
+    fn test_year_frac_datetime(#[case] dcf: DayCount, #[case] expected: f64) {
+        let start: DateTime = "2021-01-04T00:00:00Z".parse().unwrap();
+        let end: DateTime = "2021-01-18T00:00:00Z".parse().unwrap();
+
+        let tested = dcf.year_frac(&start, &end).unwrap();
+        let rev = dcf.year_frac(&end, &start).unwrap();
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(rev, -expected, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_year_frac_datetime_err() {
+        let cal = Calendar::builder()
+            .with_extra_business_days(Default::default())
+            .with_extra_holidays(Default::default())
+            .with_valid_period(
+                NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+            )
+            .with_holiday_weekdays(vec![Weekday::Sun, Weekday::Sat])
+            .build()
+            .unwrap();
+        let dcf: DayCount = Bd252::new("TKY".parse().unwrap(), cal).into();
+        let start: DateTime = "2021-06-01T00:00:00Z".parse().unwrap();
+        let end: DateTime = "2022-06-01T00:00:00Z".parse().unwrap();
+
+        let tested = dcf.year_frac(&start, &end);
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    #[case(DayCountSym::Act365f, "act365f")]
+    #[case(DayCountSym::Act360, "act360")]
+    #[case(DayCountSym::Bd252 { calendar: "NYC|TKY".parse().unwrap() }, "bd252[NYC|TKY]")]
+    #[case(DayCountSym::Thirty360, "30/360")]
+    #[case(DayCountSym::Thirty360E, "30E/360")]
+    #[case(DayCountSym::ActActIsda, "actact_isda")]
+    #[case(DayCountSym::ActActIcma { frequency: 2 }, "actact_icma[2]")]
+    fn test_sym_str_roundtrip(#[case] sym: DayCountSym, #[case] s: &str) {
+        let displayed = sym.to_string();
+        let parsed: DayCountSym = s.parse().unwrap();
+
+        assert_eq!(displayed, s);
+        assert_eq!(parsed, sym);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("30e/360")]
+    #[case("30/360E")]
+    #[case("bd252")]
+    #[case("bd252[TKY")]
+    #[case("act365")]
+    #[case("actact")]
+    #[case("actact_icma")]
+    #[case("actact_icma[x]")]
+    #[case("actact_icma[-1]")]
+    fn test_sym_parse_err(#[case] s: &str) {
+        let parsed: Result<DayCountSym, _> = s.parse();
+
+        assert!(parsed.is_err());
+    }
+
+    #[rstest]
+    #[case(DayCountSym::Thirty360, r#"{"type":"30/360"}"#)]
+    #[case(DayCountSym::Thirty360E, r#"{"type":"30E/360"}"#)]
+    #[case(DayCountSym::ActActIsda, r#"{"type":"actact_isda"}"#)]
+    #[case(DayCountSym::ActActIcma { frequency: 4 }, r#"{"type":"actact_icma","frequency":4}"#)]
+    fn test_sym_serde(#[case] sym: DayCountSym, #[case] ser: &str) {
+        let serialized = serde_json::to_string(&sym).unwrap();
+        let deserialized: DayCountSym = serde_json::from_str(ser).unwrap();
+
+        assert_eq!(serialized, ser);
+        assert_eq!(deserialized, sym);
+    }
+
+    #[rstest]
+    #[case(DayCountSym::Thirty360, Thirty360.into())]
+    #[case(DayCountSym::Thirty360E, Thirty360E.into())]
+    #[case(DayCountSym::ActActIsda, ActActIsda.into())]
+    #[case(DayCountSym::ActActIcma { frequency: 2 }, ActActIcma::new(2).unwrap().into())]
+    fn test_get_thirty360(#[case] sym: DayCountSym, #[case] expected: DayCount) {
+        let src = MockCalendarSrc;
+
+        let res = src.get_daycount(&sym).unwrap();
+
+        assert_eq!(&res, &expected);
+    }
+
+    #[test]
+    fn test_get_actacticma_err() {
+        let src = MockCalendarSrc;
+
+        let res = src.get_daycount(&DayCountSym::ActActIcma { frequency: 5 });
+
+        assert!(res.is_err());
+    }
 }
@@ -30,7 +30,7 @@ impl FxSpotMkt {
         };
         let d = HolidayAdj::Following
             .adjust(value_date, &self.settle_cal)
-            .ok_or_else(err)?;
+            .map_err(|_| err())?;
         self.settle_cal
             .iter_bizdays(d)
             .nth(self.spot_lag as usize)
@@ -1,3 +1,4 @@
+pub mod black_scholes;
 pub mod daycount;
 pub mod fxmkt;
 pub mod quantity;
@@ -1,9 +1,11 @@
 mod ccy;
 mod fxrate;
 mod vol;
+mod vol_term;
 mod yld;
 
 pub use ccy::{Ccy, CcyPair};
 pub use fxrate::FxRate;
-pub use vol::Volatility;
-pub use yld::Yield;
+pub use vol::{VolError, Volatility};
+pub use vol_term::VolTermStructure;
+pub use yld::{Compounding, Yield};
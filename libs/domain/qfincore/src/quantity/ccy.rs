@@ -12,6 +12,7 @@
     serde::Deserialize,
     schemars::JsonSchema,
     strum::Display,
+    strum::EnumIter,
 )]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Ccy {
@@ -20,6 +21,52 @@ pub enum Ccy {
     EUR,
 }
 
+//
+// methods
+//
+impl Ccy {
+    /// ISO 4217 numeric code of the currency.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::quantity::Ccy;
+    ///
+    /// assert_eq!(Ccy::JPY.numeric_code(), 392);
+    /// assert_eq!(Ccy::from_numeric(840), Some(Ccy::USD));
+    /// ```
+    #[inline]
+    pub fn numeric_code(&self) -> u16 {
+        match self {
+            Ccy::JPY => 392,
+            Ccy::USD => 840,
+            Ccy::EUR => 978,
+        }
+    }
+
+    /// Number of digits after the decimal separator defined by ISO 4217.
+    #[inline]
+    pub fn minor_units(&self) -> u8 {
+        match self {
+            Ccy::JPY => 0,
+            Ccy::USD => 2,
+            Ccy::EUR => 2,
+        }
+    }
+
+    /// Currency from ISO 4217 numeric code.
+    ///
+    /// This returns [None] if the code is unknown or the currency is not supported.
+    #[inline]
+    pub fn from_numeric(code: u16) -> Option<Ccy> {
+        match code {
+            392 => Some(Ccy::JPY),
+            840 => Some(Ccy::USD),
+            978 => Some(Ccy::EUR),
+            _ => None,
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // CcyPair
 // -----------------------------------------------------------------------------
@@ -38,3 +85,53 @@ pub struct CcyPair {
     pub base: Ccy,
     pub quote: Ccy,
 }
+
+//
+// methods
+//
+impl CcyPair {
+    /// The pair with base and quote swapped.
+    #[inline]
+    pub fn inverse(&self) -> CcyPair {
+        CcyPair {
+            base: self.quote,
+            quote: self.base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_numeric_roundtrip() {
+        for ccy in Ccy::iter() {
+            let code = ccy.numeric_code();
+
+            let tested = Ccy::from_numeric(code);
+
+            assert_eq!(tested, Some(ccy));
+        }
+    }
+
+    #[rstest]
+    #[case(Ccy::JPY, 392, 0)]
+    #[case(Ccy::USD, 840, 2)]
+    #[case(Ccy::EUR, 978, 2)]
+    fn test_metadata(#[case] ccy: Ccy, #[case] code: u16, #[case] minor_units: u8) {
+        assert_eq!(ccy.numeric_code(), code);
+        assert_eq!(ccy.minor_units(), minor_units);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(826)]
+    #[case(999)]
+    fn test_from_numeric_unknown(#[case] code: u16) {
+        assert_eq!(Ccy::from_numeric(code), None);
+    }
+}
@@ -1,6 +1,6 @@
-use qmath::num::Positive;
+use qmath::num::{Positive, Real};
 
-use crate::quantity::CcyPair;
+use crate::quantity::{Ccy, CcyPair};
 
 // -----------------------------------------------------------------------------
 // FxRate
@@ -13,3 +13,205 @@ pub struct FxRate<V> {
     pub pair: CcyPair,
     pub value: Positive<V>,
 }
+
+//
+// methods
+//
+impl<V: Real> FxRate<V> {
+    /// Reciprocal quote, that is, the rate for the inverse pair.
+    ///
+    /// # Errors
+    /// - When the reciprocal is not positive, e.g. for an infinite rate.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::quantity::{Ccy, CcyPair, FxRate};
+    /// use qmath::num::Positive;
+    ///
+    /// let usdjpy = FxRate {
+    ///     pair: CcyPair { base: Ccy::USD, quote: Ccy::JPY },
+    ///     value: Positive::new(160.0).unwrap(),
+    /// };
+    ///
+    /// let jpyusd = usdjpy.invert().unwrap();
+    ///
+    /// assert_eq!(jpyusd.pair, CcyPair { base: Ccy::JPY, quote: Ccy::USD });
+    /// assert_eq!(jpyusd.value.into_inner(), 0.00625);
+    /// ```
+    #[inline]
+    pub fn invert(&self) -> anyhow::Result<FxRate<V>> {
+        let value = V::one() / self.value.as_ref();
+        Ok(FxRate {
+            pair: self.pair.inverse(),
+            value: Positive::new(value.clone())
+                .ok_or_else(|| anyhow::anyhow!("Non-positive reciprocal rate: {value}"))?,
+        })
+    }
+
+    /// Derive a cross rate from two rates which share exactly one currency.
+    ///
+    /// The result has the non-shared currency of `rhs` as base
+    /// and the non-shared currency of `lhs` as quote.
+    /// For example, `USD/JPY` and `EUR/USD` give `EUR/JPY`.
+    /// Orientation of given rates does not matter, so `JPY/USD` and `USD/EUR` also give `EUR/JPY`.
+    ///
+    /// # Errors
+    /// - When two rates share no currency or share both of currencies.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::quantity::{Ccy, CcyPair, FxRate};
+    /// use qmath::num::Positive;
+    ///
+    /// let usdjpy = FxRate {
+    ///     pair: CcyPair { base: Ccy::USD, quote: Ccy::JPY },
+    ///     value: Positive::new(150.0).unwrap(),
+    /// };
+    /// let eurusd = FxRate {
+    ///     pair: CcyPair { base: Ccy::EUR, quote: Ccy::USD },
+    ///     value: Positive::new(1.1).unwrap(),
+    /// };
+    ///
+    /// let eurjpy = FxRate::cross(&usdjpy, &eurusd).unwrap();
+    ///
+    /// assert_eq!(eurjpy.pair, CcyPair { base: Ccy::EUR, quote: Ccy::JPY });
+    /// approx::assert_abs_diff_eq!(eurjpy.value.into_inner(), 165.0, epsilon = 1e-10);
+    /// ```
+    pub fn cross(lhs: &Self, rhs: &Self) -> anyhow::Result<Self> {
+        let (lp, rp) = (lhs.pair, rhs.pair);
+        let is_shared = |c: Ccy| c == rp.base || c == rp.quote;
+        let shared = match (is_shared(lp.base), is_shared(lp.quote)) {
+            (true, false) => lp.base,
+            (false, true) => lp.quote,
+            _ => anyhow::bail!(
+                "Cross rate requires exactly one shared currency: {}/{} and {}/{}",
+                lp.base,
+                lp.quote,
+                rp.base,
+                rp.quote
+            ),
+        };
+        // orient as shared/lhs-other and rhs-other/shared
+        let lhs = if lp.base == shared {
+            lhs.clone()
+        } else {
+            lhs.invert()?
+        };
+        let rhs = if rp.quote == shared {
+            rhs.clone()
+        } else {
+            rhs.invert()?
+        };
+        let value = rhs.value.into_inner() * lhs.value.as_ref();
+        Ok(FxRate {
+            pair: CcyPair {
+                base: rhs.pair.base,
+                quote: lhs.pair.quote,
+            },
+            value: Positive::new(value.clone())
+                .ok_or_else(|| anyhow::anyhow!("Non-positive cross rate: {value}"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn rate(base: Ccy, quote: Ccy, value: f64) -> FxRate<f64> {
+        FxRate {
+            pair: CcyPair { base, quote },
+            value: Positive::new(value).unwrap(),
+        }
+    }
+
+    #[rstest]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0), rate(Ccy::EUR, Ccy::USD, 1.1))]
+    #[case(rate(Ccy::JPY, Ccy::USD, 1.0 / 150.0), rate(Ccy::EUR, Ccy::USD, 1.1))]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0), rate(Ccy::USD, Ccy::EUR, 1.0 / 1.1))]
+    #[case(rate(Ccy::JPY, Ccy::USD, 1.0 / 150.0), rate(Ccy::USD, Ccy::EUR, 1.0 / 1.1))]
+    fn test_cross(#[case] lhs: FxRate<f64>, #[case] rhs: FxRate<f64>) {
+        let tested = FxRate::cross(&lhs, &rhs).unwrap();
+
+        assert_eq!(
+            tested.pair,
+            CcyPair {
+                base: Ccy::EUR,
+                quote: Ccy::JPY
+            }
+        );
+        approx::assert_abs_diff_eq!(tested.value.into_inner(), 165.0, epsilon = 1e-10);
+    }
+
+    #[rstest]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0), rate(Ccy::EUR, Ccy::EUR, 1.0))]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0), rate(Ccy::USD, Ccy::JPY, 150.0))]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0), rate(Ccy::JPY, Ccy::USD, 1.0 / 150.0))]
+    fn test_cross_err(#[case] lhs: FxRate<f64>, #[case] rhs: FxRate<f64>) {
+        let tested = FxRate::cross(&lhs, &rhs);
+
+        assert!(tested.is_err());
+    }
+
+    #[rstest]
+    #[case(rate(Ccy::USD, Ccy::JPY, 150.0))]
+    #[case(rate(Ccy::EUR, Ccy::USD, 1.1))]
+    #[case(rate(Ccy::EUR, Ccy::JPY, 1e-7))]
+    fn test_invert(#[case] rate: FxRate<f64>) {
+        let inv = rate.invert().unwrap();
+        let tested = inv.invert().unwrap();
+
+        assert_eq!(inv.pair, rate.pair.inverse());
+        approx::assert_relative_eq!(
+            inv.value.into_inner(),
+            1.0 / rate.value.into_inner(),
+            max_relative = 1e-15
+        );
+        assert_eq!(tested.pair, rate.pair);
+        approx::assert_relative_eq!(
+            tested.value.into_inner(),
+            rate.value.into_inner(),
+            max_relative = 1e-15
+        );
+    }
+
+    #[test]
+    fn test_invert_err_infinite() {
+        let rate = rate(Ccy::USD, Ccy::JPY, f64::INFINITY);
+
+        let tested = rate.invert();
+
+        assert!(tested.is_err());
+    }
+
+    #[test]
+    fn test_cross_err_infinite() {
+        let lhs = rate(Ccy::JPY, Ccy::USD, f64::INFINITY);
+        let rhs = rate(Ccy::EUR, Ccy::USD, 1.1);
+
+        let tested = FxRate::cross(&lhs, &rhs);
+
+        assert!(tested.is_err());
+    }
+
+    #[test]
+    fn test_invert_autodiff() {
+        let graph = qautodiff::Graph::new();
+        let x: qautodiff::Expr<&str, f64> = graph.create_var("usdjpy", 160.0).unwrap().into();
+        let rate = FxRate {
+            pair: CcyPair {
+                base: Ccy::USD,
+                quote: Ccy::JPY,
+            },
+            value: Positive::new(x).unwrap(),
+        };
+
+        let tested = rate.invert().unwrap().value.into_inner();
+
+        let grads: std::collections::HashMap<_, _> = tested.grads().unwrap().collect();
+        assert_eq!(tested.value(), 1.0 / 160.0);
+        approx::assert_abs_diff_eq!(grads[&"usdjpy"], -1.0 / 160.0 / 160.0, epsilon = 1e-15);
+    }
+}
@@ -2,7 +2,10 @@ use std::fmt::Debug;
 
 use anyhow::ensure;
 use qchrono::ext::chrono::Datelike;
-use qmath::num::{Arithmetic, FloatBased, Scalar};
+use qmath::{
+    num::{Arithmetic, FloatBased, Scalar},
+    stats::{norm_cdf, norm_pdf},
+};
 
 use crate::daycount::{StateLessYearFrac, YearFrac};
 
@@ -169,6 +172,166 @@ impl<Dcf: Debug + Eq, V: Arithmetic> std::ops::AddAssign<&Self> for Volatility<D
     }
 }
 
+//
+// implied volatility
+//
+const IMPLIED_VOL_MAX_ITER: usize = 100;
+const IMPLIED_VOL_TOL: f64 = 1e-14;
+
+impl<Dcf> Volatility<Dcf, f64> {
+    /// Recover the implied volatility from an undiscounted Black price.
+    ///
+    /// `time` is the year fraction to the expiry measured with `day_count`,
+    /// so that the returned volatility is consistent with it.
+    /// If the price is exactly on the intrinsic value, zero volatility is returned.
+    ///
+    /// Internally, this solves the total standard deviation with Newton's method
+    /// starting from the inflection point of the price function,
+    /// safeguarded by bisection on a bracket which is shrunk at each step.
+    ///
+    /// # Errors
+    /// - [`VolError::InvalidInput`] if forward, strike or time is not positive and finite,
+    ///   or price is not finite.
+    /// - [`VolError::ArbitrageBound`] if the price is outside of no-arbitrage bounds,
+    ///   i.e. below the intrinsic value or not less than the forward (call) or the strike (put).
+    /// - [`VolError::NotConverged`] if the iteration does not converge.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::{daycount::Act365f, quantity::Volatility};
+    ///
+    /// // ATM call with 20% volatility and 1 year expiry
+    /// let price = 7.965567455405804;
+    ///
+    /// let vol = Volatility::implied_from_black(Act365f, price, 100., 100., 1., true).unwrap();
+    ///
+    /// approx::assert_abs_diff_eq!(vol.value, 0.2, epsilon = 1e-12);
+    /// ```
+    pub fn implied_from_black(
+        day_count: Dcf,
+        price: f64,
+        forward: f64,
+        strike: f64,
+        time: f64,
+        is_call: bool,
+    ) -> Result<Self, VolError> {
+        for (name, value) in [("forward", forward), ("strike", strike), ("time", time)] {
+            if !(value.is_finite() && 0. < value) {
+                return Err(VolError::InvalidInput { name, value });
+            }
+        }
+        if !price.is_finite() {
+            return Err(VolError::InvalidInput {
+                name: "price",
+                value: price,
+            });
+        }
+        let (lower, upper) = if is_call {
+            ((forward - strike).max(0.), forward)
+        } else {
+            ((strike - forward).max(0.), strike)
+        };
+        if price < lower || upper <= price {
+            return Err(VolError::ArbitrageBound {
+                price,
+                lower,
+                upper,
+            });
+        }
+        // solve with out-of-the-money price for numerical stability
+        let otm_is_call = strike >= forward;
+        let target = price - lower;
+        if target == 0. {
+            return Ok(Volatility {
+                day_count,
+                value: 0.,
+            });
+        }
+        let stdev = _implied_stdev(target, forward, strike, otm_is_call)?;
+        Ok(Volatility {
+            day_count,
+            value: stdev / time.sqrt(),
+        })
+    }
+}
+
+/// Undiscounted Black price with total standard deviation `stdev`.
+#[inline]
+pub(crate) fn _black(forward: f64, strike: f64, stdev: f64, is_call: bool) -> f64 {
+    let sign = if is_call { 1. } else { -1. };
+    if stdev <= 0. {
+        return (sign * (forward - strike)).max(0.);
+    }
+    let d1 = (forward / strike).ln() / stdev + 0.5 * stdev;
+    let d2 = d1 - stdev;
+    sign * (forward * norm_cdf(sign * d1) - strike * norm_cdf(sign * d2))
+}
+
+fn _implied_stdev(target: f64, forward: f64, strike: f64, is_call: bool) -> Result<f64, VolError> {
+    let logm = (forward / strike).ln();
+    let err = |s: f64| _black(forward, strike, s, is_call) - target;
+
+    // price is convex below and concave above the inflection point sqrt(2|ln(F/K)|),
+    // hence Newton's method from there converges monotonically.
+    // for ATM, use Brenner-Subrahmanyam approximation instead.
+    let mut s = if logm == 0. {
+        (2. * std::f64::consts::PI).sqrt() * target / forward
+    } else {
+        (2. * logm.abs()).sqrt()
+    };
+
+    // bracket the root. price is increasing in stdev.
+    let (mut lo, mut hi) = (0., s);
+    while err(hi) < 0. {
+        lo = hi;
+        hi *= 2.;
+        if !hi.is_finite() || 1e3 < hi {
+            return Err(VolError::NotConverged {
+                iterations: IMPLIED_VOL_MAX_ITER,
+            });
+        }
+    }
+    for _ in 0..IMPLIED_VOL_MAX_ITER {
+        let e = err(s);
+        if e == 0. {
+            return Ok(s);
+        }
+        if e < 0. {
+            lo = s;
+        } else {
+            hi = s;
+        }
+        let d1 = logm / s + 0.5 * s;
+        let vega = forward * norm_pdf(d1);
+        let newton = s - e / vega;
+        let next = if vega.is_finite() && 0. < vega && lo < newton && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+        if (next - s).abs() <= IMPLIED_VOL_TOL * s.max(1.) || hi - lo <= IMPLIED_VOL_TOL {
+            return Ok(next);
+        }
+        s = next;
+    }
+    Err(VolError::NotConverged {
+        iterations: IMPLIED_VOL_MAX_ITER,
+    })
+}
+
+// -----------------------------------------------------------------------------
+// VolError
+// -----------------------------------------------------------------------------
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+pub enum VolError {
+    #[error("{name} must be positive and finite, but got {value}")]
+    InvalidInput { name: &'static str, value: f64 },
+    #[error("Price {price} is outside of no-arbitrage bounds [{lower}, {upper})")]
+    ArbitrageBound { price: f64, lower: f64, upper: f64 },
+    #[error("Implied volatility did not converge within {iterations} iterations")]
+    NotConverged { iterations: usize },
+}
+
 #[cfg(test)]
 mod tests {
     use qmath::ext::num::Zero;
@@ -351,4 +514,84 @@ mod tests {
 
         y1 += &y2;
     }
+
+    #[rstest]
+    #[case(100., 100., 1., 0.2, true)]
+    #[case(100., 100., 1., 0.2, false)]
+    #[case(100., 120., 0.5, 0.3, true)]
+    #[case(100., 120., 0.5, 0.3, false)]
+    #[case(100., 80., 2., 0.15, true)]
+    #[case(100., 80., 2., 0.15, false)]
+    #[case(100., 150., 0.1, 0.5, true)]
+    #[case(100., 60., 0.1, 0.5, false)]
+    #[case(0.03, 0.035, 5., 0.01, true)]
+    #[case(100., 100., 10., 2.0, true)]
+    #[case(100., 100., 1. / 365., 0.05, false)]
+    fn test_implied_from_black(
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] time: f64,
+        #[case] vol: f64,
+        #[case] is_call: bool,
+    ) {
+        let price = _black(forward, strike, vol * time.sqrt(), is_call);
+
+        let implied =
+            Volatility::implied_from_black(Act365f, price, forward, strike, time, is_call).unwrap();
+        let repriced = _black(forward, strike, implied.value * time.sqrt(), is_call);
+
+        assert_eq!(implied.day_count, Act365f);
+        approx::assert_abs_diff_eq!(repriced, price, epsilon = 1e-10 * forward);
+        approx::assert_abs_diff_eq!(implied.value, vol, epsilon = 1e-6);
+    }
+
+    #[rstest]
+    #[case(0., 100., 100., true)]
+    #[case(20., 120., 100., true)]
+    #[case(20., 100., 120., false)]
+    fn test_implied_from_black_intrinsic(
+        #[case] price: f64,
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] is_call: bool,
+    ) {
+        let implied =
+            Volatility::implied_from_black(Act365f, price, forward, strike, 1., is_call).unwrap();
+
+        assert_eq!(implied.value, 0.);
+    }
+
+    #[rstest]
+    #[case(-1., 100., 100., true)]
+    #[case(19., 120., 100., true)]
+    #[case(100., 100., 100., true)]
+    #[case(19., 100., 120., false)]
+    #[case(120., 100., 120., false)]
+    fn test_implied_from_black_arbitrage(
+        #[case] price: f64,
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] is_call: bool,
+    ) {
+        let res = Volatility::implied_from_black(Act365f, price, forward, strike, 1., is_call);
+
+        assert!(matches!(res, Err(VolError::ArbitrageBound { .. })));
+    }
+
+    #[rstest]
+    #[case(5., 0., 100., 1.)]
+    #[case(5., 100., -1., 1.)]
+    #[case(5., 100., 100., 0.)]
+    #[case(5., f64::NAN, 100., 1.)]
+    #[case(f64::NAN, 100., 100., 1.)]
+    fn test_implied_from_black_invalid(
+        #[case] price: f64,
+        #[case] forward: f64,
+        #[case] strike: f64,
+        #[case] time: f64,
+    ) {
+        let res = Volatility::implied_from_black(Act365f, price, forward, strike, time, true);
+
+        assert!(matches!(res, Err(VolError::InvalidInput { .. })));
+    }
 }
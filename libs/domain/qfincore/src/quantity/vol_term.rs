@@ -0,0 +1,348 @@
+use std::fmt::Debug;
+
+use anyhow::ensure;
+use qmath::num::{Real, Scalar};
+
+use super::Volatility;
+
+// -----------------------------------------------------------------------------
+// VolTermStructure
+// -----------------------------------------------------------------------------
+/// Term structure of volatilities interpolated linearly in total variance.
+///
+/// Each knot is a pair of a time, i.e. a year fraction measured with the day count of volatilities,
+/// and a volatility at that time.
+/// Between knots, total variance `vol^2 * t` is interpolated linearly in time
+/// and converted back to a volatility. Beyond the first and the last knots, volatility is extrapolated flat.
+///
+/// # Example
+/// ```
+/// use qfincore::{daycount::Act365f, quantity::{VolTermStructure, Volatility}};
+///
+/// let vol = |value: f64| Volatility { day_count: Act365f, value };
+/// let ts = VolTermStructure::new(vec![(1., vol(0.2)), (2., vol(0.3))]).unwrap();
+///
+/// assert_eq!(ts.vol_at(0.5).value, 0.2);
+/// assert_eq!(ts.vol_at(2.0).value, 0.3);
+/// assert_eq!(ts.vol_at(3.0).value, 0.3);
+///
+/// // total variance is 0.04 at 1Y and 0.18 at 2Y, hence 0.11 at 1.5Y
+/// approx::assert_abs_diff_eq!(ts.total_variance_at(1.5), 0.11, epsilon = 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolTermStructure<Dcf, V> {
+    day_count: Dcf,
+    times: Vec<f64>,
+    vols: Vec<V>,
+    vars: Vec<V>,
+}
+
+//
+// ctor
+//
+impl<Dcf, V> VolTermStructure<Dcf, V> {
+    /// Create a term structure from knots.
+    ///
+    /// # Errors
+    /// - If no knot is given
+    /// - If times are not positive and finite, or not strictly increasing
+    /// - If volatilities have different day counts
+    /// - If a volatility is negative
+    pub fn new<It>(knots: It) -> anyhow::Result<Self>
+    where
+        It: IntoIterator<Item = (f64, Volatility<Dcf, V>)>,
+        Dcf: Debug + Eq,
+        V: Real,
+    {
+        let mut day_count = None;
+        let mut times = Vec::new();
+        let mut vols = Vec::new();
+        for (t, vol) in knots {
+            ensure!(
+                t.is_finite() && 0. < t,
+                "time of a knot must be positive and finite: {t}"
+            );
+            ensure!(
+                times.last().map_or(true, |last| *last < t),
+                "times of knots must be strictly increasing: {t} follows {:?}",
+                times.last()
+            );
+            ensure!(
+                V::zero() <= vol.value,
+                "volatility must be non-negative: {} at {t}",
+                vol.value
+            );
+            match &day_count {
+                None => day_count = Some(vol.day_count),
+                Some(dcf) => ensure!(
+                    dcf == &vol.day_count,
+                    "day counts of volatilities must be the same: {dcf:?} and {:?}",
+                    vol.day_count
+                ),
+            }
+            times.push(t);
+            vols.push(vol.value);
+        }
+        let Some(day_count) = day_count else {
+            anyhow::bail!("term structure requires at least one knot");
+        };
+        let vars = times
+            .iter()
+            .zip(&vols)
+            .map(|(t, v)| v.clone() * v * &V::nearest_value_of_f64(*t))
+            .collect();
+        Ok(Self {
+            day_count,
+            times,
+            vols,
+            vars,
+        })
+    }
+}
+
+//
+// methods
+//
+impl<Dcf, V> VolTermStructure<Dcf, V> {
+    /// Day count of volatilities, which times of knots are measured with.
+    #[inline]
+    pub fn day_count(&self) -> &Dcf {
+        &self.day_count
+    }
+
+    /// Times of knots in ascending order.
+    #[inline]
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Volatility values of knots aligned to [`VolTermStructure::times`].
+    #[inline]
+    pub fn vols(&self) -> &[V] {
+        &self.vols
+    }
+
+    /// Total variance `vol^2 * t` at the given time.
+    ///
+    /// This is linear in time between knots and `t` times the squared volatility of the nearest knot outside.
+    /// NaN is propagated, that is, the result is NaN if `t` is NaN.
+    pub fn total_variance_at(&self, t: f64) -> V
+    where
+        V: Scalar,
+    {
+        if t.is_nan() {
+            return V::nearest_value_of_f64(t);
+        }
+        let n = self.times.len();
+        if t <= self.times[0] {
+            return self.vols[0].clone() * &self.vols[0] * &V::nearest_value_of_f64(t);
+        }
+        if self.times[n - 1] <= t {
+            return self.vols[n - 1].clone() * &self.vols[n - 1] * &V::nearest_value_of_f64(t);
+        }
+        // times[i - 1] <= t < times[i]
+        let i = self.times.partition_point(|x| *x <= t);
+        let (t0, t1) = (self.times[i - 1], self.times[i]);
+        let w = V::nearest_value_of_f64((t - t0) / (t1 - t0));
+        self.vars[i - 1].clone() + &((self.vars[i].clone() - &self.vars[i - 1]) * &w)
+    }
+
+    /// Volatility at the given time.
+    ///
+    /// Knots are reproduced exactly and volatility is flat outside of knots.
+    /// NaN is propagated, that is, the result is NaN if `t` is NaN.
+    pub fn vol_at(&self, t: f64) -> Volatility<Dcf, V>
+    where
+        Dcf: Clone,
+        V: Scalar,
+    {
+        let n = self.times.len();
+        let value = if t.is_nan() {
+            V::nearest_value_of_f64(t)
+        } else if t <= self.times[0] {
+            self.vols[0].clone()
+        } else if self.times[n - 1] <= t {
+            self.vols[n - 1].clone()
+        } else {
+            match self.times.binary_search_by(|x| x.total_cmp(&t)) {
+                Ok(i) => self.vols[i].clone(),
+                Err(_) => (self.total_variance_at(t) / &V::nearest_value_of_f64(t)).sqrt(),
+            }
+        };
+        Volatility {
+            day_count: self.day_count.clone(),
+            value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::daycount::{Act365f, DayCount};
+
+    use super::*;
+
+    fn vol(value: f64) -> Volatility<Act365f, f64> {
+        Volatility {
+            day_count: Act365f,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_vol_at_knots() {
+        let knots = vec![
+            (0.25, vol(0.3)),
+            (1., vol(0.25)),
+            (2., vol(0.22)),
+            (5., vol(0.2)),
+        ];
+        let ts = VolTermStructure::new(knots.clone()).unwrap();
+
+        for (t, v) in knots {
+            let tested = ts.vol_at(t);
+
+            assert_eq!(tested.day_count, Act365f);
+            assert_eq!(tested.value, v.value);
+            approx::assert_relative_eq!(ts.total_variance_at(t), v.value * v.value * t);
+        }
+    }
+
+    #[rstest]
+    #[case(0.01, 0.3)]
+    #[case(0.25, 0.3)]
+    #[case(5., 0.2)]
+    #[case(30., 0.2)]
+    fn test_vol_at_flat_extrapolation(#[case] t: f64, #[case] expected: f64) {
+        let knots = vec![(0.25, vol(0.3)), (1., vol(0.25)), (5., vol(0.2))];
+        let ts = VolTermStructure::new(knots).unwrap();
+
+        let tested = ts.vol_at(t);
+
+        assert_eq!(tested.value, expected);
+    }
+
+    #[test]
+    fn test_vol_at_interpolates_variance() {
+        let ts = VolTermStructure::new(vec![(1., vol(0.1)), (3., vol(0.3))]).unwrap();
+
+        let tested = ts.vol_at(2.);
+
+        // (0.01 + 0.27) / 2 = 0.14 at t=2
+        approx::assert_abs_diff_eq!(ts.total_variance_at(2.), 0.14, epsilon = 1e-15);
+        approx::assert_abs_diff_eq!(tested.value, (0.14f64 / 2.).sqrt(), epsilon = 1e-15);
+        assert!(tested.value != 0.2);
+    }
+
+    #[test]
+    fn test_total_variance_autodiff() {
+        let graph = qautodiff::Graph::new();
+        let a: qautodiff::Expr<&str, f64> = graph.create_var("a", 0.1).unwrap().into();
+        let b: qautodiff::Expr<&str, f64> = graph.create_var("b", 0.3).unwrap().into();
+        let knots = vec![
+            (
+                1.,
+                Volatility {
+                    day_count: Act365f,
+                    value: a,
+                },
+            ),
+            (
+                3.,
+                Volatility {
+                    day_count: Act365f,
+                    value: b,
+                },
+            ),
+        ];
+        let ts = VolTermStructure::new(knots).unwrap();
+
+        let tested = ts.total_variance_at(2.);
+
+        // (a^2 * 1 + b^2 * 3) / 2
+        let grads: std::collections::HashMap<_, _> = tested.grads().unwrap().collect();
+        approx::assert_abs_diff_eq!(tested.value(), 0.14, epsilon = 1e-15);
+        approx::assert_abs_diff_eq!(grads[&"a"], 0.1, epsilon = 1e-15);
+        approx::assert_abs_diff_eq!(grads[&"b"], 0.9, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_total_variance_monotone() {
+        let knots = vec![
+            (0.25, vol(0.4)),
+            (1., vol(0.25)),
+            (2., vol(0.22)),
+            (5., vol(0.2)),
+        ];
+        let ts = VolTermStructure::new(knots).unwrap();
+
+        let vars = (1..=700)
+            .map(|i| ts.total_variance_at(i as f64 / 100.))
+            .collect::<Vec<_>>();
+
+        assert!(vars.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_nan_propagation() {
+        let knots = vec![(1., vol(0.2)), (2., vol(0.3)), (5., vol(0.25))];
+        let ts = VolTermStructure::new(knots).unwrap();
+
+        let variance = ts.total_variance_at(f64::NAN);
+        let tested = ts.vol_at(f64::NAN);
+
+        assert!(variance.is_nan());
+        assert!(tested.value.is_nan());
+        assert_eq!(tested.day_count, Act365f);
+    }
+
+    #[rstest]
+    #[case(f64::INFINITY, f64::INFINITY, 0.3)]
+    #[case(f64::NEG_INFINITY, f64::NEG_INFINITY, 0.2)]
+    fn test_infinite_time(#[case] t: f64, #[case] variance: f64, #[case] vol: f64) {
+        let ts = VolTermStructure::new(vec![(1., self::vol(0.2)), (2., self::vol(0.3))]).unwrap();
+
+        assert_eq!(ts.total_variance_at(t), variance);
+        assert_eq!(ts.vol_at(t).value, vol);
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![(0., vol(0.2))])]
+    #[case(vec![(-1., vol(0.2))])]
+    #[case(vec![(f64::NAN, vol(0.2))])]
+    #[case(vec![(1., vol(0.2)), (1., vol(0.2))])]
+    #[case(vec![(2., vol(0.2)), (1., vol(0.2))])]
+    #[case(vec![(1., vol(-0.2))])]
+    fn test_new_err(#[case] knots: Vec<(f64, Volatility<Act365f, f64>)>) {
+        let res = VolTermStructure::new(knots);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_new_err_day_count_mismatch() {
+        let knots = vec![
+            (
+                1.,
+                Volatility {
+                    day_count: DayCount::Act365f,
+                    value: 0.2,
+                },
+            ),
+            (
+                2.,
+                Volatility {
+                    day_count: DayCount::Act360,
+                    value: 0.2,
+                },
+            ),
+        ];
+
+        let res = VolTermStructure::new(knots);
+
+        assert!(res.is_err());
+    }
+}
@@ -5,6 +5,43 @@ use qmath::num::{Arithmetic, FloatBased, Real, Scalar};
 
 use crate::daycount::{Act360, Act365f, YearFrac};
 
+// -----------------------------------------------------------------------------
+// Compounding
+// -----------------------------------------------------------------------------
+/// Compounding convention of a quoted rate.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Compounding {
+    Continuous,
+    Annual,
+    SemiAnnual,
+    Quarterly,
+    Simple,
+}
+
+impl Compounding {
+    /// Number of compounding periods in a year, if the convention is periodic.
+    #[inline]
+    pub fn periods_per_year(&self) -> Option<u8> {
+        match self {
+            Compounding::Annual => Some(1),
+            Compounding::SemiAnnual => Some(2),
+            Compounding::Quarterly => Some(4),
+            Compounding::Continuous | Compounding::Simple => None,
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Yield
 // -----------------------------------------------------------------------------
@@ -118,6 +155,87 @@ impl<Dcf, V> Yield<Dcf, V> {
     }
 }
 
+/// Compounding conversions.
+///
+/// [Yield] itself is a continuously compounded rate, as curves in this project are.
+/// Methods here convert it to and from rates quoted with other [Compounding] conventions
+/// so that both represent the same growth over `year_fraction`.
+/// Note that `year_fraction` affects only [Compounding::Simple].
+impl<Dcf: Clone, V: Real> Yield<Dcf, V> {
+    /// Convert this continuously compounded yield to a rate quoted with `target` compounding.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::{daycount::Act365f, quantity::{Compounding, Yield}};
+    ///
+    /// let y = Yield {
+    ///     day_count: Act365f,
+    ///     value: 0.05,
+    /// };
+    ///
+    /// let annual = y.to_compounding(Compounding::Annual, 2.0);
+    ///
+    /// approx::assert_abs_diff_eq!(annual.value, 0.05f64.exp() - 1., epsilon = 1e-15);
+    /// ```
+    pub fn to_compounding(&self, target: Compounding, year_fraction: f64) -> Self {
+        let value = match (target, target.periods_per_year()) {
+            (_, Some(n)) => {
+                let n = V::nearest_value_of_f64(n as f64);
+                ((self.value.clone() / &n).exp() - &V::one()) * &n
+            }
+            (Compounding::Simple, _) if year_fraction != 0. => {
+                let t = V::nearest_value_of_f64(year_fraction);
+                ((self.value.clone() * &t).exp() - &V::one()) / &t
+            }
+            _ => self.value.clone(),
+        };
+        Yield {
+            day_count: self.day_count.clone(),
+            value,
+        }
+    }
+
+    /// Convert a rate quoted with `source` compounding into a continuously compounded yield.
+    ///
+    /// This is the inverse of [Yield::to_compounding].
+    /// The result may be NaN if the quoted rate implies a non-positive growth factor.
+    pub fn from_compounding(&self, source: Compounding, year_fraction: f64) -> Self {
+        let value = match (source, source.periods_per_year()) {
+            (_, Some(n)) => {
+                let n = V::nearest_value_of_f64(n as f64);
+                (V::one() + &(self.value.clone() / &n)).log() * &n
+            }
+            (Compounding::Simple, _) if year_fraction != 0. => {
+                let t = V::nearest_value_of_f64(year_fraction);
+                (V::one() + &(self.value.clone() * &t)).log() / &t
+            }
+            _ => self.value.clone(),
+        };
+        Yield {
+            day_count: self.day_count.clone(),
+            value,
+        }
+    }
+
+    /// Discount factor over `t`, a year fraction measured with the day count of this yield.
+    ///
+    /// # Example
+    /// ```
+    /// use qfincore::{daycount::Act365f, quantity::Yield};
+    ///
+    /// let y = Yield {
+    ///     day_count: Act365f,
+    ///     value: 0.05,
+    /// };
+    ///
+    /// assert_eq!(y.discount_factor(2.0), (-0.1f64).exp());
+    /// ```
+    #[inline]
+    pub fn discount_factor(&self, t: f64) -> V {
+        (-(self.value.clone() * &V::nearest_value_of_f64(t))).exp()
+    }
+}
+
 impl<Dcf: Debug + Eq + Default, V: Arithmetic> qmath::ext::num::Zero for Yield<Dcf, V> {
     #[inline]
     fn zero() -> Self {
@@ -640,4 +758,97 @@ mod tests {
 
         assert_eq!(y, yld * year);
     }
+
+    #[rstest]
+    #[case(Compounding::Continuous)]
+    #[case(Compounding::Annual)]
+    #[case(Compounding::SemiAnnual)]
+    #[case(Compounding::Quarterly)]
+    #[case(Compounding::Simple)]
+    fn test_compounding_roundtrip(#[case] compounding: Compounding) {
+        for value in [-0.01, 0., 0.02, 0.15] {
+            for t in [0., 0.25, 1., 7.5] {
+                let y = Yield {
+                    day_count: Act365f,
+                    value,
+                };
+
+                let quoted = y.to_compounding(compounding, t);
+                let tested = quoted.from_compounding(compounding, t);
+
+                approx::assert_abs_diff_eq!(tested.value, value, epsilon = 1e-14);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Compounding::Continuous, 0.05)]
+    #[case(Compounding::Annual, 0.05127109637602412)]
+    #[case(Compounding::SemiAnnual, 0.050630241048857716)]
+    #[case(Compounding::Quarterly, 0.050313806162537666)]
+    #[case(Compounding::Simple, 0.05192276725642101)]
+    fn test_to_compounding(#[case] compounding: Compounding, #[case] expected: f64) {
+        let y = Yield {
+            day_count: Act365f,
+            value: 0.05,
+        };
+        let t = 1.5;
+
+        let tested = y.to_compounding(compounding, t);
+
+        // all quoted rates must give the same growth over the period
+        let growth = match compounding {
+            Compounding::Continuous => (tested.value * t).exp(),
+            Compounding::Annual => (1. + tested.value).powf(t),
+            Compounding::SemiAnnual => (1. + tested.value / 2.).powf(2. * t),
+            Compounding::Quarterly => (1. + tested.value / 4.).powf(4. * t),
+            Compounding::Simple => 1. + tested.value * t,
+        };
+        assert_eq!(tested.day_count, Act365f);
+        approx::assert_abs_diff_eq!(tested.value, expected, epsilon = 1e-14);
+        approx::assert_abs_diff_eq!(growth, (0.05f64 * t).exp(), epsilon = 1e-14);
+    }
+
+    #[rstest]
+    #[case(0.05, 0., 1.)]
+    #[case(0.05, 1., 0.951229424500714)]
+    #[case(0.05, 10., 0.6065306597126334)]
+    #[case(-0.01, 2., 1.0202013400267558)]
+    fn test_discount_factor(#[case] value: f64, #[case] t: f64, #[case] expected: f64) {
+        let y = Yield {
+            day_count: Act365f,
+            value,
+        };
+
+        let tested = y.discount_factor(t);
+
+        approx::assert_abs_diff_eq!(tested, expected, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_discount_factor_autodiff() {
+        let graph = qautodiff::Graph::new();
+        let x: qautodiff::Expr<&str, f64> = graph.create_var("r", 0.05).unwrap().into();
+        let y = Yield {
+            day_count: Act365f,
+            value: x,
+        };
+
+        let tested = y.discount_factor(2.);
+
+        let grads: std::collections::HashMap<_, _> = tested.grads().unwrap().collect();
+        approx::assert_abs_diff_eq!(grads[&"r"], -2. * (-0.1f64).exp(), epsilon = 1e-15);
+    }
+
+    #[rstest]
+    #[case(Compounding::Continuous, "\"continuous\"")]
+    #[case(Compounding::SemiAnnual, "\"semi_annual\"")]
+    #[case(Compounding::Simple, "\"simple\"")]
+    fn test_compounding_serde(#[case] compounding: Compounding, #[case] ser: &str) {
+        let serialized = serde_json::to_string(&compounding).unwrap();
+        let deserialized: Compounding = serde_json::from_str(ser).unwrap();
+
+        assert_eq!(serialized, ser);
+        assert_eq!(deserialized, compounding);
+    }
 }
@@ -1,4 +1,4 @@
-use qchrono::{duration::Tenor, ext::chrono::offset::LocalResult, timepoint::DateTime};
+use qchrono::{duration::Tenor, timepoint::DateTime};
 use qfincore::{daycount::Act365f, quantity::Yield};
 use qmath::num::Real;
 
@@ -27,20 +27,7 @@ impl<V: Real> YieldCurveAdj<V> for Lookback {
         from: &DateTime,
         to: &DateTime,
     ) -> anyhow::Result<Yield<Act365f, V>> {
-        let add_tenor = |dt: &DateTime| match dt.add_tenor(-self.tenor) {
-            LocalResult::Single(dt) => Ok(dt),
-            LocalResult::None => anyhow::bail!(
-                "Add tenor result does not exist due to timezone issue. dt: {}, tenor: {}",
-                dt,
-                self.tenor
-            ),
-            LocalResult::Ambiguous(_, _) => anyhow::bail!(
-                "Add tenor result is ambiguous due to timezone issue. dt: {}, tenor: {}",
-                dt,
-                self.tenor
-            ),
-        };
-        curve.forward_rate(&add_tenor(from)?, &add_tenor(to)?)
+        curve.forward_rate(&from.add_tenor(-self.tenor)?, &to.add_tenor(-self.tenor)?)
     }
 }
 
@@ -86,22 +73,46 @@ mod tests {
         "2026-05-01T00:00:00Z".parse().unwrap(),
         "P1D".parse().unwrap()
     )]
+    fn test_adj_err(#[case] stt: DateTime, #[case] end: DateTime, #[case] tenor: Tenor) {
+        let crv = Flat { rate: 0.01.into() };
+        let adj = Lookback { tenor };
+
+        let res = adj.adjusted_forward_rate(&crv, &stt, &end);
+
+        assert!(res.is_err());
+    }
+
+    #[rstest]
     #[case(
         "2021-01-01T00:00:00Z".parse().unwrap(),
         "2023-11-06T02:30:00-04:00[America/New_York]".parse().unwrap(),
-        "P1D".parse().unwrap()
+        "2020-12-31T00:00:00Z".parse().unwrap(),
+        "2023-11-05T01:30:00-04:00[America/New_York]".parse().unwrap(),
     )]
     #[case(
         "2023-11-06T02:30:00-04:00[America/New_York]".parse().unwrap(),
         "2025-01-01T00:00:00Z".parse().unwrap(),
-        "P1D".parse().unwrap()
+        "2023-11-05T01:30:00-04:00[America/New_York]".parse().unwrap(),
+        "2024-12-31T00:00:00Z".parse().unwrap(),
     )]
-    fn test_adj_err(#[case] stt: DateTime, #[case] end: DateTime, #[case] tenor: Tenor) {
-        let crv = Flat { rate: 0.01.into() };
-        let adj = Lookback { tenor };
+    fn test_adj_ambiguous(
+        #[case] stt: DateTime,
+        #[case] end: DateTime,
+        #[case] shifted_stt: DateTime,
+        #[case] shifted_end: DateTime,
+    ) {
+        let crv = Joint {
+            switch_point: "2023-06-01T00:00:00Z".parse().unwrap(),
+            short: Flat { rate: 0.02.into() },
+            long: Flat { rate: 0.01.into() },
+        };
+        let adj = Lookback {
+            tenor: "P1D".parse().unwrap(),
+        };
 
-        let res = adj.adjusted_forward_rate(&crv, &stt, &end);
+        let res = adj.adjusted_forward_rate(&crv, &stt, &end).unwrap();
 
-        assert!(res.is_err());
+        let expected = crv.forward_rate(&shifted_stt, &shifted_end).unwrap();
+        approx::assert_abs_diff_eq!(res.value, expected.value, epsilon = 1e-10);
     }
 }
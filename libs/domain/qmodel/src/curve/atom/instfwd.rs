@@ -31,7 +31,7 @@ where
         if from == to {
             return self.inst_fwd.eval(from).map_err(Into::into);
         }
-        let integrated = self.inst_fwd.integrate(from, to).map_err(Into::into)?;
+        let integrated = self.inst_fwd.integrate(from, to)?;
         let dcf = Act365f.year_frac(from, to).unwrap();
         Ok(Yield {
             day_count: Act365f,
@@ -40,7 +40,7 @@ impl Visitor for SchemaCollector {
             if let Some(reference) = &schema.reference {
                 let name = reference
                     .split('/')
-                    .last()
+                    .next_back()
                     .expect("Reference must have a name");
                 new_ref = Some(format!("./{}.json", name));
             }